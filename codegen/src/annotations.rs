@@ -0,0 +1,241 @@
+//! Reads the CSI specific options (`alpha_*` and `csi_secret`) from a
+//! compiled descriptor set. `prost-types` drops unknown extensions when decoding, so this
+//! declares the subset of `descriptor.proto` that is needed, with the
+//! options carrying the extension fields.
+
+use std::collections::{BTreeMap, BTreeSet, HashSet};
+
+use prost::Message;
+
+/// Options of any kind. All the `alpha_*` extensions in `csi.proto` share
+/// the same field number.
+#[derive(Clone, PartialEq, Message)]
+struct Options {
+  #[prost(bool, optional, tag = "1059")]
+  secret: Option<bool>,
+  #[prost(bool, optional, tag = "1060")]
+  alpha: Option<bool>,
+}
+
+impl Options {
+  fn is_alpha(options: &Option<Options>) -> bool {
+    options.as_ref().and_then(|o| o.alpha).unwrap_or(false)
+  }
+
+  fn is_secret(options: &Option<Options>) -> bool {
+    options.as_ref().and_then(|o| o.secret).unwrap_or(false)
+  }
+}
+
+#[derive(Clone, PartialEq, Message)]
+struct FileDescriptorSet {
+  #[prost(message, repeated, tag = "1")]
+  file: Vec<FileDescriptorProto>,
+}
+
+#[derive(Clone, PartialEq, Message)]
+struct FileDescriptorProto {
+  #[prost(string, optional, tag = "2")]
+  package: Option<String>,
+  #[prost(message, repeated, tag = "4")]
+  message_type: Vec<DescriptorProto>,
+  #[prost(message, repeated, tag = "5")]
+  enum_type: Vec<EnumDescriptorProto>,
+  #[prost(message, repeated, tag = "6")]
+  service: Vec<ServiceDescriptorProto>,
+}
+
+#[derive(Clone, PartialEq, Message)]
+struct DescriptorProto {
+  #[prost(string, optional, tag = "1")]
+  name: Option<String>,
+  #[prost(message, repeated, tag = "2")]
+  field: Vec<FieldDescriptorProto>,
+  #[prost(message, repeated, tag = "3")]
+  nested_type: Vec<DescriptorProto>,
+  #[prost(message, repeated, tag = "4")]
+  enum_type: Vec<EnumDescriptorProto>,
+  #[prost(message, optional, tag = "7")]
+  options: Option<Options>,
+}
+
+#[derive(Clone, PartialEq, Message)]
+struct FieldDescriptorProto {
+  #[prost(string, optional, tag = "1")]
+  name: Option<String>,
+  #[prost(message, optional, tag = "8")]
+  options: Option<Options>,
+}
+
+#[derive(Clone, PartialEq, Message)]
+struct EnumDescriptorProto {
+  #[prost(string, optional, tag = "1")]
+  name: Option<String>,
+  #[prost(message, repeated, tag = "2")]
+  value: Vec<EnumValueDescriptorProto>,
+  #[prost(message, optional, tag = "3")]
+  options: Option<Options>,
+}
+
+#[derive(Clone, PartialEq, Message)]
+struct EnumValueDescriptorProto {
+  #[prost(string, optional, tag = "1")]
+  name: Option<String>,
+  #[prost(message, optional, tag = "3")]
+  options: Option<Options>,
+}
+
+#[derive(Clone, PartialEq, Message)]
+struct ServiceDescriptorProto {
+  #[prost(string, optional, tag = "1")]
+  name: Option<String>,
+  #[prost(message, repeated, tag = "2")]
+  method: Vec<MethodDescriptorProto>,
+  #[prost(message, optional, tag = "3")]
+  options: Option<Options>,
+}
+
+#[derive(Clone, PartialEq, Message)]
+struct MethodDescriptorProto {
+  #[prost(string, optional, tag = "1")]
+  name: Option<String>,
+  #[prost(message, optional, tag = "4")]
+  options: Option<Options>,
+}
+
+/// The items marked with one of the `alpha_*` options.
+#[derive(Debug, Default)]
+pub struct Alpha {
+  /// Fully qualified names of alpha messages and enums, like
+  /// `.csi.v1.VolumeCondition`.
+  pub types: Vec<String>,
+
+  /// Fully qualified names of alpha fields and enum values, like
+  /// `.csi.v1.Snapshot.group_snapshot_id`.
+  pub fields: Vec<String>,
+
+  /// Proto names of alpha services.
+  pub services: HashSet<String>,
+
+  /// Proto names of alpha methods, as `Service.Method`.
+  pub methods: HashSet<String>,
+}
+
+impl Alpha {
+  /// Collect the alpha items of `package` from an encoded
+  /// `FileDescriptorSet`.
+  pub fn from_descriptor_set(bytes: &[u8], package: &str) -> Result<Self, prost::DecodeError> {
+    let set = FileDescriptorSet::decode(bytes)?;
+    let mut alpha = Alpha::default();
+
+    for file in set.file.iter().filter(|f| f.package() == package) {
+      let scope = format!(".{}", package);
+      for message in &file.message_type {
+        alpha.collect_message(&scope, message);
+      }
+
+      for enumeration in &file.enum_type {
+        alpha.collect_enum(&scope, enumeration);
+      }
+
+      for service in &file.service {
+        if Options::is_alpha(&service.options) {
+          alpha.services.insert(service.name().to_owned());
+        }
+
+        for method in &service.method {
+          if Options::is_alpha(&method.options) {
+            alpha
+              .methods
+              .insert(format!("{}.{}", service.name(), method.name()));
+          }
+        }
+      }
+    }
+
+    Ok(alpha)
+  }
+
+  fn collect_message(&mut self, scope: &str, message: &DescriptorProto) {
+    let name = format!("{}.{}", scope, message.name());
+    if Options::is_alpha(&message.options) {
+      // Nested types are matched by prefix.
+      self.types.push(name);
+      return;
+    }
+
+    for field in &message.field {
+      if Options::is_alpha(&field.options) {
+        self.fields.push(format!("{}.{}", name, field.name()));
+      }
+    }
+
+    for nested in &message.nested_type {
+      self.collect_message(&name, nested);
+    }
+
+    for enumeration in &message.enum_type {
+      self.collect_enum(&name, enumeration);
+    }
+  }
+
+  fn collect_enum(&mut self, scope: &str, enumeration: &EnumDescriptorProto) {
+    let name = format!("{}.{}", scope, enumeration.name());
+    if Options::is_alpha(&enumeration.options) {
+      self.types.push(name);
+      return;
+    }
+
+    for value in &enumeration.value {
+      if Options::is_alpha(&value.options) {
+        self.fields.push(format!("{}.{}", name, value.name()));
+      }
+    }
+  }
+}
+
+/// The fields marked with the `csi_secret` option.
+#[derive(Debug, Default)]
+pub struct Secrets {
+  /// Proto names of the secret fields, by the name of their message
+  /// relative to the package, like `Outer.Inner`.
+  pub messages: BTreeMap<String, BTreeSet<String>>,
+}
+
+impl Secrets {
+  /// Collect the secret fields of `package` from an encoded
+  /// `FileDescriptorSet`.
+  pub fn from_descriptor_set(bytes: &[u8], package: &str) -> Result<Self, prost::DecodeError> {
+    let set = FileDescriptorSet::decode(bytes)?;
+    let mut secrets = Secrets::default();
+
+    for file in set.file.iter().filter(|f| f.package() == package) {
+      for message in &file.message_type {
+        secrets.collect_message(None, message);
+      }
+    }
+
+    Ok(secrets)
+  }
+
+  fn collect_message(&mut self, scope: Option<&str>, message: &DescriptorProto) {
+    let name = match scope {
+      Some(scope) => format!("{}.{}", scope, message.name()),
+      None => message.name().to_owned(),
+    };
+
+    let fields = message
+      .field
+      .iter()
+      .filter(|f| Options::is_secret(&f.options))
+      .map(|f| f.name().to_owned())
+      .collect::<BTreeSet<_>>();
+    if !fields.is_empty() {
+      self.messages.insert(name.clone(), fields);
+    }
+
+    for nested in &message.nested_type {
+      self.collect_message(Some(&name), nested);
+    }
+  }
+}
@@ -1,12 +1,21 @@
+mod annotations;
+mod redact;
+
 use std::{
   fs,
   path::{Path, PathBuf},
 };
 
+use annotations::{Alpha, Secrets};
 use anyhow::Result;
 use duct::cmd;
+use prost_build::{Method, Service, ServiceGenerator};
 use serde::Deserialize;
 
+const PACKAGE: &str = "csi.v1";
+const CFG_ALPHA: &str = "#[cfg(feature = \"alpha\")]";
+const CFG_NOT_ALPHA: &str = "#[cfg(not(feature = \"alpha\"))]";
+
 #[derive(Deserialize, Debug, Clone, Copy, Hash, PartialEq, Eq)]
 struct LocateProject<'a> {
   root: &'a str,
@@ -20,26 +29,95 @@ pub fn find_workspace() -> Result<PathBuf> {
   Ok(path.parent().unwrap().to_owned())
 }
 
+/// Generates the tonic servers, gating alpha services and methods behind
+/// the `alpha` feature. A service with alpha methods is generated twice,
+/// with and without them, as tonic has no way to put attributes on
+/// individual methods.
+struct ServerGenerator {
+  alpha: Alpha,
+}
+
+impl ServerGenerator {
+  fn push(buf: &mut String, cfg: Option<&str>, service: &Service) {
+    if let Some(cfg) = cfg {
+      buf.push_str(cfg);
+      buf.push('\n');
+    }
+
+    buf.push_str(&tonic_build::server::generate(service, "super").to_string());
+    buf.push('\n');
+  }
+
+  fn is_alpha(&self, service: &str, method: &Method) -> bool {
+    let name = format!("{}.{}", service, method.proto_name);
+    self.alpha.methods.contains(&name)
+  }
+}
+
+impl ServiceGenerator for ServerGenerator {
+  fn generate(&mut self, mut service: Service, buf: &mut String) {
+    if self.alpha.services.contains(&service.proto_name) {
+      Self::push(buf, Some(CFG_ALPHA), &service);
+      return;
+    }
+
+    let name = service.proto_name.clone();
+    if !service.methods.iter().any(|m| self.is_alpha(&name, m)) {
+      Self::push(buf, None, &service);
+      return;
+    }
+
+    Self::push(buf, Some(CFG_ALPHA), &service);
+    service.methods.retain(|m| !self.is_alpha(&name, m));
+    Self::push(buf, Some(CFG_NOT_ALPHA), &service);
+  }
+}
+
 fn main() -> Result<()> {
   let root = find_workspace()?;
   let proto_dir = root.join("proto");
   let target_dir = root.join("target").join("proto");
   let csi_proto_file = proto_dir.join("csi.proto");
+  let descriptor_file = target_dir.join("csi.desc");
 
   fs::create_dir_all(&target_dir)?;
 
+  cmd!(
+    prost_build::protoc(),
+    "--include_imports",
+    "-I",
+    &proto_dir,
+    "-I",
+    prost_build::protoc_include(),
+    "-o",
+    &descriptor_file,
+    &csi_proto_file
+  )
+  .run()?;
+  let descriptor_set = fs::read(&descriptor_file)?;
+  let alpha = Alpha::from_descriptor_set(&descriptor_set, PACKAGE)?;
+  let secrets = Secrets::from_descriptor_set(&descriptor_set, PACKAGE)?;
+
   let proto_crate_src_dir = root.join("crates").join("proto").join("src");
   let mut config = prost_build::Config::default();
-  config.protoc_arg(&format!("-I{}", proto_dir.display()));
+  config.protoc_arg(format!("-I{}", proto_dir.display()));
+  config.out_dir(&target_dir);
+  for path in &alpha.types {
+    config.type_attribute(path, CFG_ALPHA);
+  }
+
+  for path in &alpha.fields {
+    config.field_attribute(path, CFG_ALPHA);
+  }
+
+  config.service_generator(Box::new(ServerGenerator { alpha }));
+  config.compile_protos(&[csi_proto_file], &[])?;
 
-  tonic_build::configure()
-    .out_dir(&target_dir)
-    .build_client(false)
-    .build_server(true)
-    .compile_with_config(config, &[csi_proto_file], &[])?;
-  // tonic_build::server::generate(service, proto_path)
+  let csi_file = target_dir.join(format!("{}.rs", PACKAGE));
+  let code = redact::redact(&fs::read_to_string(&csi_file)?, &secrets)?;
+  fs::write(&csi_file, code)?;
+  tonic_build::fmt(target_dir.to_str().unwrap());
 
-  let csi_file = target_dir.join("csi.v1.rs");
   let target_file = proto_crate_src_dir.join("proto.rs");
 
   if target_file.is_file() {
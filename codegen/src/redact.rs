@@ -0,0 +1,357 @@
+//! Redacts the fields marked with the `csi_secret` option from the `Debug`
+//! output of the generated messages.
+//!
+//! The `::prost::Message` derive of prost 0.7 always implements `Debug`,
+//! so the messages with secret fields derive `Default` instead, and their
+//! `Message` and `Debug` implementations are written out here. They encode
+//! and decode exactly like the derived ones, for the field kinds used by
+//! those messages.
+
+use std::{collections::BTreeSet, fmt::Write};
+
+use anyhow::{bail, Result};
+
+use crate::annotations::Secrets;
+
+const DERIVE: &str = "#[derive(Clone, PartialEq, ::prost::Message)]";
+const DERIVE_REDACTED: &str = "#[derive(Clone, PartialEq, Default)]";
+
+/// A field of a generated message, as declared by its `#[prost(..)]`
+/// attribute.
+struct Field {
+  name: String,
+  cfg: Vec<String>,
+  kind: Kind,
+  tag: u32,
+  secret: bool,
+}
+
+enum Kind {
+  /// A proto3 scalar, skipped when it has the default value. `set` checks
+  /// for that, with `{}` standing for the field.
+  Scalar {
+    module: String,
+    set: &'static str,
+  },
+  RepeatedString,
+  StringMap,
+  Message,
+  RepeatedMessage,
+}
+
+/// Rewrite the messages of `code` that have secret fields.
+pub fn redact(code: &str, secrets: &Secrets) -> Result<String> {
+  let mut lines = code.lines().map(str::to_owned).collect::<Vec<_>>();
+  let mut impls = String::new();
+
+  for (message, fields) in &secrets.messages {
+    if message.contains('.') {
+      bail!(
+        "secret fields in nested message {} are not supported",
+        message
+      );
+    }
+
+    let header = format!("pub struct {} {{", message);
+    let start = match lines.iter().position(|l| l.trim() == header) {
+      Some(start) if start > 0 && lines[start - 1].trim() == DERIVE => start,
+      _ => bail!("generated message {} not found", message),
+    };
+
+    lines[start - 1] = DERIVE_REDACTED.to_owned();
+    let cfg = lines[..start - 1]
+      .iter()
+      .rev()
+      .take_while(|l| l.trim().starts_with("#[cfg"))
+      .map(|l| l.trim().to_owned())
+      .collect::<Vec<_>>();
+
+    let parsed = parse_fields(message, &lines[start + 1..], fields)?;
+    write_impls(&mut impls, message, &cfg, &parsed);
+
+    // The attributes are only understood by the derive.
+    let end = start
+      + lines[start..]
+        .iter()
+        .position(|l| l.trim() == "}")
+        .unwrap_or(0);
+    let mut index = 0;
+    lines.retain(|l| {
+      index += 1;
+      !(index > start && index <= end && l.trim().starts_with("#[prost("))
+    });
+  }
+
+  let mut code = lines.join("\n");
+  code.push('\n');
+  code.push_str(&impls);
+  Ok(code)
+}
+
+fn parse_fields(message: &str, lines: &[String], secrets: &BTreeSet<String>) -> Result<Vec<Field>> {
+  let mut fields = Vec::new();
+  let mut cfg = Vec::new();
+  let mut prost = None;
+
+  for line in lines.iter().map(|l| l.trim()) {
+    if line == "}" {
+      break;
+    } else if line.starts_with("///") {
+      continue;
+    } else if line.starts_with("#[cfg") {
+      cfg.push(line.to_owned());
+    } else if let Some(attr) = line.strip_prefix("#[prost(") {
+      prost = Some(attr.trim_end_matches(")]").to_owned());
+    } else if let Some(decl) = line.strip_prefix("pub ") {
+      let name = decl.split(':').next().unwrap_or_default().trim().to_owned();
+      let attr = match prost.take() {
+        Some(attr) => attr,
+        None => bail!("{}.{} has no prost attribute", message, name),
+      };
+
+      let (kind, tag) = parse_attr(&attr)
+        .ok_or_else(|| anyhow::anyhow!("{}.{} has an unsupported type: {}", message, name, attr))?;
+      let secret = secrets.contains(name.trim_start_matches("r#"));
+      fields.push(Field {
+        name,
+        cfg: std::mem::take(&mut cfg),
+        kind,
+        tag,
+        secret,
+      });
+    }
+  }
+
+  for secret in secrets {
+    if !fields
+      .iter()
+      .any(|f| f.secret && f.name.trim_start_matches("r#") == secret)
+    {
+      bail!("secret field {}.{} not found", message, secret);
+    }
+  }
+
+  Ok(fields)
+}
+
+/// Parse the arguments of a `#[prost(..)]` attribute, like
+/// `map = "string, string", tag = "5"`.
+fn parse_attr(attr: &str) -> Option<(Kind, u32)> {
+  let mut args = Vec::new();
+  let mut quoted = false;
+  let mut arg = String::new();
+  for c in attr.chars() {
+    match c {
+      '"' => quoted = !quoted,
+      ',' if !quoted => args.push(std::mem::take(&mut arg)),
+      ' ' => {}
+      c => arg.push(c),
+    }
+  }
+  args.push(arg);
+
+  let tag = args
+    .iter()
+    .find_map(|a| a.strip_prefix("tag="))?
+    .parse()
+    .ok()?;
+  let args = args
+    .iter()
+    .filter(|a| !a.starts_with("tag="))
+    .map(String::as_str)
+    .collect::<Vec<_>>();
+
+  let kind = match args.as_slice() {
+    ["string"] => Kind::Scalar {
+      module: "string".to_owned(),
+      set: "!{}.is_empty()",
+    },
+    ["bool"] => Kind::Scalar {
+      module: "bool".to_owned(),
+      set: "{}",
+    },
+    [ty @ ("int32" | "int64" | "uint32" | "uint64")] => Kind::Scalar {
+      module: (*ty).to_owned(),
+      set: "{} != 0",
+    },
+    ["string", "repeated"] => Kind::RepeatedString,
+    ["map=string,string"] => Kind::StringMap,
+    ["message", "optional"] => Kind::Message,
+    ["message", "repeated"] => Kind::RepeatedMessage,
+    _ => return None,
+  };
+
+  Some((kind, tag))
+}
+
+fn write_impls(buf: &mut String, message: &str, cfg: &[String], fields: &[Field]) {
+  let cfg = attributes(cfg);
+
+  let mut encode = String::new();
+  let mut merge = String::new();
+  let mut encoded_len = String::new();
+  let mut clear = String::new();
+  let mut debug = String::new();
+  for f in fields {
+    let (name, tag) = (&f.name, f.tag);
+    let label = name.trim_start_matches("r#");
+    let cfg = attributes(&f.cfg);
+    let (e, m, l, c) = match &f.kind {
+      Kind::Scalar { module, set } => (
+        format!(
+          "if {s} {{ ::prost::encoding::{m}::encode({t}, &self.{n}, buf); }}",
+          s = set.replace("{}", &format!("self.{}", name)),
+          n = name,
+          m = module,
+          t = tag
+        ),
+        format!(
+          "::prost::encoding::{}::merge(wire_type, &mut self.{}, buf, ctx)",
+          module, name
+        ),
+        format!(
+          "if {s} {{ ::prost::encoding::{m}::encoded_len({t}, &self.{n}) }} else {{ 0 }}",
+          s = set.replace("{}", &format!("self.{}", name)),
+          n = name,
+          m = module,
+          t = tag
+        ),
+        format!("self.{} = ::core::default::Default::default()", name),
+      ),
+      Kind::RepeatedString => (
+        format!(
+          "::prost::encoding::string::encode_repeated({}, &self.{}, buf);",
+          tag, name
+        ),
+        format!(
+          "::prost::encoding::string::merge_repeated(wire_type, &mut self.{}, buf, ctx)",
+          name
+        ),
+        format!(
+          "::prost::encoding::string::encoded_len_repeated({}, &self.{})",
+          tag, name
+        ),
+        format!("self.{}.clear()", name),
+      ),
+      Kind::StringMap => (
+        format!(
+          "::prost::encoding::hash_map::encode(::prost::encoding::string::encode, ::prost::encoding::string::encoded_len, ::prost::encoding::string::encode, ::prost::encoding::string::encoded_len, {}, &self.{}, buf);",
+          tag, name
+        ),
+        format!(
+          "::prost::encoding::hash_map::merge(::prost::encoding::string::merge, ::prost::encoding::string::merge, &mut self.{}, buf, ctx)",
+          name
+        ),
+        format!(
+          "::prost::encoding::hash_map::encoded_len(::prost::encoding::string::encoded_len, ::prost::encoding::string::encoded_len, {}, &self.{})",
+          tag, name
+        ),
+        format!("self.{}.clear()", name),
+      ),
+      Kind::Message => (
+        format!(
+          "if let Some(ref msg) = self.{} {{ ::prost::encoding::message::encode({}, msg, buf); }}",
+          name, tag
+        ),
+        format!(
+          "::prost::encoding::message::merge(wire_type, self.{}.get_or_insert_with(Default::default), buf, ctx)",
+          name
+        ),
+        format!(
+          "self.{}.as_ref().map_or(0, |msg| ::prost::encoding::message::encoded_len({}, msg))",
+          name, tag
+        ),
+        format!("self.{} = ::core::option::Option::None", name),
+      ),
+      Kind::RepeatedMessage => (
+        format!(
+          "for msg in &self.{} {{ ::prost::encoding::message::encode({}, msg, buf); }}",
+          name, tag
+        ),
+        format!(
+          "::prost::encoding::message::merge_repeated(wire_type, &mut self.{}, buf, ctx)",
+          name
+        ),
+        format!(
+          "::prost::encoding::message::encoded_len_repeated({}, &self.{})",
+          tag, name
+        ),
+        format!("self.{}.clear()", name),
+      ),
+    };
+
+    let _ = writeln!(encode, "{}{}", cfg, e);
+    let _ = writeln!(
+      merge,
+      "{}{} => {}.map_err(|mut error| {{ error.push(STRUCT_NAME, {:?}); error }}),",
+      cfg, tag, m, label
+    );
+    let _ = writeln!(encoded_len, "{}len += {};", cfg, l);
+    let _ = writeln!(clear, "{}{};", cfg, c);
+    let value = match (&f.kind, f.secret) {
+      (Kind::StringMap, true) => format!(
+        "&self.{}.keys().map(|k| (k, \"SECRET\")).collect::<::std::collections::BTreeMap<_, _>>()",
+        name
+      ),
+      (_, true) => "&\"SECRET\"".to_owned(),
+      (_, false) => format!("&self.{}", name),
+    };
+    let _ = writeln!(debug, "{}builder.field({:?}, {});", cfg, label, value);
+  }
+
+  let _ = write!(
+    buf,
+    r#"
+{cfg}impl ::prost::Message for {name} {{
+  fn encode_raw<B>(&self, buf: &mut B) where B: ::prost::bytes::BufMut {{
+    {encode}
+  }}
+
+  fn merge_field<B>(
+    &mut self,
+    tag: u32,
+    wire_type: ::prost::encoding::WireType,
+    buf: &mut B,
+    ctx: ::prost::encoding::DecodeContext,
+  ) -> ::core::result::Result<(), ::prost::DecodeError> where B: ::prost::bytes::Buf {{
+    const STRUCT_NAME: &str = {name:?};
+    match tag {{
+      {merge}
+      _ => ::prost::encoding::skip_field(wire_type, tag, buf, ctx),
+    }}
+  }}
+
+  fn encoded_len(&self) -> usize {{
+    let mut len = 0;
+    {encoded_len}
+    len
+  }}
+
+  fn clear(&mut self) {{
+    {clear}
+  }}
+}}
+
+/// The fields marked with the `csi_secret` option are redacted.
+{cfg}impl ::core::fmt::Debug for {name} {{
+  fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {{
+    let mut builder = f.debug_struct({name:?});
+    {debug}
+    builder.finish()
+  }}
+}}
+"#,
+    cfg = cfg,
+    name = message,
+    encode = encode,
+    merge = merge,
+    encoded_len = encoded_len,
+    clear = clear,
+    debug = debug,
+  );
+}
+
+/// Attributes each on their own line, followed by a newline.
+fn attributes(attrs: &[String]) -> String {
+  attrs.iter().map(|a| format!("{}\n", a)).collect()
+}
@@ -5,18 +5,25 @@ mod delete_snapshot;
 mod delete_volume;
 mod expand_volume;
 mod get_capacity;
+#[cfg(feature = "alpha")]
 mod get_volume;
 mod list_snapshots;
 mod list_volumes;
+mod pagination;
 mod publish_volume;
 mod snapshot;
+mod supported_capabilities;
 mod unpublish_volume;
 mod validate_volume_capabilities;
 
+#[cfg(feature = "alpha")]
+use crate::node::NodeCapabilities;
 use crate::{
-  plugin, proto,
+  plugin::require_volume_expansion,
+  proto,
+  response::{Advertised, ValidateResponse},
   secrets::*,
-  utils::{record_request, Record},
+  server::{Dispatch, OperationKey},
   IdentityService,
 };
 use async_trait::async_trait;
@@ -31,22 +38,46 @@ pub use delete_snapshot::*;
 pub use delete_volume::*;
 pub use expand_volume::*;
 pub use get_capacity::*;
+#[cfg(feature = "alpha")]
 pub use get_volume::*;
 pub use list_snapshots::*;
 pub use list_volumes::*;
+pub use pagination::*;
 pub use publish_volume::*;
 pub use snapshot::*;
+pub use supported_capabilities::*;
 pub use unpublish_volume::*;
 pub use validate_volume_capabilities::*;
 
 #[async_trait]
 pub trait ControllerService: IdentityService {
-  /// Get the set of services provided by this controller.
+  /// Get the set of services provided by this controller. Calls of RPCs that
+  /// require a capability that is not returned are rejected with
+  /// `UNIMPLEMENTED` before they reach the plugin, and capabilities
+  /// advertised without those they depend on are logged as warnings when
+  /// the server starts.
   #[inline]
   fn capabilities(&self) -> ControllerCapabilities {
     ControllerCapabilities::empty()
   }
 
+  /// The RPC methods the plugin implements, of those that require a
+  /// capability. When returned, methods implemented without advertising
+  /// their capability, and advertised capabilities whose methods are not
+  /// implemented, are logged as warnings when the server starts.
+  #[inline]
+  fn implemented_methods(&self) -> Option<ControllerMethods> {
+    None
+  }
+
+  /// The volume capabilities the plugin supports. When returned, `CreateVolume`
+  /// calls requesting an unsupported capability are rejected with
+  /// `INVALID_ARGUMENT` before they reach the plugin.
+  #[inline]
+  fn supported_capabilities(&self) -> Option<&SupportedCapabilities> {
+    None
+  }
+
   /// A Controller Plugin MUST implement this RPC call if it has `CREATE_DELETE_VOLUME`
   /// controller capability.
   ///
@@ -153,6 +184,9 @@ pub trait ControllerService: IdentityService {
   /// communicating using a newer, backwards-compatible version of the CSI protobufs.
   /// Therefore, the CO SHALL reconcile successful capability-validation responses by
   /// comparing the validated capabilities with those that it had originally requested.
+  ///
+  /// Once the plugin has found the volume, [`SupportedCapabilities::validate`]
+  /// answers the call for volumes that support all of `supported_capabilities`.
   async fn validate_volume_capabilities(
     &self,
     request: ValidateVolumeCapabilitiesRequest,
@@ -165,6 +199,8 @@ pub trait ControllerService: IdentityService {
   /// volumes in the list, not witness existing volumes, or both. The CO SHALL NOT expect
   /// a consistent "view" of all volumes when paging through the volume list via multiple
   /// calls to ListVolumes.
+  ///
+  /// A [`Paginator`] can build the pages and tokens from all the volumes.
   #[allow(unused_variables)]
   async fn list_volumes(
     &self,
@@ -260,6 +296,8 @@ pub trait ControllerService: IdentityService {
   ///
   /// The CO SHALL NOT expect a consistent "view" of all snapshots when paging through
   /// the snapshot list via multiple calls to `ListSnapshots`.
+  ///
+  /// A [`Paginator`] can build the pages and tokens from all the snapshots.
   #[allow(unused_variables)]
   async fn list_snapshots(
     &self,
@@ -317,6 +355,7 @@ pub trait ControllerService: IdentityService {
   /// `ControllerGetVolumeResponse` should contain current information of a volume
   /// if it exists. If the volume does not exist any more, `ControllerGetVolume`
   /// should return gRPC error code `NOT_FOUND`.
+  #[cfg(feature = "alpha")]
   #[allow(unused_variables)]
   async fn controller_get_volume(
     &self,
@@ -326,56 +365,32 @@ pub trait ControllerService: IdentityService {
   }
 }
 
-struct Controller<T: ControllerService>(Arc<T>);
-
-#[async_trait]
-impl<T: ControllerService> proto::identity_server::Identity for Controller<T> {
-  #[instrument(
-    name = "identity.get_plugin_info",
-    skip(self, _request),
-    fields(name, vendor_version, manifest)
-  )]
-  async fn get_plugin_info(
-    &self,
-    _request: tonic::Request<proto::GetPluginInfoRequest>,
-  ) -> Result<tonic::Response<proto::GetPluginInfoResponse>, tonic::Status> {
-    let response = proto::GetPluginInfoResponse {
-      name: self.0.name().record_field("name").into(),
-      vendor_version: self.0.version().record_field("vendor_version").into(),
-      manifest: self.0.manifest().record_field("manifest").clone(),
-    };
+pub(crate) struct Controller<T: ControllerService> {
+  pub(crate) plugin: Arc<T>,
+  pub(crate) dispatch: Arc<Dispatch>,
+}
 
-    Ok(tonic::Response::new(response))
+impl<T: ControllerService> Controller<T> {
+  fn advertised(&self) -> Advertised {
+    Advertised {
+      accessibility_constraints: self.plugin.volume_accessibility_constraints_support(),
+      controller: ControllerCapabilities::advertised(&*self.plugin),
+      #[cfg(feature = "alpha")]
+      node: NodeCapabilities::empty(),
+    }
   }
 
-  // TODO: Instrument response
-  #[instrument(name = "identity.get_plugin_capabilities", skip(self, _request))]
-  async fn get_plugin_capabilities(
-    &self,
-    _request: tonic::Request<proto::GetPluginCapabilitiesRequest>,
-  ) -> Result<tonic::Response<proto::GetPluginCapabilitiesResponse>, tonic::Status> {
-    let mut response = plugin::get_capabilities(&*self.0);
-    response.capabilities.push(proto::PluginCapability {
-      r#type: Some(proto::plugin_capability::Type::Service(
-        proto::plugin_capability::Service {
-          r#type: proto::plugin_capability::service::Type::ControllerService.into(),
-        },
-      )),
-    });
-
-    Ok(tonic::Response::new(response))
-  }
-
-  #[instrument(name = "identity.probe", skip(self, _request), fields(ready))]
-  async fn probe(
-    &self,
-    _request: tonic::Request<proto::ProbeRequest>,
-  ) -> Result<tonic::Response<proto::ProbeResponse>, tonic::Status> {
-    let response = proto::ProbeResponse {
-      ready: Some(self.0.ready().record_field("ready")),
-    };
-
-    Ok(tonic::Response::new(response))
+  /// Fails with `UNIMPLEMENTED` unless the plugin advertises `capability`,
+  /// so the plugin is only called for the RPCs it advertises.
+  fn require(&self, method: &str, capability: ControllerCapabilities) -> Result<(), tonic::Status> {
+    if ControllerCapabilities::advertised(&*self.plugin).contains(capability) {
+      return Ok(());
+    }
+
+    Err(tonic::Status::unimplemented(format!(
+      "{} is not supported, the {:?} capability is not advertised",
+      method, capability
+    )))
   }
 }
 
@@ -384,229 +399,425 @@ impl<T: ControllerService> proto::controller_server::Controller for Controller<T
   #[instrument(
     name = "controller.create_volume",
     skip(self, request),
-    fields(request, response)
+    fields(request, response, code, error, latency_ms)
   )]
   async fn create_volume(
     &self,
     request: tonic::Request<proto::CreateVolumeRequest>,
   ) -> Result<tonic::Response<proto::CreateVolumeResponse>, tonic::Status> {
-    let request = record_request(request.into_inner().try_into()?);
-    let response = self
-      .0
-      .create_volume(request)
-      .await?
-      .record_response()
-      .try_into()?;
-    Ok(tonic::Response::new(response))
+    self
+      .dispatch
+      .call("CreateVolume", request, |request, _call| async move {
+        self.require("CreateVolume", ControllerCapabilities::CREATE_DELETE_VOLUME)?;
+        let request = self.dispatch.check_request(request)?;
+        let request: CreateVolumeRequest = request.try_into()?;
+        if let Some(supported) = self.plugin.supported_capabilities() {
+          supported
+            .check_all(request.volume_capabilities())
+            .map_err(|e| {
+              tonic::Status::invalid_argument(format!("Unsupported volume capability: {}", e))
+            })?;
+        }
+        let _lock = self
+          .dispatch
+          .lock(OperationKey::VolumeName(request.name().to_owned()))?;
+        let response = self
+          .plugin
+          .create_volume(request)
+          .await?
+          .validated("CreateVolume", &self.advertised())?
+          .try_into()?;
+        let response = self.dispatch.check_response(response)?;
+        Ok(response)
+      })
+      .await
   }
 
   #[instrument(
     name = "controller.delete_volume",
     skip(self, request),
-    fields(request)
+    fields(request, response, code, error, latency_ms)
   )]
   async fn delete_volume(
     &self,
     request: tonic::Request<proto::DeleteVolumeRequest>,
   ) -> Result<tonic::Response<proto::DeleteVolumeResponse>, tonic::Status> {
-    let request = record_request(request.into_inner().try_into()?);
-    self.0.delete_volume(request).await?;
-    let response = proto::DeleteVolumeResponse {};
-    Ok(tonic::Response::new(response))
+    self
+      .dispatch
+      .call("DeleteVolume", request, |request, _call| async move {
+        self.require("DeleteVolume", ControllerCapabilities::CREATE_DELETE_VOLUME)?;
+        let request = self.dispatch.check_request(request)?;
+        let request: DeleteVolumeRequest = request.try_into()?;
+        let _lock = self
+          .dispatch
+          .lock(OperationKey::VolumeId(request.volume_id().to_owned()))?;
+        self.plugin.delete_volume(request).await?;
+        let response = proto::DeleteVolumeResponse {};
+        Ok(response)
+      })
+      .await
   }
 
   #[instrument(
     name = "controller.controller_publish_volume",
     skip(self, request),
-    fields(request, response)
+    fields(request, response, code, error, latency_ms)
   )]
   async fn controller_publish_volume(
     &self,
     request: tonic::Request<proto::ControllerPublishVolumeRequest>,
   ) -> Result<tonic::Response<proto::ControllerPublishVolumeResponse>, tonic::Status> {
-    let request = record_request(request.into_inner().try_into()?);
-    let response = self
-      .0
-      .controller_publish_volume(request)
-      .await?
-      .record_response()
-      .try_into()?;
-    Ok(tonic::Response::new(response))
+    self
+      .dispatch
+      .call(
+        "ControllerPublishVolume",
+        request,
+        |request, _call| async move {
+          self.require(
+            "ControllerPublishVolume",
+            ControllerCapabilities::PUBLISH_UNPUBLISH_VOLUME,
+          )?;
+          let request = self.dispatch.check_request(request)?;
+          let request: ControllerPublishVolumeRequest = request.try_into()?;
+          let _lock = self
+            .dispatch
+            .lock(OperationKey::VolumeId(request.volume_id().to_owned()))?;
+          let response = self
+            .plugin
+            .controller_publish_volume(request)
+            .await?
+            .try_into()?;
+          let response = self.dispatch.check_response(response)?;
+          Ok(response)
+        },
+      )
+      .await
   }
 
   #[instrument(
     name = "controller.controller_unpublish_volume",
     skip(self, request),
-    fields(request)
+    fields(request, response, code, error, latency_ms)
   )]
   async fn controller_unpublish_volume(
     &self,
     request: tonic::Request<proto::ControllerUnpublishVolumeRequest>,
   ) -> Result<tonic::Response<proto::ControllerUnpublishVolumeResponse>, tonic::Status> {
-    let request = record_request(request.into_inner().try_into()?);
-    self.0.controller_unpublish_volume(request).await?;
-    let response = proto::ControllerUnpublishVolumeResponse {};
-    Ok(tonic::Response::new(response))
+    self
+      .dispatch
+      .call(
+        "ControllerUnpublishVolume",
+        request,
+        |request, _call| async move {
+          self.require(
+            "ControllerUnpublishVolume",
+            ControllerCapabilities::PUBLISH_UNPUBLISH_VOLUME,
+          )?;
+          let request = self.dispatch.check_request(request)?;
+          let request: ControllerUnpublishVolumeRequest = request.try_into()?;
+          let _lock = self
+            .dispatch
+            .lock(OperationKey::VolumeId(request.volume_id().to_owned()))?;
+          self.plugin.controller_unpublish_volume(request).await?;
+          let response = proto::ControllerUnpublishVolumeResponse {};
+          Ok(response)
+        },
+      )
+      .await
   }
 
   #[instrument(
     name = "controller.validate_volume_capabilities",
     skip(self, request),
-    fields(request, response)
+    fields(request, response, code, error, latency_ms)
   )]
   async fn validate_volume_capabilities(
     &self,
     request: tonic::Request<proto::ValidateVolumeCapabilitiesRequest>,
   ) -> Result<tonic::Response<proto::ValidateVolumeCapabilitiesResponse>, tonic::Status> {
-    let request = record_request(request.into_inner().try_into()?);
-    let response = self
-      .0
-      .validate_volume_capabilities(request)
-      .await?
-      .record_response()
-      .try_into()?;
-    Ok(tonic::Response::new(response))
+    self
+      .dispatch
+      .call(
+        "ValidateVolumeCapabilities",
+        request,
+        |request, _call| async move {
+          let request = self.dispatch.check_request(request)?;
+          let request = request.try_into()?;
+          let response = self
+            .plugin
+            .validate_volume_capabilities(request)
+            .await?
+            .try_into()?;
+          let response = self.dispatch.check_response(response)?;
+          Ok(response)
+        },
+      )
+      .await
   }
 
   #[instrument(
     name = "controller.list_volumes",
     skip(self, request),
-    fields(request, response)
+    fields(request, response, code, error, latency_ms)
   )]
   async fn list_volumes(
     &self,
     request: tonic::Request<proto::ListVolumesRequest>,
   ) -> Result<tonic::Response<proto::ListVolumesResponse>, tonic::Status> {
-    let request = record_request(request.into_inner().try_into()?);
-    let response = self
-      .0
-      .list_volumes(request)
-      .await?
-      .record_response()
-      .try_into()?;
-    Ok(tonic::Response::new(response))
+    self
+      .dispatch
+      .call("ListVolumes", request, |request, _call| async move {
+        self.require("ListVolumes", ControllerCapabilities::LIST_VOLUMES)?;
+        let request = self.dispatch.check_request(request)?;
+        let request = request.try_into()?;
+        let response = self
+          .plugin
+          .list_volumes(request)
+          .await?
+          .validated("ListVolumes", &self.advertised())?
+          .try_into()?;
+        let response = self.dispatch.check_response(response)?;
+        Ok(response)
+      })
+      .await
   }
 
   #[instrument(
     name = "controller.get_capacity",
     skip(self, request),
-    fields(request, response)
+    fields(request, response, code, error, latency_ms)
   )]
   async fn get_capacity(
     &self,
     request: tonic::Request<proto::GetCapacityRequest>,
   ) -> Result<tonic::Response<proto::GetCapacityResponse>, tonic::Status> {
-    let request = record_request(request.into_inner().try_into()?);
-    let response = self
-      .0
-      .get_capacity(request)
-      .await?
-      .record_response()
-      .try_into()?;
-    Ok(tonic::Response::new(response))
+    self
+      .dispatch
+      .call("GetCapacity", request, |request, _call| async move {
+        self.require("GetCapacity", ControllerCapabilities::GET_CAPACITY)?;
+        let request = self.dispatch.check_request(request)?;
+        let request = request.try_into()?;
+        let response = self.plugin.get_capacity(request).await?.try_into()?;
+        let response = self.dispatch.check_response(response)?;
+        Ok(response)
+      })
+      .await
   }
 
   #[instrument(
     name = "controller.controller_get_capabilities",
-    skip(self),
-    fields(response)
+    skip(self, request),
+    fields(request, response, code, error, latency_ms)
   )]
   async fn controller_get_capabilities(
     &self,
-    _: tonic::Request<proto::ControllerGetCapabilitiesRequest>,
+    request: tonic::Request<proto::ControllerGetCapabilitiesRequest>,
   ) -> Result<tonic::Response<proto::ControllerGetCapabilitiesResponse>, tonic::Status> {
-    let response = self.0.capabilities().record_response().try_into()?;
-    Ok(tonic::Response::new(response))
+    self
+      .dispatch
+      .call(
+        "ControllerGetCapabilities",
+        request,
+        |_, _call| async move {
+          let response = ControllerCapabilities::advertised(&*self.plugin).try_into()?;
+          Ok(response)
+        },
+      )
+      .await
   }
 
   #[instrument(
     name = "controller.create_snapshot",
     skip(self, request),
-    fields(request, response)
+    fields(request, response, code, error, latency_ms)
   )]
   async fn create_snapshot(
     &self,
     request: tonic::Request<proto::CreateSnapshotRequest>,
   ) -> Result<tonic::Response<proto::CreateSnapshotResponse>, tonic::Status> {
-    let request = record_request(request.into_inner().try_into()?);
-    let response = self
-      .0
-      .create_snapshot(request)
-      .await?
-      .record_response()
-      .try_into()?;
-    Ok(tonic::Response::new(response))
+    self
+      .dispatch
+      .call("CreateSnapshot", request, |request, _call| async move {
+        self.require(
+          "CreateSnapshot",
+          ControllerCapabilities::CREATE_DELETE_SNAPSHOT,
+        )?;
+        let request = self.dispatch.check_request(request)?;
+        let request: CreateSnapshotRequest = request.try_into()?;
+        let _lock = self
+          .dispatch
+          .lock(OperationKey::SnapshotName(request.name().to_owned()))?;
+        let response = self.plugin.create_snapshot(request).await?.try_into()?;
+        let response = self.dispatch.check_response(response)?;
+        Ok(response)
+      })
+      .await
   }
 
   #[instrument(
     name = "controller.delete_snapshot",
     skip(self, request),
-    fields(request)
+    fields(request, response, code, error, latency_ms)
   )]
   async fn delete_snapshot(
     &self,
     request: tonic::Request<proto::DeleteSnapshotRequest>,
   ) -> Result<tonic::Response<proto::DeleteSnapshotResponse>, tonic::Status> {
-    let request = record_request(request.into_inner().try_into()?);
-    self.0.delete_snapshot(request).await?;
-    let response = proto::DeleteSnapshotResponse {};
-    Ok(tonic::Response::new(response))
+    self
+      .dispatch
+      .call("DeleteSnapshot", request, |request, _call| async move {
+        self.require(
+          "DeleteSnapshot",
+          ControllerCapabilities::CREATE_DELETE_SNAPSHOT,
+        )?;
+        let request = self.dispatch.check_request(request)?;
+        let request: DeleteSnapshotRequest = request.try_into()?;
+        let _lock = self
+          .dispatch
+          .lock(OperationKey::SnapshotId(request.snapshot_id().to_owned()))?;
+        self.plugin.delete_snapshot(request).await?;
+        let response = proto::DeleteSnapshotResponse {};
+        Ok(response)
+      })
+      .await
   }
 
   #[instrument(
     name = "controller.list_snapshots",
     skip(self, request),
-    fields(request, response)
+    fields(request, response, code, error, latency_ms)
   )]
   async fn list_snapshots(
     &self,
     request: tonic::Request<proto::ListSnapshotsRequest>,
   ) -> Result<tonic::Response<proto::ListSnapshotsResponse>, tonic::Status> {
-    let request = record_request(request.into_inner().try_into()?);
-    let response = self
-      .0
-      .list_snapshots(request)
-      .await?
-      .record_response()
-      .try_into()?;
-    Ok(tonic::Response::new(response))
+    self
+      .dispatch
+      .call("ListSnapshots", request, |request, _call| async move {
+        self.require("ListSnapshots", ControllerCapabilities::LIST_SNAPSHOTS)?;
+        let request = self.dispatch.check_request(request)?;
+        let request = request.try_into()?;
+        let response = self.plugin.list_snapshots(request).await?.try_into()?;
+        let response = self.dispatch.check_response(response)?;
+        Ok(response)
+      })
+      .await
   }
 
   #[instrument(
     name = "controller.controller_expand_volume",
     skip(self, request),
-    fields(request, response)
+    fields(request, response, code, error, latency_ms)
   )]
   async fn controller_expand_volume(
     &self,
     request: tonic::Request<proto::ControllerExpandVolumeRequest>,
   ) -> Result<tonic::Response<proto::ControllerExpandVolumeResponse>, tonic::Status> {
-    let request = record_request(request.into_inner().try_into()?);
-    let response = self
-      .0
-      .controller_expand_volume(request)
-      .await?
-      .record_response()
-      .try_into()?;
-    Ok(tonic::Response::new(response))
+    self
+      .dispatch
+      .call(
+        "ControllerExpandVolume",
+        request,
+        |request, _call| async move {
+          self.require(
+            "ControllerExpandVolume",
+            ControllerCapabilities::EXPAND_VOLUME,
+          )?;
+          require_volume_expansion(&*self.plugin, "ControllerExpandVolume")?;
+          let request = self.dispatch.check_request(request)?;
+          let request: ControllerExpandVolumeRequest = request.try_into()?;
+          let _lock = self
+            .dispatch
+            .lock(OperationKey::VolumeId(request.volume_id().to_owned()))?;
+          let response = self
+            .plugin
+            .controller_expand_volume(request)
+            .await?
+            .try_into()?;
+          let response = self.dispatch.check_response(response)?;
+          Ok(response)
+        },
+      )
+      .await
   }
 
+  #[cfg(feature = "alpha")]
   #[instrument(
     name = "controller.controller_get_volume",
     skip(self, request),
-    fields(request, response)
+    fields(request, response, code, error, latency_ms)
   )]
   async fn controller_get_volume(
     &self,
     request: tonic::Request<proto::ControllerGetVolumeRequest>,
   ) -> Result<tonic::Response<proto::ControllerGetVolumeResponse>, tonic::Status> {
-    let request = record_request(request.into_inner().try_into()?);
-    let response = self
-      .0
-      .controller_get_volume(request)
-      .await?
-      .record_response()
-      .try_into()?;
-    Ok(tonic::Response::new(response))
+    self
+      .dispatch
+      .call(
+        "ControllerGetVolume",
+        request,
+        |request, _call| async move {
+          self.require("ControllerGetVolume", ControllerCapabilities::GET_VOLUME)?;
+          let request = self.dispatch.check_request(request)?;
+          let request = request.try_into()?;
+          let response = self
+            .plugin
+            .controller_get_volume(request)
+            .await?
+            .validated("ControllerGetVolume", &self.advertised())?
+            .try_into()?;
+          let response = self.dispatch.check_response(response)?;
+          Ok(response)
+        },
+      )
+      .await
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use test_case::test_case;
+  use tonic::Code;
+
+  struct Plugin;
+
+  impl IdentityService for Plugin {
+    fn name(&self) -> &str {
+      "csi.example.com"
+    }
+
+    fn version(&self) -> &str {
+      "0.1.0"
+    }
+  }
+
+  #[async_trait]
+  impl ControllerService for Plugin {
+    fn capabilities(&self) -> ControllerCapabilities {
+      ControllerCapabilities::CREATE_DELETE_VOLUME | ControllerCapabilities::LIST_VOLUMES
+    }
+
+    async fn validate_volume_capabilities(
+      &self,
+      request: ValidateVolumeCapabilitiesRequest,
+    ) -> Result<ValidateVolumeCapabilitiesResponse, ValidateVolumeCapabilitiesError> {
+      Err(ValidateVolumeCapabilitiesError::VolumeNotFound(
+        request.volume_id().to_owned(),
+      ))
+    }
+  }
+
+  #[test_case(ControllerCapabilities::LIST_VOLUMES => Ok(()) ; "advertised")]
+  #[test_case(ControllerCapabilities::GET_CAPACITY => Err(Code::Unimplemented) ; "not advertised")]
+  fn requires_capability(capability: ControllerCapabilities) -> Result<(), Code> {
+    let controller = Controller {
+      plugin: Arc::new(Plugin),
+      dispatch: Arc::new(Dispatch::default()),
+    };
+
+    controller
+      .require("Method", capability)
+      .map_err(|e| e.code())
   }
 }
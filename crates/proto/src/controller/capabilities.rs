@@ -2,7 +2,8 @@ use std::convert::TryFrom;
 
 use bitflags::bitflags;
 
-use crate::proto;
+use crate::{proto, ControllerService, VolumeExpansionSupport};
+use tracing::warn;
 
 #[rustfmt::skip]
 bitflags! {
@@ -47,12 +48,160 @@ bitflags! {
     /// is the truth.
     /// Note that, for alpha, `VolumeCondition` is intended be
     /// informative for humans only, not for automation.
+    #[cfg(feature = "alpha")]
     const VOLUME_CONDITION             = 0b_0000_0100_0000_0000;
 
     /// Indicates the SP supports the ControllerGetVolume RPC.
     /// This enables COs to, for example, fetch per volume
     /// condition after a volume is provisioned.
+    #[cfg(feature = "alpha")]
     const GET_VOLUME                   = 0b_0000_1000_0000_0000;
+
+    /// Indicates the SP supports the SINGLE_NODE_SINGLE_WRITER and/or
+    /// SINGLE_NODE_MULTI_WRITER access modes.
+    /// These access modes are intended to replace the
+    /// SINGLE_NODE_WRITER access mode to clarify the number of writers
+    /// for a volume on a single node. Plugins MUST accept and allow
+    /// use of the SINGLE_NODE_WRITER access mode when either
+    /// SINGLE_NODE_SINGLE_WRITER and/or SINGLE_NODE_MULTI_WRITER are
+    /// supported, in order to permit older COs to continue working.
+    const SINGLE_NODE_MULTI_WRITER     = 0b_0001_0000_0000_0000;
+  }
+}
+
+#[rustfmt::skip]
+bitflags! {
+  /// The RPC methods of [`ControllerService`] that a plugin implements
+  /// only if it advertises their capability, see
+  /// [`ControllerService::implemented_methods`].
+  pub struct ControllerMethods: u32 {
+    const CREATE_VOLUME                = 0b_0000_0000_0000_0001;
+    const DELETE_VOLUME                = 0b_0000_0000_0000_0010;
+    const CONTROLLER_PUBLISH_VOLUME    = 0b_0000_0000_0000_0100;
+    const CONTROLLER_UNPUBLISH_VOLUME  = 0b_0000_0000_0000_1000;
+    const LIST_VOLUMES                 = 0b_0000_0000_0001_0000;
+    const GET_CAPACITY                 = 0b_0000_0000_0010_0000;
+    const CREATE_SNAPSHOT              = 0b_0000_0000_0100_0000;
+    const DELETE_SNAPSHOT              = 0b_0000_0000_1000_0000;
+    const LIST_SNAPSHOTS               = 0b_0000_0001_0000_0000;
+    const CONTROLLER_EXPAND_VOLUME     = 0b_0000_0010_0000_0000;
+    #[cfg(feature = "alpha")]
+    const CONTROLLER_GET_VOLUME        = 0b_0000_0100_0000_0000;
+  }
+}
+
+impl ControllerMethods {
+  /// The capability each method requires.
+  #[rustfmt::skip]
+  const CAPABILITIES: &'static [(Self, ControllerCapabilities)] = &[
+    (Self::CREATE_VOLUME,               ControllerCapabilities::CREATE_DELETE_VOLUME),
+    (Self::DELETE_VOLUME,               ControllerCapabilities::CREATE_DELETE_VOLUME),
+    (Self::CONTROLLER_PUBLISH_VOLUME,   ControllerCapabilities::PUBLISH_UNPUBLISH_VOLUME),
+    (Self::CONTROLLER_UNPUBLISH_VOLUME, ControllerCapabilities::PUBLISH_UNPUBLISH_VOLUME),
+    (Self::LIST_VOLUMES,                ControllerCapabilities::LIST_VOLUMES),
+    (Self::GET_CAPACITY,                ControllerCapabilities::GET_CAPACITY),
+    (Self::CREATE_SNAPSHOT,             ControllerCapabilities::CREATE_DELETE_SNAPSHOT),
+    (Self::DELETE_SNAPSHOT,             ControllerCapabilities::CREATE_DELETE_SNAPSHOT),
+    (Self::LIST_SNAPSHOTS,              ControllerCapabilities::LIST_SNAPSHOTS),
+    (Self::CONTROLLER_EXPAND_VOLUME,    ControllerCapabilities::EXPAND_VOLUME),
+    #[cfg(feature = "alpha")]
+    (Self::CONTROLLER_GET_VOLUME,       ControllerCapabilities::GET_VOLUME),
+  ];
+
+  /// Split the methods that do not match `capabilities` into those that
+  /// are implemented without their capability, and those that are not
+  /// implemented although their capability is advertised.
+  fn mismatched(self, capabilities: ControllerCapabilities) -> (Self, Self) {
+    let mut unadvertised = Self::empty();
+    let mut unimplemented = Self::empty();
+    for &(method, capability) in Self::CAPABILITIES {
+      match (self.contains(method), capabilities.contains(capability)) {
+        (true, false) => unadvertised |= method,
+        (false, true) => unimplemented |= method,
+        _ => {}
+      }
+    }
+
+    (unadvertised, unimplemented)
+  }
+}
+
+impl ControllerCapabilities {
+  /// Capabilities marked as alpha in the CSI spec.
+  #[cfg(feature = "alpha")]
+  pub(crate) const ALPHA: Self =
+    Self::from_bits_truncate(Self::VOLUME_CONDITION.bits() | Self::GET_VOLUME.bits());
+
+  /// The capabilities advertised by `plugin`, leaving out alpha
+  /// capabilities unless the plugin has enabled alpha features.
+  pub(crate) fn advertised<T: ControllerService + ?Sized>(plugin: &T) -> Self {
+    let capabilities = plugin.capabilities();
+    #[cfg(feature = "alpha")]
+    let capabilities = if plugin.enable_alpha_features() {
+      capabilities
+    } else {
+      capabilities - Self::ALPHA
+    };
+
+    capabilities
+  }
+
+  /// Log a warning for each capability of `plugin` that is advertised
+  /// without the capabilities or plugin capabilities it depends on, for
+  /// alpha capabilities left out because alpha features are not enabled,
+  /// and for the methods the plugin declares as implemented that do not
+  /// match the advertised capabilities.
+  pub(crate) fn check<T: ControllerService + ?Sized>(plugin: &T) {
+    #[cfg(feature = "alpha")]
+    if !plugin.enable_alpha_features() && plugin.capabilities().intersects(Self::ALPHA) {
+      warn!(
+        capabilities = ?(plugin.capabilities() & Self::ALPHA),
+        "Not advertising alpha controller capabilities, alpha features are not enabled by the plugin"
+      );
+    }
+
+    let capabilities = Self::advertised(plugin);
+    let requires = |capability: Self, required: Self| {
+      if capabilities.contains(capability) && !capabilities.intersects(required) {
+        warn!(
+          ?capability,
+          ?required,
+          "Controller capability is advertised without the capability it depends on"
+        );
+      }
+    };
+
+    requires(Self::CLONE_VOLUME, Self::CREATE_DELETE_VOLUME);
+    requires(Self::PUBLISH_READONLY, Self::PUBLISH_UNPUBLISH_VOLUME);
+    requires(Self::LIST_VOLUMES_PUBLISHED_NODES, Self::LIST_VOLUMES);
+    #[cfg(feature = "alpha")]
+    requires(
+      Self::VOLUME_CONDITION,
+      Self::LIST_VOLUMES | Self::GET_VOLUME,
+    );
+
+    if capabilities.contains(Self::EXPAND_VOLUME)
+      && plugin.volume_expansion_support() == VolumeExpansionSupport::None
+    {
+      warn!("Controller capability EXPAND_VOLUME is advertised, but the plugin does not support volume expansion");
+    }
+
+    if let Some(methods) = plugin.implemented_methods() {
+      let (unadvertised, unimplemented) = methods.mismatched(capabilities);
+      if !unadvertised.is_empty() {
+        warn!(
+          methods = ?unadvertised,
+          "Controller methods are implemented, but their capabilities are not advertised"
+        );
+      }
+
+      if !unimplemented.is_empty() {
+        warn!(
+          methods = ?unimplemented,
+          "Controller capabilities are advertised, but their methods are not implemented"
+        );
+      }
+    }
   }
 }
 
@@ -79,7 +228,7 @@ impl TryFrom<ControllerCapabilities> for proto::ControllerGetCapabilitiesRespons
       }
     }
 
-    let mut capabilities = Vec::with_capacity(12 /* number of different capabilities */);
+    let mut capabilities = Vec::with_capacity(13 /* number of different capabilities */);
     push_cap(
       &mut capabilities,
       value,
@@ -140,19 +289,45 @@ impl TryFrom<ControllerCapabilities> for proto::ControllerGetCapabilitiesRespons
       ControllerCapabilities::LIST_VOLUMES_PUBLISHED_NODES,
       Type::ListVolumesPublishedNodes,
     );
+    #[cfg(feature = "alpha")]
     push_cap(
       &mut capabilities,
       value,
       ControllerCapabilities::VOLUME_CONDITION,
       Type::VolumeCondition,
     );
+    #[cfg(feature = "alpha")]
     push_cap(
       &mut capabilities,
       value,
       ControllerCapabilities::GET_VOLUME,
       Type::GetVolume,
     );
+    push_cap(
+      &mut capabilities,
+      value,
+      ControllerCapabilities::SINGLE_NODE_MULTI_WRITER,
+      Type::SingleNodeMultiWriter,
+    );
 
     Ok(proto::ControllerGetCapabilitiesResponse { capabilities })
   }
 }
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use test_case::test_case;
+
+  type Methods = ControllerMethods;
+  type Capabilities = ControllerCapabilities;
+
+  #[test_case(Methods::empty(), Capabilities::empty() => (Methods::empty(), Methods::empty()) ; "nothing")]
+  #[test_case(Methods::CREATE_VOLUME | Methods::DELETE_VOLUME, Capabilities::CREATE_DELETE_VOLUME => (Methods::empty(), Methods::empty()) ; "matching")]
+  #[test_case(Methods::LIST_VOLUMES, Capabilities::LIST_VOLUMES_PUBLISHED_NODES => (Methods::LIST_VOLUMES, Methods::empty()) ; "implemented without capability")]
+  #[test_case(Methods::CREATE_VOLUME, Capabilities::CREATE_DELETE_VOLUME | Capabilities::GET_CAPACITY => (Methods::empty(), Methods::DELETE_VOLUME | Methods::GET_CAPACITY) ; "advertised without method")]
+  #[test_case(Methods::CREATE_SNAPSHOT | Methods::DELETE_SNAPSHOT, Capabilities::LIST_SNAPSHOTS => (Methods::CREATE_SNAPSHOT | Methods::DELETE_SNAPSHOT, Methods::LIST_SNAPSHOTS) ; "both")]
+  fn mismatched_methods(methods: Methods, capabilities: Capabilities) -> (Methods, Methods) {
+    methods.mismatched(capabilities)
+  }
+}
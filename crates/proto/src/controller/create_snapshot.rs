@@ -33,8 +33,8 @@ impl CreateSnapshotRequest {
   /// This field is OPTIONAL. Refer to the `Secrets Requirements`
   /// section on how to use this field.
   #[inline]
-  pub fn secrets(&self) -> &HashMap<String, String> {
-    self.secrets.as_ref()
+  pub fn secrets(&self) -> &Secrets {
+    &self.secrets
   }
 
   /// Plugin specific parameters passed in as opaque key-value pairs.
@@ -53,6 +53,31 @@ impl CreateSnapshotRequest {
   }
 }
 
+#[cfg(feature = "test-util")]
+impl CreateSnapshotRequest {
+  #[inline]
+  pub fn new(source_volume_id: impl Into<String>, name: impl Into<String>) -> Self {
+    CreateSnapshotRequest {
+      source_volume_id: source_volume_id.into(),
+      name: name.into(),
+      secrets: Secrets::default(),
+      parameters: HashMap::new(),
+    }
+  }
+
+  #[inline]
+  pub fn with_secrets(mut self, secrets: HashMap<String, String>) -> Self {
+    self.secrets = secrets.into();
+    self
+  }
+
+  #[inline]
+  pub fn with_parameters(mut self, parameters: HashMap<String, String>) -> Self {
+    self.parameters = parameters;
+    self
+  }
+}
+
 impl TryFrom<proto::CreateSnapshotRequest> for CreateSnapshotRequest {
   type Error = tonic::Status;
 
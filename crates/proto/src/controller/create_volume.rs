@@ -53,6 +53,91 @@ impl TryFrom<proto::TopologyRequirement> for TopologyRequirement {
   }
 }
 
+impl TopologyRequirement {
+  /// The topologies the provisioned volume MUST be accessible from at
+  /// least one of, empty if there is no such requirement.
+  #[inline]
+  pub fn requisite(&self) -> &[Topology] {
+    match self {
+      TopologyRequirement::Requisite(requisite)
+      | TopologyRequirement::RequisiteAndPreffered { requisite, .. } => requisite,
+      TopologyRequirement::Preferred(_) => &[],
+    }
+  }
+
+  /// The topologies the plugin MUST attempt to make the provisioned volume
+  /// accessible from, in order from first to last.
+  #[inline]
+  pub fn preferred(&self) -> &[Topology] {
+    match self {
+      TopologyRequirement::Preferred(preferred)
+      | TopologyRequirement::RequisiteAndPreffered { preferred, .. } => preferred,
+      TopologyRequirement::Requisite(_) => &[],
+    }
+  }
+
+  /// Order the topologies the plugin can provision a volume in, as
+  /// `available`, by how they meet the requirement. A topology meets a
+  /// requested topology if all of its segments are in the requested one,
+  /// so a volume provisioned in `{region: R1}` is accessible from
+  /// `{region: R1, zone: Z2}`.
+  ///
+  /// The candidates are the available topologies that meet the preferred
+  /// topologies, in order, followed by those that meet the remaining
+  /// requisite topologies. Without requisite topologies the remaining
+  /// available topologies follow, as the plugin may choose any of them.
+  /// A plugin should provision the volume in the first candidate it is
+  /// able to, and fail with [`CreateVolumeError::UnableToProvision`] if
+  /// there is none.
+  pub fn candidates<'a>(&self, available: &'a [Topology]) -> Vec<&'a Topology> {
+    let mut taken = vec![false; available.len()];
+    let mut candidates = Vec::new();
+    let mut push = |index: usize, candidates: &mut Vec<&'a Topology>| {
+      if !taken[index] {
+        taken[index] = true;
+        candidates.push(&available[index]);
+      }
+    };
+
+    for requested in self.preferred().iter().chain(self.requisite()) {
+      for (index, topology) in available.iter().enumerate() {
+        if meets(topology, requested) {
+          push(index, &mut candidates);
+        }
+      }
+    }
+
+    if self.requisite().is_empty() {
+      for index in 0..available.len() {
+        push(index, &mut candidates);
+      }
+    }
+
+    candidates
+  }
+
+  /// Whether a volume accessible from `accessible_topology`, as returned in
+  /// the `Volume`, is accessible from at least one of the requisite
+  /// topologies. A volume without accessible topology is accessible from
+  /// all nodes.
+  pub fn is_satisfied_by(&self, accessible_topology: &[Topology]) -> bool {
+    let requisite = self.requisite();
+    requisite.is_empty()
+      || accessible_topology.is_empty()
+      || accessible_topology
+        .iter()
+        .any(|topology| requisite.iter().any(|requested| meets(topology, requested)))
+  }
+}
+
+/// Whether all segments of `topology` are in `requested`.
+#[inline]
+fn meets(topology: &Topology, requested: &Topology) -> bool {
+  topology
+    .iter()
+    .all(|(key, value)| requested.get(key) == Some(value))
+}
+
 #[derive(Debug)]
 pub struct CreateVolumeRequest {
   name: String,
@@ -140,8 +225,8 @@ impl CreateVolumeRequest {
   /// This field is OPTIONAL. Refer to the `Secrets Requirements`
   /// section on how to use this field.
   #[inline]
-  pub fn secrets(&self) -> &HashMap<String, String> {
-    self.secrets.as_ref()
+  pub fn secrets(&self) -> &Secrets {
+    &self.secrets
   }
 
   /// If specified, the new volume will be pre-populated with data from
@@ -168,6 +253,55 @@ impl CreateVolumeRequest {
   }
 }
 
+#[cfg(feature = "test-util")]
+impl CreateVolumeRequest {
+  #[inline]
+  pub fn new(name: impl Into<String>, volume_capabilities: Vec<VolumeCapability>) -> Self {
+    CreateVolumeRequest {
+      name: name.into(),
+      capacity_range: None,
+      volume_capabilities,
+      parameters: HashMap::new(),
+      secrets: Secrets::default(),
+      volume_content_source: None,
+      accessibility_requirements: None,
+    }
+  }
+
+  #[inline]
+  pub fn with_capacity_range(mut self, capacity_range: CapacityRange) -> Self {
+    self.capacity_range = Some(capacity_range);
+    self
+  }
+
+  #[inline]
+  pub fn with_parameters(mut self, parameters: HashMap<String, String>) -> Self {
+    self.parameters = parameters;
+    self
+  }
+
+  #[inline]
+  pub fn with_secrets(mut self, secrets: HashMap<String, String>) -> Self {
+    self.secrets = secrets.into();
+    self
+  }
+
+  #[inline]
+  pub fn with_volume_content_source(mut self, volume_content_source: VolumeContentSource) -> Self {
+    self.volume_content_source = Some(volume_content_source);
+    self
+  }
+
+  #[inline]
+  pub fn with_accessibility_requirements(
+    mut self,
+    accessibility_requirements: TopologyRequirement,
+  ) -> Self {
+    self.accessibility_requirements = Some(accessibility_requirements);
+    self
+  }
+}
+
 impl TryFrom<proto::CreateVolumeRequest> for CreateVolumeRequest {
   type Error = tonic::Status;
 
@@ -274,3 +408,93 @@ impl From<CreateVolumeError> for tonic::Status {
     }
   }
 }
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use test_case::test_case;
+
+  fn zone(zone: &str) -> Topology {
+    topology(&[("region", "R1"), ("zone", zone)])
+  }
+
+  fn topology(segments: &[(&str, &str)]) -> Topology {
+    segments
+      .iter()
+      .map(|(k, v)| (k.to_string(), v.to_string()))
+      .collect()
+  }
+
+  fn zones(zones: &[&str]) -> Vec<Topology> {
+    zones.iter().map(|z| zone(z)).collect()
+  }
+
+  fn names(candidates: Vec<&Topology>) -> Vec<String> {
+    candidates
+      .into_iter()
+      .map(|t| match t.get("zone") {
+        Some(zone) => zone.clone(),
+        None => t["region"].clone(),
+      })
+      .collect()
+  }
+
+  // The examples of the spec, for a volume accessible from a single zone.
+  #[test_case(
+    TopologyRequirement::Requisite(zones(&["Z2"])),
+    &["Z1", "Z2", "Z3"]
+    => vec!["Z2"] ; "single requisite")]
+  #[test_case(
+    TopologyRequirement::Requisite(zones(&["Z2", "Z3"])),
+    &["Z1", "Z2", "Z3"]
+    => vec!["Z2", "Z3"] ; "requisite")]
+  #[test_case(
+    TopologyRequirement::RequisiteAndPreffered {
+      requisite: zones(&["Z2", "Z3"]),
+      preferred: zones(&["Z3"]),
+    },
+    &["Z1", "Z2", "Z3"]
+    => vec!["Z3", "Z2"] ; "preferred before requisite")]
+  #[test_case(
+    TopologyRequirement::RequisiteAndPreffered {
+      requisite: zones(&["Z2", "Z3", "Z4"]),
+      preferred: zones(&["Z4", "Z2"]),
+    },
+    &["Z1", "Z2", "Z3"]
+    => vec!["Z2", "Z3"] ; "unavailable preferred")]
+  #[test_case(
+    TopologyRequirement::Preferred(zones(&["Z3"])),
+    &["Z1", "Z2", "Z3"]
+    => vec!["Z3", "Z1", "Z2"] ; "preferred before any")]
+  #[test_case(
+    TopologyRequirement::Requisite(zones(&["Z4"])),
+    &["Z1", "Z2", "Z3"]
+    => Vec::<String>::new() ; "none available")]
+  fn orders_candidates(requirement: TopologyRequirement, available: &[&str]) -> Vec<String> {
+    names(requirement.candidates(&zones(available)))
+  }
+
+  #[test]
+  fn broader_topologies_meet_requested() {
+    let requirement = TopologyRequirement::Requisite(zones(&["Z2"]));
+    let available = vec![topology(&[("region", "R2")]), topology(&[("region", "R1")])];
+    assert_eq!(names(requirement.candidates(&available)), vec!["R1"]);
+  }
+
+  #[test_case(&[] => true ; "no topology")]
+  #[test_case(&["Z1"] => false ; "outside requisite")]
+  #[test_case(&["Z1", "Z3"] => true ; "one of requisite")]
+  fn checks_accessible_topology(accessible: &[&str]) -> bool {
+    let requirement = TopologyRequirement::RequisiteAndPreffered {
+      requisite: zones(&["Z2", "Z3"]),
+      preferred: zones(&["Z3"]),
+    };
+    requirement.is_satisfied_by(&zones(accessible))
+  }
+
+  #[test]
+  fn preferred_only_is_always_satisfied() {
+    let requirement = TopologyRequirement::Preferred(zones(&["Z2"]));
+    assert!(requirement.is_satisfied_by(&zones(&["Z1"])));
+  }
+}
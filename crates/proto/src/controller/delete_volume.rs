@@ -1,5 +1,7 @@
 use super::Secrets;
 use crate::proto;
+#[cfg(feature = "test-util")]
+use std::collections::HashMap;
 use std::convert::TryFrom;
 use thiserror::Error;
 
@@ -9,6 +11,39 @@ pub struct DeleteVolumeRequest {
   secrets: Secrets,
 }
 
+impl DeleteVolumeRequest {
+  /// The ID of the volume to be deprovisioned.
+  #[inline]
+  pub fn volume_id(&self) -> &str {
+    &self.volume_id
+  }
+
+  /// Secrets required by plugin to complete volume deletion request.
+  /// This field is OPTIONAL. Refer to the `Secrets Requirements`
+  /// section on how to use this field.
+  #[inline]
+  pub fn secrets(&self) -> &Secrets {
+    &self.secrets
+  }
+}
+
+#[cfg(feature = "test-util")]
+impl DeleteVolumeRequest {
+  #[inline]
+  pub fn new(volume_id: impl Into<String>) -> Self {
+    DeleteVolumeRequest {
+      volume_id: volume_id.into(),
+      secrets: Secrets::default(),
+    }
+  }
+
+  #[inline]
+  pub fn with_secrets(mut self, secrets: HashMap<String, String>) -> Self {
+    self.secrets = secrets.into();
+    self
+  }
+}
+
 impl TryFrom<proto::DeleteVolumeRequest> for DeleteVolumeRequest {
   type Error = tonic::Status;
 
@@ -1,7 +1,8 @@
 use super::{CapacityRange, Secrets, VolumeCapability};
 use crate::proto;
+#[cfg(feature = "test-util")]
+use std::collections::HashMap;
 use std::{
-  collections::HashMap,
   convert::{TryFrom, TryInto},
   num::NonZeroU64,
 };
@@ -32,8 +33,8 @@ impl ControllerExpandVolumeRequest {
   /// Secrets required by the plugin for expanding the volume.
   /// This field is OPTIONAL.
   #[inline]
-  pub fn secrets(&self) -> &HashMap<String, String> {
-    self.secrets.as_ref()
+  pub fn secrets(&self) -> &Secrets {
+    &self.secrets
   }
 
   /// Volume capability describing how the CO intends to use this volume.
@@ -49,6 +50,31 @@ impl ControllerExpandVolumeRequest {
   }
 }
 
+#[cfg(feature = "test-util")]
+impl ControllerExpandVolumeRequest {
+  #[inline]
+  pub fn new(volume_id: impl Into<String>, capacity_range: CapacityRange) -> Self {
+    ControllerExpandVolumeRequest {
+      volume_id: volume_id.into(),
+      capacity_range,
+      secrets: Secrets::default(),
+      volume_capability: None,
+    }
+  }
+
+  #[inline]
+  pub fn with_secrets(mut self, secrets: HashMap<String, String>) -> Self {
+    self.secrets = secrets.into();
+    self
+  }
+
+  #[inline]
+  pub fn with_volume_capability(mut self, volume_capability: VolumeCapability) -> Self {
+    self.volume_capability = Some(volume_capability);
+    self
+  }
+}
+
 impl TryFrom<proto::ControllerExpandVolumeRequest> for ControllerExpandVolumeRequest {
   type Error = tonic::Status;
 
@@ -92,6 +118,36 @@ pub struct ControllerExpandVolumeResponse {
   node_expansion_required: bool,
 }
 
+impl ControllerExpandVolumeResponse {
+  /// Create a response. Fails with `INTERNAL` if `capacity_bytes` is
+  /// out of range.
+  pub fn new(
+    capacity_bytes: NonZeroU64,
+    node_expansion_required: bool,
+  ) -> Result<Self, tonic::Status> {
+    if capacity_bytes.get() > i64::MAX as u64 {
+      return Err(tonic::Status::internal(
+        "ControllerExpandVolumeResponse.capacity_bytes is out of range",
+      ));
+    }
+
+    Ok(ControllerExpandVolumeResponse {
+      capacity_bytes,
+      node_expansion_required,
+    })
+  }
+
+  #[inline]
+  pub fn capacity_bytes(&self) -> NonZeroU64 {
+    self.capacity_bytes
+  }
+
+  #[inline]
+  pub fn node_expansion_required(&self) -> bool {
+    self.node_expansion_required
+  }
+}
+
 impl TryFrom<ControllerExpandVolumeResponse> for proto::ControllerExpandVolumeResponse {
   type Error = tonic::Status;
 
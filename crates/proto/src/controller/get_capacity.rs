@@ -3,7 +3,6 @@ use crate::proto;
 use std::{
   collections::HashMap,
   convert::{TryFrom, TryInto},
-  num::NonZeroU64,
 };
 use thiserror::Error;
 
@@ -46,6 +45,44 @@ impl GetCapacityRequest {
   }
 }
 
+#[cfg(feature = "test-util")]
+impl Default for GetCapacityRequest {
+  #[inline]
+  fn default() -> Self {
+    GetCapacityRequest {
+      volume_capabilities: Vec::new(),
+      parameters: HashMap::new(),
+      accessible_topology: None,
+    }
+  }
+}
+
+#[cfg(feature = "test-util")]
+impl GetCapacityRequest {
+  #[inline]
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  #[inline]
+  pub fn with_volume_capabilities(mut self, volume_capabilities: Vec<VolumeCapability>) -> Self {
+    self.volume_capabilities = volume_capabilities;
+    self
+  }
+
+  #[inline]
+  pub fn with_parameters(mut self, parameters: HashMap<String, String>) -> Self {
+    self.parameters = parameters;
+    self
+  }
+
+  #[inline]
+  pub fn with_accessible_topology(mut self, accessible_topology: Topology) -> Self {
+    self.accessible_topology = Some(accessible_topology);
+    self
+  }
+}
+
 impl TryFrom<proto::GetCapacityRequest> for GetCapacityRequest {
   type Error = tonic::Status;
 
@@ -73,14 +110,33 @@ pub struct GetCapacityResponse {
   /// specified in the request, the Plugin SHALL take those into
   /// consideration when calculating the available capacity of the
   /// storage. This field is REQUIRED.
-  available_capacity: NonZeroU64,
+  available_capacity: u64,
+}
+
+impl GetCapacityResponse {
+  /// Create a response. Fails with `INTERNAL` if `available_capacity`
+  /// is out of range.
+  pub fn new(available_capacity: u64) -> Result<Self, tonic::Status> {
+    if available_capacity > i64::MAX as u64 {
+      return Err(tonic::Status::internal(
+        "GetCapacityResponse.available_capacity is out of range",
+      ));
+    }
+
+    Ok(GetCapacityResponse { available_capacity })
+  }
+
+  #[inline]
+  pub fn available_capacity(&self) -> u64 {
+    self.available_capacity
+  }
 }
 
 impl TryFrom<GetCapacityResponse> for proto::GetCapacityResponse {
   type Error = tonic::Status;
 
   fn try_from(value: GetCapacityResponse) -> Result<Self, Self::Error> {
-    let available_capacity = value.available_capacity.get() as i64;
+    let available_capacity = value.available_capacity as i64;
     Ok(proto::GetCapacityResponse { available_capacity })
   }
 }
@@ -1,5 +1,8 @@
 use super::{Volume, VolumeStatus};
-use crate::proto;
+use crate::{
+  proto,
+  response::{Advertised, ValidateResponse},
+};
 use std::convert::{TryFrom, TryInto};
 use thiserror::Error;
 
@@ -16,6 +19,16 @@ impl ControllerGetVolumeRequest {
   }
 }
 
+#[cfg(feature = "test-util")]
+impl ControllerGetVolumeRequest {
+  #[inline]
+  pub fn new(volume_id: impl Into<String>) -> Self {
+    ControllerGetVolumeRequest {
+      volume_id: volume_id.into(),
+    }
+  }
+}
+
 impl TryFrom<proto::ControllerGetVolumeRequest> for ControllerGetVolumeRequest {
   type Error = tonic::Status;
 
@@ -39,6 +52,33 @@ pub struct ControllerGetVolumeResponse {
   status: VolumeStatus,
 }
 
+impl ControllerGetVolumeResponse {
+  #[inline]
+  pub fn new(volume: Volume, status: VolumeStatus) -> Self {
+    ControllerGetVolumeResponse { volume, status }
+  }
+
+  #[inline]
+  pub fn volume(&self) -> &Volume {
+    &self.volume
+  }
+
+  #[inline]
+  pub fn status(&self) -> &VolumeStatus {
+    &self.status
+  }
+}
+
+impl ValidateResponse for ControllerGetVolumeResponse {
+  fn validate(&self, advertised: &Advertised) -> Result<(), String> {
+    self.volume.validate(advertised)?;
+    self
+      .status
+      .validate(advertised)
+      .map_err(|e| format!("volume {} status {}", self.volume.volume_id(), e))
+  }
+}
+
 impl TryFrom<ControllerGetVolumeResponse> for proto::ControllerGetVolumeResponse {
   type Error = tonic::Status;
 
@@ -1,7 +1,8 @@
 use super::{Secrets, Snapshot};
 use crate::proto;
+#[cfg(feature = "test-util")]
+use std::collections::HashMap;
 use std::{
-  collections::HashMap,
   convert::{TryFrom, TryInto},
   num::NonZeroU32,
 };
@@ -60,8 +61,60 @@ impl ListSnapshotsRequest {
   /// This field is OPTIONAL. Refer to the `Secrets Requirements`
   /// section on how to use this field.
   #[inline]
-  pub fn secrets(&self) -> &HashMap<String, String> {
-    self.secrets.as_ref()
+  pub fn secrets(&self) -> &Secrets {
+    &self.secrets
+  }
+}
+
+#[cfg(feature = "test-util")]
+impl Default for ListSnapshotsRequest {
+  #[inline]
+  fn default() -> Self {
+    ListSnapshotsRequest {
+      max_entries: None,
+      starting_token: None,
+      source_volume_id: None,
+      snapshot_id: None,
+      secrets: Secrets::default(),
+    }
+  }
+}
+
+#[cfg(feature = "test-util")]
+impl ListSnapshotsRequest {
+  #[inline]
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  #[inline]
+  pub fn with_max_entries(mut self, max_entries: u32) -> Self {
+    self.max_entries = NonZeroU32::new(max_entries);
+    self
+  }
+
+  #[inline]
+  pub fn with_starting_token(mut self, starting_token: impl Into<String>) -> Self {
+    self.starting_token = Some(starting_token.into());
+    self
+  }
+
+  #[inline]
+  pub fn with_source_volume_id(mut self, source_volume_id: impl Into<String>) -> Self {
+    self.source_volume_id = Some(source_volume_id.into());
+    self
+  }
+
+  #[inline]
+  pub fn with_snapshot_id(mut self, snapshot_id: impl Into<String>) -> Self {
+    self.snapshot_id = Some(snapshot_id.into());
+    self
+  }
+
+  #[inline]
+  pub fn with_secrets(mut self, secrets: HashMap<String, String>) -> Self {
+    self.secrets = secrets.into();
+    self
   }
 }
 
@@ -121,6 +174,34 @@ pub struct ListSnapshotsResponse {
   next_token: Option<String>,
 }
 
+impl ListSnapshotsResponse {
+  #[inline]
+  pub fn new(entries: Vec<Snapshot>) -> Self {
+    ListSnapshotsResponse {
+      entries,
+      next_token: None,
+    }
+  }
+
+  /// Set the token for the next page of entries. An empty token is the
+  /// same as no token.
+  #[inline]
+  pub fn with_next_token(mut self, next_token: impl Into<String>) -> Self {
+    self.next_token = Some(next_token.into()).filter(|v| !v.is_empty());
+    self
+  }
+
+  #[inline]
+  pub fn entries(&self) -> &[Snapshot] {
+    &self.entries
+  }
+
+  #[inline]
+  pub fn next_token(&self) -> Option<&str> {
+    self.next_token.as_deref()
+  }
+}
+
 impl TryFrom<ListSnapshotsResponse> for proto::ListSnapshotsResponse {
   type Error = tonic::Status;
 
@@ -1,5 +1,8 @@
-use super::{Volume, VolumeStatus};
-use crate::proto;
+use super::{ControllerCapabilities, Volume, VolumeStatus};
+use crate::{
+  proto,
+  response::{Advertised, ValidateResponse},
+};
 use std::{
   convert::{TryFrom, TryInto},
   num::NonZeroU32,
@@ -34,6 +37,37 @@ impl ListVolumesRequest {
   }
 }
 
+#[cfg(feature = "test-util")]
+impl Default for ListVolumesRequest {
+  #[inline]
+  fn default() -> Self {
+    ListVolumesRequest {
+      max_entries: None,
+      starting_token: None,
+    }
+  }
+}
+
+#[cfg(feature = "test-util")]
+impl ListVolumesRequest {
+  #[inline]
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  #[inline]
+  pub fn with_max_entries(mut self, max_entries: u32) -> Self {
+    self.max_entries = NonZeroU32::new(max_entries);
+    self
+  }
+
+  #[inline]
+  pub fn with_starting_token(mut self, starting_token: impl Into<String>) -> Self {
+    self.starting_token = Some(starting_token.into());
+    self
+  }
+}
+
 impl TryFrom<proto::ListVolumesRequest> for ListVolumesRequest {
   type Error = tonic::Status;
 
@@ -69,6 +103,76 @@ pub struct VolumeListEntry {
   status: Option<VolumeStatus>,
 }
 
+impl VolumeListEntry {
+  #[inline]
+  pub fn new(volume: Volume) -> Self {
+    VolumeListEntry {
+      volume,
+      status: None,
+    }
+  }
+
+  #[inline]
+  pub fn with_status(mut self, status: VolumeStatus) -> Self {
+    self.status = Some(status);
+    self
+  }
+
+  #[inline]
+  pub fn volume(&self) -> &Volume {
+    &self.volume
+  }
+
+  #[inline]
+  pub fn status(&self) -> Option<&VolumeStatus> {
+    self.status.as_ref()
+  }
+}
+
+impl ValidateResponse for VolumeListEntry {
+  fn validate(&self, advertised: &Advertised) -> Result<(), String> {
+    self.volume.validate(advertised)?;
+
+    let published_nodes = advertised
+      .controller
+      .contains(ControllerCapabilities::LIST_VOLUMES_PUBLISHED_NODES);
+    let status = match &self.status {
+      Some(status) => status,
+      None if published_nodes => {
+        return Err(format!(
+          "volume {} has no status, but the LIST_VOLUMES_PUBLISHED_NODES controller capability is advertised",
+          self.volume.volume_id()
+        ))
+      }
+      #[cfg(feature = "alpha")]
+      None if advertised
+        .controller
+        .contains(ControllerCapabilities::VOLUME_CONDITION) =>
+      {
+        return Err(format!(
+          "volume {} has no status, but the VOLUME_CONDITION controller capability is advertised",
+          self.volume.volume_id()
+        ))
+      }
+      None => return Ok(()),
+    };
+
+    if !published_nodes && status.published_node_ids().len() > 0 {
+      return Err(format!(
+        "volume {} has published_node_ids, but the LIST_VOLUMES_PUBLISHED_NODES controller capability is not advertised",
+        self.volume.volume_id()
+      ));
+    }
+
+    #[cfg(feature = "alpha")]
+    status
+      .validate(advertised)
+      .map_err(|e| format!("volume {} status {}", self.volume.volume_id(), e))?;
+
+    Ok(())
+  }
+}
+
 impl TryFrom<VolumeListEntry> for proto::list_volumes_response::Entry {
   type Error = tonic::Status;
 
@@ -93,6 +197,43 @@ pub struct ListVolumesResponse {
   next_token: Option<String>,
 }
 
+impl ListVolumesResponse {
+  #[inline]
+  pub fn new(entries: Vec<VolumeListEntry>) -> Self {
+    ListVolumesResponse {
+      entries,
+      next_token: None,
+    }
+  }
+
+  /// Set the token for the next page of entries. An empty token is the
+  /// same as no token.
+  #[inline]
+  pub fn with_next_token(mut self, next_token: impl Into<String>) -> Self {
+    self.next_token = Some(next_token.into()).filter(|v| !v.is_empty());
+    self
+  }
+
+  #[inline]
+  pub fn entries(&self) -> &[VolumeListEntry] {
+    &self.entries
+  }
+
+  #[inline]
+  pub fn next_token(&self) -> Option<&str> {
+    self.next_token.as_deref()
+  }
+}
+
+impl ValidateResponse for ListVolumesResponse {
+  fn validate(&self, advertised: &Advertised) -> Result<(), String> {
+    self
+      .entries
+      .iter()
+      .try_for_each(|entry| entry.validate(advertised))
+  }
+}
+
 impl TryFrom<ListVolumesResponse> for proto::ListVolumesResponse {
   type Error = tonic::Status;
 
@@ -139,3 +280,58 @@ impl From<ListVolumesError> for tonic::Status {
     }
   }
 }
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use test_case::test_case;
+
+  fn advertised(capabilities: ControllerCapabilities) -> Advertised {
+    Advertised {
+      accessibility_constraints: false,
+      controller: capabilities,
+      #[cfg(feature = "alpha")]
+      node: crate::node::NodeCapabilities::empty(),
+    }
+  }
+
+  fn entry(published_node_ids: Option<&[&str]>) -> VolumeListEntry {
+    let entry = VolumeListEntry::new(Volume::new("vol-1").unwrap());
+    match published_node_ids {
+      None => entry,
+      Some(ids) => entry.with_status(
+        VolumeStatus::new().with_published_node_ids(ids.iter().map(|&v| v.into()).collect()),
+      ),
+    }
+  }
+
+  #[test_case(ControllerCapabilities::LIST_VOLUMES, None => true ; "no status")]
+  #[test_case(ControllerCapabilities::LIST_VOLUMES, Some(&["node-1"][..]) => false ; "published nodes not advertised")]
+  #[test_case(ControllerCapabilities::LIST_VOLUMES_PUBLISHED_NODES, None => false ; "missing status")]
+  #[test_case(ControllerCapabilities::LIST_VOLUMES_PUBLISHED_NODES, Some(&[][..]) => true ; "not published")]
+  #[test_case(ControllerCapabilities::LIST_VOLUMES_PUBLISHED_NODES, Some(&["node-1"][..]) => true ; "published")]
+  fn validates_entry(
+    capabilities: ControllerCapabilities,
+    published_node_ids: Option<&[&str]>,
+  ) -> bool {
+    entry(published_node_ids)
+      .validate(&advertised(capabilities))
+      .is_ok()
+  }
+
+  #[test]
+  fn rejects_topology_without_accessibility_constraints() {
+    let volume = Volume::new("vol-1").unwrap().with_accessible_topology(
+      [("zone".to_owned(), "a".to_owned())]
+        .iter()
+        .cloned()
+        .collect(),
+    );
+    let response = ListVolumesResponse::new(vec![VolumeListEntry::new(volume)]);
+
+    let err = response
+      .validate(&advertised(ControllerCapabilities::LIST_VOLUMES))
+      .unwrap_err();
+    assert!(err.contains("VOLUME_ACCESSIBILITY_CONSTRAINTS"), "{}", err);
+  }
+}
@@ -0,0 +1,392 @@
+use super::{
+  ListSnapshotsError, ListSnapshotsRequest, ListSnapshotsResponse, ListVolumesError,
+  ListVolumesRequest, ListVolumesResponse, Snapshot, VolumeListEntry,
+};
+use futures::{pin_mut, Stream, StreamExt};
+use hmac::{Hmac, Mac, NewMac};
+use sha2::Sha256;
+use std::{convert::TryInto, fmt, num::NonZeroU32};
+
+type HmacSha256 = Hmac<Sha256>;
+
+const OFFSET_LEN: usize = 8;
+const TAG_LEN: usize = 32;
+
+/// Pages through the entries of `ListVolumes` and `ListSnapshots`.
+///
+/// The entries passed to the paginator MUST be in the same order for every
+/// call, for example sorted by ID. The `next_token` of a page is opaque to
+/// the CO, and signed with the key of the paginator, so a `starting_token`
+/// that was not issued by a paginator with the same key for the same
+/// listing is rejected with `ABORTED`, as the spec requires. All replicas of
+/// a controller should use the same key, so they accept each others tokens.
+///
+/// ```
+/// # use csi_proto::controller::*;
+/// # async fn list(request: ListVolumesRequest, volumes: Vec<Volume>)
+/// #   -> Result<ListVolumesResponse, ListVolumesError> {
+/// let paginator = Paginator::new(b"a key shared by all replicas");
+/// paginator.list_volumes(&request, volumes.into_iter().map(VolumeListEntry::new))
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct Paginator {
+  mac: HmacSha256,
+}
+
+impl Paginator {
+  /// Create a paginator that signs its tokens with `key`.
+  pub fn new(key: impl AsRef<[u8]>) -> Self {
+    let mac = HmacSha256::new_from_slice(key.as_ref()).expect("HMAC accepts keys of any length");
+    Paginator { mac }
+  }
+
+  /// Build a page of `entries` for `request`.
+  pub fn list_volumes<I>(
+    &self,
+    request: &ListVolumesRequest,
+    entries: I,
+  ) -> Result<ListVolumesResponse, ListVolumesError>
+  where
+    I: IntoIterator<Item = VolumeListEntry>,
+  {
+    let page = self
+      .page(
+        &["ListVolumes"],
+        request.max_entries(),
+        request.starting_token(),
+      )
+      .map_err(ListVolumesError::InvalidStartingToken)?;
+    let (entries, next_token) = page.collect(entries);
+
+    Ok(ListVolumesResponse::new(entries).with_next_token(next_token.unwrap_or_default()))
+  }
+
+  /// Build a page of `entries` for `request`, fetching no more entries from
+  /// the stream than needed.
+  pub async fn list_volumes_stream<S>(
+    &self,
+    request: &ListVolumesRequest,
+    entries: S,
+  ) -> Result<ListVolumesResponse, ListVolumesError>
+  where
+    S: Stream<Item = Result<VolumeListEntry, tonic::Status>>,
+  {
+    let page = self
+      .page(
+        &["ListVolumes"],
+        request.max_entries(),
+        request.starting_token(),
+      )
+      .map_err(ListVolumesError::InvalidStartingToken)?;
+    let (entries, next_token) = page.collect_stream(entries).await?;
+
+    Ok(ListVolumesResponse::new(entries).with_next_token(next_token.unwrap_or_default()))
+  }
+
+  /// Build a page of `entries` for `request`. The entries are expected to
+  /// be filtered by the `source_volume_id` and `snapshot_id` of the request
+  /// already, a token is only accepted for the same filters it was issued
+  /// for.
+  pub fn list_snapshots<I>(
+    &self,
+    request: &ListSnapshotsRequest,
+    entries: I,
+  ) -> Result<ListSnapshotsResponse, ListSnapshotsError>
+  where
+    I: IntoIterator<Item = Snapshot>,
+  {
+    let scope = snapshots_scope(request);
+    let page = self
+      .page(&scope, request.max_entries(), request.starting_token())
+      .map_err(ListSnapshotsError::InvalidStartingToken)?;
+    let (entries, next_token) = page.collect(entries);
+
+    Ok(ListSnapshotsResponse::new(entries).with_next_token(next_token.unwrap_or_default()))
+  }
+
+  /// Build a page of `entries` for `request`, fetching no more entries from
+  /// the stream than needed. See [`Paginator::list_snapshots`].
+  pub async fn list_snapshots_stream<S>(
+    &self,
+    request: &ListSnapshotsRequest,
+    entries: S,
+  ) -> Result<ListSnapshotsResponse, ListSnapshotsError>
+  where
+    S: Stream<Item = Result<Snapshot, tonic::Status>>,
+  {
+    let scope = snapshots_scope(request);
+    let page = self
+      .page(&scope, request.max_entries(), request.starting_token())
+      .map_err(ListSnapshotsError::InvalidStartingToken)?;
+    let (entries, next_token) = page.collect_stream(entries).await?;
+
+    Ok(ListSnapshotsResponse::new(entries).with_next_token(next_token.unwrap_or_default()))
+  }
+
+  fn page<'a>(
+    &'a self,
+    scope: &'a [&'a str],
+    max_entries: Option<NonZeroU32>,
+    starting_token: Option<&str>,
+  ) -> Result<Page<'a>, String> {
+    let offset = match starting_token {
+      Some(token) => self.decode(scope, token)?,
+      None => 0,
+    };
+
+    Ok(Page {
+      paginator: self,
+      scope,
+      offset,
+      max_entries,
+    })
+  }
+
+  /// The MAC of the offset of a page into a listing. The scope identifies
+  /// the listing, so tokens cannot be used for other listings.
+  fn sign(&self, scope: &[&str], offset: u64) -> HmacSha256 {
+    let mut mac = self.mac.clone();
+    for part in scope {
+      mac.update(&(part.len() as u64).to_be_bytes());
+      mac.update(part.as_bytes());
+    }
+    mac.update(&offset.to_be_bytes());
+    mac
+  }
+
+  fn encode(&self, scope: &[&str], offset: u64) -> String {
+    let mut token = offset.to_be_bytes().to_vec();
+    token.extend_from_slice(&self.sign(scope, offset).finalize().into_bytes());
+    base64::encode_config(token, base64::URL_SAFE_NO_PAD)
+  }
+
+  fn decode(&self, scope: &[&str], token: &str) -> Result<u64, String> {
+    let token = base64::decode_config(token, base64::URL_SAFE_NO_PAD)
+      .ok()
+      .filter(|token| token.len() == OFFSET_LEN + TAG_LEN)
+      .ok_or_else(|| "token is malformed".to_owned())?;
+
+    let (offset, tag) = token.split_at(OFFSET_LEN);
+    let offset = u64::from_be_bytes(offset.try_into().unwrap());
+    self
+      .sign(scope, offset)
+      .verify(tag)
+      .map_err(|_| "token was not issued for this listing".to_owned())?;
+
+    Ok(offset)
+  }
+}
+
+impl fmt::Debug for Paginator {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.debug_struct("Paginator").finish()
+  }
+}
+
+fn snapshots_scope(request: &ListSnapshotsRequest) -> [&str; 3] {
+  [
+    "ListSnapshots",
+    request.source_volume_id().unwrap_or_default(),
+    request.snapshot_id().unwrap_or_default(),
+  ]
+}
+
+/// A page of a listing, starting at `offset`.
+struct Page<'a> {
+  paginator: &'a Paginator,
+  scope: &'a [&'a str],
+  offset: u64,
+  max_entries: Option<NonZeroU32>,
+}
+
+impl Page<'_> {
+  fn limit(&self) -> usize {
+    match self.max_entries {
+      Some(max_entries) => max_entries.get() as usize,
+      None => usize::MAX,
+    }
+  }
+
+  fn skip(&self) -> usize {
+    self.offset.try_into().unwrap_or(usize::MAX)
+  }
+
+  fn next_token(&self, len: usize) -> String {
+    self.paginator.encode(self.scope, self.offset + len as u64)
+  }
+
+  /// The entries of the page, and the token for the next page if there are
+  /// more entries.
+  fn collect<T>(&self, entries: impl IntoIterator<Item = T>) -> (Vec<T>, Option<String>) {
+    let mut entries = entries.into_iter().skip(self.skip());
+    let page = entries.by_ref().take(self.limit()).collect::<Vec<_>>();
+    let next_token = entries.next().map(|_| self.next_token(page.len()));
+
+    (page, next_token)
+  }
+
+  async fn collect_stream<T>(
+    &self,
+    entries: impl Stream<Item = Result<T, tonic::Status>>,
+  ) -> Result<(Vec<T>, Option<String>), tonic::Status> {
+    pin_mut!(entries);
+
+    // Entries before the page are dropped, but errors are not: the page
+    // would be built from an incomplete listing.
+    for _ in 0..self.skip() {
+      match entries.next().await {
+        Some(entry) => drop(entry?),
+        None => return Ok((Vec::new(), None)),
+      }
+    }
+
+    let limit = self.limit();
+    let mut page = Vec::new();
+    while page.len() < limit {
+      match entries.next().await {
+        Some(entry) => page.push(entry?),
+        None => return Ok((page, None)),
+      }
+    }
+
+    let next_token = match entries.next().await {
+      Some(entry) => entry.map(|_| Some(self.next_token(page.len())))?,
+      None => None,
+    };
+
+    Ok((page, next_token))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::{controller::Volume, proto};
+  use futures::{executor::block_on, stream};
+  use std::convert::TryFrom;
+  use test_case::test_case;
+
+  fn volumes_request(max_entries: u32, starting_token: &str) -> ListVolumesRequest {
+    ListVolumesRequest::try_from(proto::ListVolumesRequest {
+      max_entries: max_entries as i32,
+      starting_token: starting_token.to_owned(),
+    })
+    .unwrap()
+  }
+
+  fn snapshots_request(source_volume_id: &str, starting_token: &str) -> ListSnapshotsRequest {
+    ListSnapshotsRequest::try_from(proto::ListSnapshotsRequest {
+      source_volume_id: source_volume_id.to_owned(),
+      starting_token: starting_token.to_owned(),
+      ..Default::default()
+    })
+    .unwrap()
+  }
+
+  fn volumes(count: usize) -> impl Iterator<Item = VolumeListEntry> {
+    (0..count).map(|i| VolumeListEntry::new(Volume::new(format!("vol-{}", i)).unwrap()))
+  }
+
+  fn ids(response: &ListVolumesResponse) -> Vec<&str> {
+    response
+      .entries()
+      .iter()
+      .map(|entry| entry.volume().volume_id())
+      .collect()
+  }
+
+  #[test_case(5, 0 => vec![5] ; "unlimited")]
+  #[test_case(5, 2 => vec![2, 2, 1] ; "partial last page")]
+  #[test_case(4, 2 => vec![2, 2] ; "full last page")]
+  #[test_case(0, 2 => vec![0] ; "empty")]
+  fn pages_through_volumes(count: usize, max_entries: u32) -> Vec<usize> {
+    let paginator = Paginator::new("key");
+    let mut pages = Vec::new();
+    let mut seen = Vec::new();
+    let mut token = String::new();
+    loop {
+      let request = volumes_request(max_entries, &token);
+
+      let response = paginator.list_volumes(&request, volumes(count)).unwrap();
+      let streamed =
+        block_on(paginator.list_volumes_stream(&request, stream::iter(volumes(count).map(Ok))))
+          .unwrap();
+      assert_eq!(ids(&streamed), ids(&response));
+      assert_eq!(streamed.next_token(), response.next_token());
+
+      pages.push(response.entries().len());
+      seen.extend(ids(&response).into_iter().map(str::to_owned));
+      match response.next_token() {
+        Some(next_token) => token = next_token.to_owned(),
+        None => break,
+      }
+    }
+
+    let expected = volumes(count)
+      .map(|entry| entry.volume().volume_id().to_owned())
+      .collect::<Vec<_>>();
+    assert_eq!(seen, expected);
+    pages
+  }
+
+  #[test]
+  fn fails_on_errors_before_the_page() {
+    let paginator = Paginator::new("key");
+    let request = volumes_request(2, &paginator.encode(&["ListVolumes"], 3));
+    let mut entries = volumes(5).map(Ok).collect::<Vec<_>>();
+    entries[1] = Err(tonic::Status::unavailable("backend is down"));
+
+    let err: tonic::Status =
+      block_on(paginator.list_volumes_stream(&request, stream::iter(entries)))
+        .unwrap_err()
+        .into();
+    assert_eq!(err.code(), tonic::Code::Unavailable);
+  }
+
+  /// The token for another offset, with the signature of `token`.
+  fn tamper(token: &str) -> String {
+    let mut token = base64::decode_config(token, base64::URL_SAFE_NO_PAD).unwrap();
+    token[OFFSET_LEN - 1] ^= 1;
+    base64::encode_config(token, base64::URL_SAFE_NO_PAD)
+  }
+
+  #[test]
+  fn rejects_foreign_tokens() {
+    let paginator = Paginator::new("key");
+    let token = paginator.encode(&["ListVolumes"], 2);
+    assert!(token.len() <= 128);
+
+    for token in [
+      "not a token".to_owned(),
+      Paginator::new("other key").encode(&["ListVolumes"], 2),
+      paginator.encode(&["ListSnapshots", "", ""], 2),
+      tamper(&token),
+    ]
+    .iter()
+    {
+      let request = volumes_request(0, token);
+      let err: tonic::Status = paginator
+        .list_volumes(&request, volumes(5))
+        .unwrap_err()
+        .into();
+      assert_eq!(err.code(), tonic::Code::Aborted, "{}", token);
+    }
+  }
+
+  #[test]
+  fn scopes_snapshot_tokens_to_filters() {
+    let paginator = Paginator::new("key");
+    let token = paginator.encode(&snapshots_scope(&snapshots_request("vol-1", "")), 1);
+
+    let same = snapshots_request("vol-1", &token);
+    assert!(paginator.list_snapshots(&same, Vec::new()).is_ok());
+
+    let other = snapshots_request("vol-2", &token);
+    let err: tonic::Status = paginator
+      .list_snapshots(&other, Vec::new())
+      .unwrap_err()
+      .into();
+    assert_eq!(err.code(), tonic::Code::Aborted);
+  }
+}
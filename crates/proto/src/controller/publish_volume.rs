@@ -51,8 +51,8 @@ impl ControllerPublishVolumeRequest {
   /// request. This field is OPTIONAL. Refer to the
   /// `Secrets Requirements` section on how to use this field.
   #[inline]
-  pub fn secrets(&self) -> &HashMap<String, String> {
-    self.secrets.as_ref()
+  pub fn secrets(&self) -> &Secrets {
+    &self.secrets
   }
 
   /// Volume context as returned by SP in
@@ -64,6 +64,43 @@ impl ControllerPublishVolumeRequest {
   }
 }
 
+#[cfg(feature = "test-util")]
+impl ControllerPublishVolumeRequest {
+  #[inline]
+  pub fn new(
+    volume_id: impl Into<String>,
+    node_id: impl Into<String>,
+    volume_capability: VolumeCapability,
+  ) -> Self {
+    ControllerPublishVolumeRequest {
+      volume_id: volume_id.into(),
+      node_id: node_id.into(),
+      volume_capability,
+      readonly: false,
+      secrets: Secrets::default(),
+      volume_context: HashMap::new(),
+    }
+  }
+
+  #[inline]
+  pub fn with_readonly(mut self, readonly: bool) -> Self {
+    self.readonly = readonly;
+    self
+  }
+
+  #[inline]
+  pub fn with_secrets(mut self, secrets: HashMap<String, String>) -> Self {
+    self.secrets = secrets.into();
+    self
+  }
+
+  #[inline]
+  pub fn with_volume_context(mut self, volume_context: HashMap<String, String>) -> Self {
+    self.volume_context = volume_context;
+    self
+  }
+}
+
 impl TryFrom<proto::ControllerPublishVolumeRequest> for ControllerPublishVolumeRequest {
   type Error = tonic::Status;
 
@@ -128,6 +165,18 @@ pub struct ControllerPublishVolumeResponse {
   publish_context: HashMap<String, String>,
 }
 
+impl ControllerPublishVolumeResponse {
+  #[inline]
+  pub fn new(publish_context: HashMap<String, String>) -> Self {
+    ControllerPublishVolumeResponse { publish_context }
+  }
+
+  #[inline]
+  pub fn publish_context(&self) -> &HashMap<String, String> {
+    &self.publish_context
+  }
+}
+
 impl TryFrom<ControllerPublishVolumeResponse> for proto::ControllerPublishVolumeResponse {
   type Error = tonic::Status;
 
@@ -37,17 +37,134 @@ pub struct Snapshot {
   /// `volume_content_source` in a `CreateVolumeRequest`. The default
   /// value is false. This field is REQUIRED.
   ready_to_use: bool,
+  /// The ID of the volume group snapshot that this snapshot is part of.
+  /// This field is OPTIONAL. If set, the snapshot MUST NOT be deleted
+  /// on its own, only as part of the group snapshot.
+  #[cfg(feature = "alpha")]
+  group_snapshot_id: Option<String>,
+}
+
+impl Snapshot {
+  /// Create a snapshot. Fails with `INTERNAL` if `snapshot_id` or
+  /// `source_volume_id` is empty, as they are REQUIRED.
+  pub fn new(
+    snapshot_id: impl Into<String>,
+    source_volume_id: impl Into<String>,
+    creation_time: impl Into<Timestamp>,
+  ) -> Result<Self, tonic::Status> {
+    let snapshot_id = snapshot_id.into();
+    if snapshot_id.is_empty() {
+      return Err(tonic::Status::internal("Snapshot.snapshot_id is empty"));
+    }
+
+    let source_volume_id = source_volume_id.into();
+    if source_volume_id.is_empty() {
+      return Err(tonic::Status::internal(
+        "Snapshot.source_volume_id is empty",
+      ));
+    }
+
+    Ok(Snapshot {
+      size_bytes: None,
+      snapshot_id,
+      source_volume_id,
+      creation_time: creation_time.into(),
+      ready_to_use: false,
+      #[cfg(feature = "alpha")]
+      group_snapshot_id: None,
+    })
+  }
+
+  /// Set the complete size of the snapshot in bytes. A value of 0 means
+  /// the size is unknown, which is also the default.
+  #[inline]
+  pub fn with_size_bytes(mut self, size_bytes: u64) -> Self {
+    self.size_bytes = NonZeroU64::new(size_bytes);
+    self
+  }
+
+  /// Set whether the snapshot is ready to use as a
+  /// `volume_content_source`. Defaults to false.
+  #[inline]
+  pub fn with_ready_to_use(mut self, ready_to_use: bool) -> Self {
+    self.ready_to_use = ready_to_use;
+    self
+  }
+
+  /// Set the ID of the volume group snapshot the snapshot is part of. Set
+  /// automatically when the snapshot is added to a
+  /// [`VolumeGroupSnapshot`](crate::group_controller::VolumeGroupSnapshot).
+  #[cfg(feature = "alpha")]
+  #[inline]
+  pub fn with_group_snapshot_id(mut self, group_snapshot_id: impl Into<String>) -> Self {
+    self.group_snapshot_id = Some(group_snapshot_id.into()).filter(|v| !v.is_empty());
+    self
+  }
+
+  /// The size of the snapshot in bytes, `None` if unknown.
+  #[inline]
+  pub fn size_bytes(&self) -> Option<NonZeroU64> {
+    self.size_bytes
+  }
+
+  #[inline]
+  pub fn snapshot_id(&self) -> &str {
+    &self.snapshot_id
+  }
+
+  #[inline]
+  pub fn source_volume_id(&self) -> &str {
+    &self.source_volume_id
+  }
+
+  #[inline]
+  pub fn creation_time(&self) -> &Timestamp {
+    &self.creation_time
+  }
+
+  #[inline]
+  pub fn ready_to_use(&self) -> bool {
+    self.ready_to_use
+  }
+
+  #[cfg(feature = "alpha")]
+  #[inline]
+  pub fn group_snapshot_id(&self) -> Option<&str> {
+    self.group_snapshot_id.as_deref()
+  }
+
+  #[cfg(feature = "alpha")]
+  #[inline]
+  pub(crate) fn set_group_snapshot_id(&mut self, group_snapshot_id: &str) {
+    self.group_snapshot_id = Some(group_snapshot_id.to_owned());
+  }
 }
 
 impl TryFrom<Snapshot> for proto::Snapshot {
   type Error = tonic::Status;
 
   fn try_from(value: Snapshot) -> Result<Self, Self::Error> {
-    let size_bytes = value.size_bytes.map(|v| v.get() as i64).unwrap_or_default();
+    if value.snapshot_id.is_empty() {
+      return Err(tonic::Status::internal("Snapshot.snapshot_id is empty"));
+    }
+
+    if value.source_volume_id.is_empty() {
+      return Err(tonic::Status::internal(
+        "Snapshot.source_volume_id is empty",
+      ));
+    }
+
+    let size_bytes = match value.size_bytes {
+      None => 0,
+      Some(v) => i64::try_from(v.get())
+        .map_err(|_| tonic::Status::internal("Snapshot.size_bytes is out of range"))?,
+    };
     let snapshot_id = value.snapshot_id;
     let source_volume_id = value.source_volume_id;
     let creation_time = Some(value.creation_time);
     let ready_to_use = value.ready_to_use;
+    #[cfg(feature = "alpha")]
+    let group_snapshot_id = value.group_snapshot_id.unwrap_or_default();
 
     Ok(proto::Snapshot {
       size_bytes,
@@ -55,6 +172,8 @@ impl TryFrom<Snapshot> for proto::Snapshot {
       source_volume_id,
       creation_time,
       ready_to_use,
+      #[cfg(feature = "alpha")]
+      group_snapshot_id,
     })
   }
 }
@@ -0,0 +1,234 @@
+use super::{
+  AccessMode, AccessType, Confirmed, MountVolume, ValidateVolumeCapabilitiesError,
+  ValidateVolumeCapabilitiesRequest, ValidateVolumeCapabilitiesResponse, VolumeCapability,
+};
+
+/// The volume capabilities a plugin supports, see
+/// [`ControllerService::supported_capabilities`].
+///
+/// ```
+/// # use csi_proto::controller::*;
+/// let supported = SupportedCapabilities::new(vec![AccessMode::SingleNodeWriter])
+///   .with_block()
+///   .with_fs_types(vec!["ext4", "xfs"])
+///   .with_mount_flags(vec!["noatime", "discard"]);
+/// ```
+///
+/// [`ControllerService::supported_capabilities`]: crate::ControllerService::supported_capabilities
+#[derive(Debug, Clone)]
+pub struct SupportedCapabilities {
+  access_modes: Vec<AccessMode>,
+  block: bool,
+  mount: Option<MountSupport>,
+}
+
+/// Restrictions on `Mount` access.
+#[derive(Debug, Clone, Default)]
+struct MountSupport {
+  fs_types: Option<Vec<String>>,
+  mount_flags: Option<Vec<String>>,
+}
+
+impl SupportedCapabilities {
+  /// Support the given access modes, with neither `Block` nor `Mount`
+  /// access.
+  pub fn new(access_modes: impl IntoIterator<Item = AccessMode>) -> Self {
+    SupportedCapabilities {
+      access_modes: access_modes.into_iter().collect(),
+      block: false,
+      mount: None,
+    }
+  }
+
+  /// Support `Block` access.
+  #[inline]
+  pub fn with_block(mut self) -> Self {
+    self.block = true;
+    self
+  }
+
+  /// Support `Mount` access, with any filesystem type and mount flags
+  /// unless restricted with [`SupportedCapabilities::with_fs_types`] and
+  /// [`SupportedCapabilities::with_mount_flags`].
+  #[inline]
+  pub fn with_mount(mut self) -> Self {
+    self.mount.get_or_insert_with(MountSupport::default);
+    self
+  }
+
+  /// Support `Mount` access with the given filesystem types only. A
+  /// capability without filesystem type is always supported, the plugin
+  /// picks the filesystem type then.
+  pub fn with_fs_types(mut self, fs_types: impl IntoIterator<Item = impl Into<String>>) -> Self {
+    self
+      .mount
+      .get_or_insert_with(MountSupport::default)
+      .fs_types = Some(fs_types.into_iter().map(Into::into).collect());
+    self
+  }
+
+  /// Support `Mount` access with the given mount flags only. A flag with a
+  /// value, like `uid=1000`, is supported if its name, `uid`, is.
+  pub fn with_mount_flags(
+    mut self,
+    mount_flags: impl IntoIterator<Item = impl Into<String>>,
+  ) -> Self {
+    self
+      .mount
+      .get_or_insert_with(MountSupport::default)
+      .mount_flags = Some(mount_flags.into_iter().map(Into::into).collect());
+    self
+  }
+
+  #[inline]
+  pub fn access_modes(&self) -> &[AccessMode] {
+    &self.access_modes
+  }
+
+  /// Check that `capability` is supported, failing with the reason if it
+  /// is not.
+  pub fn check(&self, capability: &VolumeCapability) -> Result<(), String> {
+    let access_mode = capability.access_mode();
+    if !self.access_modes.contains(access_mode) {
+      return Err(format!("access mode {:?} is not supported", access_mode));
+    }
+
+    match capability.access_type() {
+      AccessType::Block if self.block => Ok(()),
+      AccessType::Block => Err("block access is not supported".to_owned()),
+      AccessType::Mount(mount) => match &self.mount {
+        Some(support) => support.check(mount),
+        None => Err("mount access is not supported".to_owned()),
+      },
+    }
+  }
+
+  /// Check that all of `capabilities` are supported, failing with the
+  /// reason for the first one that is not.
+  pub fn check_all<'a>(
+    &self,
+    capabilities: impl IntoIterator<Item = &'a VolumeCapability>,
+  ) -> Result<(), String> {
+    capabilities
+      .into_iter()
+      .try_for_each(|capability| self.check(capability))
+  }
+
+  /// Answer a `ValidateVolumeCapabilities` call: the requested capabilities
+  /// are confirmed if they are all supported. The plugin MUST check that
+  /// the volume exists first, and fail with `NOT_FOUND` if it does not:
+  ///
+  /// ```
+  /// # use csi_proto::controller::*;
+  /// # fn volume_exists(volume_id: &str) -> bool { true }
+  /// # fn validate(
+  /// #   supported: &SupportedCapabilities,
+  /// #   request: ValidateVolumeCapabilitiesRequest,
+  /// # ) -> Result<ValidateVolumeCapabilitiesResponse, ValidateVolumeCapabilitiesError> {
+  /// if !volume_exists(request.volume_id()) {
+  ///   return Err(ValidateVolumeCapabilitiesError::VolumeNotFound(
+  ///     request.volume_id().to_owned(),
+  ///   ));
+  /// }
+  ///
+  /// supported.validate(&request)
+  /// # }
+  /// ```
+  pub fn validate(
+    &self,
+    request: &ValidateVolumeCapabilitiesRequest,
+  ) -> Result<ValidateVolumeCapabilitiesResponse, ValidateVolumeCapabilitiesError> {
+    Ok(match self.check_all(request.volume_capabilities()) {
+      Ok(()) => ValidateVolumeCapabilitiesResponse::Confirmed(Confirmed::new(
+        request.volume_capabilities().to_vec(),
+      )?),
+      Err(message) => ValidateVolumeCapabilitiesResponse::Message(message),
+    })
+  }
+}
+
+impl MountSupport {
+  fn check(&self, mount: &MountVolume) -> Result<(), String> {
+    if let (Some(fs_types), Some(fs_type)) = (&self.fs_types, mount.fs_type()) {
+      if !fs_types.iter().any(|v| v == fs_type) {
+        return Err(format!("fs_type {} is not supported", fs_type));
+      }
+    }
+
+    if let Some(mount_flags) = &self.mount_flags {
+      for flag in mount.mount_flags() {
+        // Only the name is reported, as the values may be sensitive.
+        let name = flag.split('=').next().unwrap_or_default();
+        if !mount_flags.iter().any(|v| v == flag || v == name) {
+          return Err(format!("mount flag {} is not supported", name));
+        }
+      }
+    }
+
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::proto;
+  use std::convert::{TryFrom, TryInto};
+  use test_case::test_case;
+
+  fn supported() -> SupportedCapabilities {
+    SupportedCapabilities::new(vec![
+      AccessMode::SingleNodeWriter,
+      AccessMode::MultiNodeReaderOnly,
+    ])
+    .with_fs_types(vec!["ext4", "xfs"])
+    .with_mount_flags(vec!["noatime", "uid"])
+  }
+
+  fn mount(fs_type: Option<&str>, mount_flags: &[&str]) -> AccessType {
+    AccessType::Mount(MountVolume::new(
+      fs_type.map(Into::into),
+      mount_flags.iter().map(|&v| v.into()).collect(),
+    ))
+  }
+
+  #[test_case(AccessMode::SingleNodeWriter, mount(None, &[]) => Ok(()) ; "default fs_type")]
+  #[test_case(AccessMode::MultiNodeReaderOnly, mount(Some("xfs"), &["noatime", "uid=1000"]) => Ok(()) ; "supported flags")]
+  #[test_case(AccessMode::MultiNodeMultiWriter, mount(None, &[]) => Err("access mode MultiNodeMultiWriter is not supported".into()) ; "access mode")]
+  #[test_case(AccessMode::SingleNodeWriter, AccessType::Block => Err("block access is not supported".into()) ; "block")]
+  #[test_case(AccessMode::SingleNodeWriter, mount(Some("btrfs"), &[]) => Err("fs_type btrfs is not supported".into()) ; "fs_type")]
+  #[test_case(AccessMode::SingleNodeWriter, mount(None, &["gid=1000"]) => Err("mount flag gid is not supported".into()) ; "mount flag")]
+  fn checks_capability(access_mode: AccessMode, access_type: AccessType) -> Result<(), String> {
+    supported().check(&VolumeCapability::new(access_mode, access_type))
+  }
+
+  #[test]
+  fn block_only_rejects_mount() {
+    let supported = SupportedCapabilities::new(vec![AccessMode::SingleNodeWriter]).with_block();
+    let capability = VolumeCapability::new(AccessMode::SingleNodeWriter, mount(None, &[]));
+    assert_eq!(
+      supported.check(&capability),
+      Err("mount access is not supported".into())
+    );
+  }
+
+  #[test_case(AccessMode::SingleNodeWriter => None ; "supported")]
+  #[test_case(AccessMode::MultiNodeMultiWriter => Some("access mode MultiNodeMultiWriter is not supported".to_owned()) ; "unsupported")]
+  fn validates_request(access_mode: AccessMode) -> Option<String> {
+    let request =
+      ValidateVolumeCapabilitiesRequest::try_from(proto::ValidateVolumeCapabilitiesRequest {
+        volume_id: "vol-1".into(),
+        volume_capabilities: vec![VolumeCapability::new(access_mode, AccessType::Block)
+          .try_into()
+          .unwrap()],
+        ..Default::default()
+      })
+      .unwrap();
+
+    let supported = SupportedCapabilities::new(vec![AccessMode::SingleNodeWriter]).with_block();
+    match supported.validate(&request).unwrap() {
+      ValidateVolumeCapabilitiesResponse::Confirmed(_) => None,
+      ValidateVolumeCapabilitiesResponse::Message(message) => Some(message),
+    }
+  }
+}
@@ -1,6 +1,8 @@
 use super::Secrets;
 use crate::proto;
-use std::{collections::HashMap, convert::TryFrom};
+#[cfg(feature = "test-util")]
+use std::collections::HashMap;
+use std::convert::TryFrom;
 use thiserror::Error;
 
 #[derive(Debug)]
@@ -33,8 +35,26 @@ impl ControllerUnpublishVolumeRequest {
   /// This field is OPTIONAL. Refer to the `Secrets Requirements`
   /// section on how to use this field.
   #[inline]
-  pub fn secrets(&self) -> &HashMap<String, String> {
-    self.secrets.as_ref()
+  pub fn secrets(&self) -> &Secrets {
+    &self.secrets
+  }
+}
+
+#[cfg(feature = "test-util")]
+impl ControllerUnpublishVolumeRequest {
+  #[inline]
+  pub fn new(volume_id: impl Into<String>, node_id: impl Into<String>) -> Self {
+    ControllerUnpublishVolumeRequest {
+      volume_id: volume_id.into(),
+      node_id: node_id.into(),
+      secrets: Secrets::default(),
+    }
+  }
+
+  #[inline]
+  pub fn with_secrets(mut self, secrets: HashMap<String, String>) -> Self {
+    self.secrets = secrets.into();
+    self
   }
 }
 
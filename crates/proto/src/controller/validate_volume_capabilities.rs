@@ -50,8 +50,40 @@ impl ValidateVolumeCapabilitiesRequest {
   /// This field is OPTIONAL. Refer to the `Secrets Requirements`
   /// section on how to use this field.
   #[inline]
-  pub fn secrets(&self) -> &HashMap<String, String> {
-    self.secrets.as_ref()
+  pub fn secrets(&self) -> &Secrets {
+    &self.secrets
+  }
+}
+
+#[cfg(feature = "test-util")]
+impl ValidateVolumeCapabilitiesRequest {
+  #[inline]
+  pub fn new(volume_id: impl Into<String>, volume_capabilities: Vec<VolumeCapability>) -> Self {
+    ValidateVolumeCapabilitiesRequest {
+      volume_id: volume_id.into(),
+      volume_context: HashMap::new(),
+      volume_capabilities,
+      parameters: HashMap::new(),
+      secrets: Secrets::default(),
+    }
+  }
+
+  #[inline]
+  pub fn with_volume_context(mut self, volume_context: HashMap<String, String>) -> Self {
+    self.volume_context = volume_context;
+    self
+  }
+
+  #[inline]
+  pub fn with_parameters(mut self, parameters: HashMap<String, String>) -> Self {
+    self.parameters = parameters;
+    self
+  }
+
+  #[inline]
+  pub fn with_secrets(mut self, secrets: HashMap<String, String>) -> Self {
+    self.secrets = secrets.into();
+    self
   }
 }
 
@@ -103,6 +135,51 @@ pub struct Confirmed {
   parameters: Option<HashMap<String, String>>,
 }
 
+impl Confirmed {
+  /// Confirm the given volume capabilities. Fails with `INTERNAL` if
+  /// `volume_capabilities` is empty, as it is REQUIRED.
+  pub fn new(volume_capabilities: Vec<VolumeCapability>) -> Result<Self, tonic::Status> {
+    if volume_capabilities.is_empty() {
+      return Err(tonic::Status::internal(
+        "Confirmed.volume_capabilities is empty",
+      ));
+    }
+
+    Ok(Confirmed {
+      volume_context: None,
+      volume_capabilities,
+      parameters: None,
+    })
+  }
+
+  #[inline]
+  pub fn with_volume_context(mut self, volume_context: HashMap<String, String>) -> Self {
+    self.volume_context = Some(volume_context);
+    self
+  }
+
+  #[inline]
+  pub fn with_parameters(mut self, parameters: HashMap<String, String>) -> Self {
+    self.parameters = Some(parameters);
+    self
+  }
+
+  #[inline]
+  pub fn volume_context(&self) -> Option<&HashMap<String, String>> {
+    self.volume_context.as_ref()
+  }
+
+  #[inline]
+  pub fn volume_capabilities(&self) -> &[VolumeCapability] {
+    &self.volume_capabilities
+  }
+
+  #[inline]
+  pub fn parameters(&self) -> Option<&HashMap<String, String>> {
+    self.parameters.as_ref()
+  }
+}
+
 impl TryFrom<Confirmed> for proto::validate_volume_capabilities_response::Confirmed {
   type Error = tonic::Status;
 
@@ -0,0 +1,196 @@
+mod capabilities;
+mod create_volume_group_snapshot;
+mod delete_volume_group_snapshot;
+mod get_volume_group_snapshot;
+mod volume_group_snapshot;
+
+use crate::{proto, secrets::*, server::Dispatch, IdentityService};
+use async_trait::async_trait;
+use std::{convert::TryInto, sync::Arc};
+use tracing::instrument;
+
+pub use crate::controller::Snapshot;
+pub use capabilities::*;
+pub use create_volume_group_snapshot::*;
+pub use delete_volume_group_snapshot::*;
+pub use get_volume_group_snapshot::*;
+pub use volume_group_snapshot::*;
+
+/// **ALPHA FEATURE**
+///
+/// The `GroupController` service, for operations on groups of volumes.
+/// It is served next to the `Controller` service when enabled with
+/// [`PluginServer::group_controller`](crate::PluginServer::group_controller).
+#[async_trait]
+pub trait GroupControllerService: IdentityService {
+  /// Get the set of services provided by this group controller.
+  #[inline]
+  fn capabilities(&self) -> GroupControllerCapabilities {
+    GroupControllerCapabilities::empty()
+  }
+
+  /// A Group Controller Plugin MUST implement this RPC call if it has
+  /// `CREATE_DELETE_GET_VOLUME_GROUP_SNAPSHOT` group controller capability.
+  ///
+  /// This RPC will be called by the CO to create a new volume group snapshot
+  /// from a list of source volumes on behalf of a user. The operation SHOULD
+  /// snapshot all volumes at the same point-in-time, so the snapshots are
+  /// crash-consistent with each other.
+  ///
+  /// This operation MUST be idempotent. If a volume group snapshot
+  /// corresponding to the specified `name` already exists and is compatible
+  /// with the specified `source_volume_ids` and `parameters`, the Plugin MUST
+  /// reply `0 OK` with the corresponding `VolumeGroupSnapshot`.
+  ///
+  /// If the Plugin does not support creating a group snapshot from some of
+  /// the source volumes, it MUST fail the whole request.
+  #[allow(unused_variables)]
+  async fn create_volume_group_snapshot(
+    &self,
+    request: CreateVolumeGroupSnapshotRequest,
+  ) -> Result<VolumeGroupSnapshot, CreateVolumeGroupSnapshotError> {
+    unsupported!("CreateVolumeGroupSnapshot")
+  }
+
+  /// A Group Controller Plugin MUST implement this RPC call if it has
+  /// `CREATE_DELETE_GET_VOLUME_GROUP_SNAPSHOT` capability. This RPC will be
+  /// called by the CO to delete a volume group snapshot, including all the
+  /// snapshots in it.
+  ///
+  /// This operation MUST be idempotent. If a group snapshot corresponding to
+  /// the specified `group_snapshot_id` does not exist or the artifacts
+  /// associated with the group snapshot do not exist anymore, the Plugin MUST
+  /// reply `0 OK`.
+  #[allow(unused_variables)]
+  async fn delete_volume_group_snapshot(
+    &self,
+    request: DeleteVolumeGroupSnapshotRequest,
+  ) -> Result<(), DeleteVolumeGroupSnapshotError> {
+    unsupported!("DeleteVolumeGroupSnapshot")
+  }
+
+  /// A Group Controller Plugin MUST implement this RPC call if it has
+  /// `CREATE_DELETE_GET_VOLUME_GROUP_SNAPSHOT` capability. This RPC MAY be
+  /// called by the CO to fetch current information about a volume group
+  /// snapshot.
+  ///
+  /// If the group snapshot does not exist any more, the Plugin MUST return
+  /// gRPC error code `NOT_FOUND`.
+  #[allow(unused_variables)]
+  async fn get_volume_group_snapshot(
+    &self,
+    request: GetVolumeGroupSnapshotRequest,
+  ) -> Result<VolumeGroupSnapshot, GetVolumeGroupSnapshotError> {
+    unsupported!("GetVolumeGroupSnapshot")
+  }
+}
+
+pub(crate) struct GroupController<T: GroupControllerService> {
+  pub(crate) plugin: Arc<T>,
+  pub(crate) dispatch: Arc<Dispatch>,
+}
+
+#[async_trait]
+impl<T: GroupControllerService> proto::group_controller_server::GroupController
+  for GroupController<T>
+{
+  #[instrument(
+    name = "group_controller.group_controller_get_capabilities",
+    skip(self, request),
+    fields(request, response, code, error, latency_ms)
+  )]
+  async fn group_controller_get_capabilities(
+    &self,
+    request: tonic::Request<proto::GroupControllerGetCapabilitiesRequest>,
+  ) -> Result<tonic::Response<proto::GroupControllerGetCapabilitiesResponse>, tonic::Status> {
+    self
+      .dispatch
+      .call(
+        "GroupControllerGetCapabilities",
+        request,
+        |_, _call| async move {
+          let response = self.plugin.capabilities().try_into()?;
+          Ok(response)
+        },
+      )
+      .await
+  }
+
+  #[instrument(
+    name = "group_controller.create_volume_group_snapshot",
+    skip(self, request),
+    fields(request, response, code, error, latency_ms)
+  )]
+  async fn create_volume_group_snapshot(
+    &self,
+    request: tonic::Request<proto::CreateVolumeGroupSnapshotRequest>,
+  ) -> Result<tonic::Response<proto::CreateVolumeGroupSnapshotResponse>, tonic::Status> {
+    self
+      .dispatch
+      .call(
+        "CreateVolumeGroupSnapshot",
+        request,
+        |request, _call| async move {
+          let request = request.try_into()?;
+          let response = self
+            .plugin
+            .create_volume_group_snapshot(request)
+            .await?
+            .try_into()?;
+          Ok(response)
+        },
+      )
+      .await
+  }
+
+  #[instrument(
+    name = "group_controller.delete_volume_group_snapshot",
+    skip(self, request),
+    fields(request, response, code, error, latency_ms)
+  )]
+  async fn delete_volume_group_snapshot(
+    &self,
+    request: tonic::Request<proto::DeleteVolumeGroupSnapshotRequest>,
+  ) -> Result<tonic::Response<proto::DeleteVolumeGroupSnapshotResponse>, tonic::Status> {
+    self
+      .dispatch
+      .call(
+        "DeleteVolumeGroupSnapshot",
+        request,
+        |request, _call| async move {
+          let request = request.try_into()?;
+          self.plugin.delete_volume_group_snapshot(request).await?;
+          let response = proto::DeleteVolumeGroupSnapshotResponse {};
+          Ok(response)
+        },
+      )
+      .await
+  }
+
+  #[instrument(
+    name = "group_controller.get_volume_group_snapshot",
+    skip(self, request),
+    fields(request, response, code, error, latency_ms)
+  )]
+  async fn get_volume_group_snapshot(
+    &self,
+    request: tonic::Request<proto::GetVolumeGroupSnapshotRequest>,
+  ) -> Result<tonic::Response<proto::GetVolumeGroupSnapshotResponse>, tonic::Status> {
+    self
+      .dispatch
+      .call(
+        "GetVolumeGroupSnapshot",
+        request,
+        |request, _call| async move {
+          let request = request.try_into()?;
+          let response = self
+            .plugin
+            .get_volume_group_snapshot(request)
+            .await?
+            .try_into()?;
+          Ok(response)
+        },
+      )
+      .await
+  }
+}
@@ -0,0 +1,50 @@
+use std::convert::TryFrom;
+
+use bitflags::bitflags;
+
+use crate::proto;
+
+#[rustfmt::skip]
+bitflags! {
+  pub struct GroupControllerCapabilities: u32 {
+    /// Indicates that the group controller plugin supports
+    /// creating, deleting, and getting details of a volume
+    /// group snapshot.
+    const CREATE_DELETE_GET_VOLUME_GROUP_SNAPSHOT = 0b_0000_0000_0000_0001;
+  }
+}
+
+use proto::group_controller_service_capability::rpc::Type;
+impl TryFrom<GroupControllerCapabilities> for proto::GroupControllerGetCapabilitiesResponse {
+  type Error = tonic::Status;
+
+  fn try_from(value: GroupControllerCapabilities) -> Result<Self, Self::Error> {
+    #[inline]
+    fn push_cap(
+      vec: &mut Vec<proto::GroupControllerServiceCapability>,
+      value: GroupControllerCapabilities,
+      test: GroupControllerCapabilities,
+      proto: Type,
+    ) {
+      if value.contains(test) {
+        vec.push(proto::GroupControllerServiceCapability {
+          r#type: Some(proto::group_controller_service_capability::Type::Rpc(
+            proto::group_controller_service_capability::Rpc {
+              r#type: proto as i32,
+            },
+          )),
+        })
+      }
+    }
+
+    let mut capabilities = Vec::with_capacity(1 /* number of different capabilities */);
+    push_cap(
+      &mut capabilities,
+      value,
+      GroupControllerCapabilities::CREATE_DELETE_GET_VOLUME_GROUP_SNAPSHOT,
+      Type::CreateDeleteGetVolumeGroupSnapshot,
+    );
+
+    Ok(proto::GroupControllerGetCapabilitiesResponse { capabilities })
+  }
+}
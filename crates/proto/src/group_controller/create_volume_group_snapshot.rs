@@ -0,0 +1,156 @@
+use super::Secrets;
+use crate::proto;
+use std::{collections::HashMap, convert::TryFrom};
+use thiserror::Error;
+
+#[derive(Debug)]
+pub struct CreateVolumeGroupSnapshotRequest {
+  name: String,
+  source_volume_ids: Vec<String>,
+  secrets: Secrets,
+  parameters: HashMap<String, String>,
+}
+
+impl CreateVolumeGroupSnapshotRequest {
+  /// The suggested name for the group snapshot. This field is REQUIRED
+  /// for idempotency.
+  /// Any Unicode string that conforms to the length limit is allowed
+  /// except those containing the following banned characters:
+  /// U+0000-U+0008, U+000B, U+000C, U+000E-U+001F, U+007F-U+009F.
+  /// (These are control characters other than commonly used whitespace.)
+  #[inline]
+  pub fn name(&self) -> &str {
+    &self.name
+  }
+
+  /// The IDs of the source volumes to be snapshotted together.
+  #[inline]
+  pub fn source_volume_ids(&self) -> impl ExactSizeIterator<Item = &str> {
+    self.source_volume_ids.iter().map(|v| &**v)
+  }
+
+  /// Secrets required by plugin to complete group snapshot creation
+  /// request. This field is OPTIONAL. Refer to the `Secrets Requirements`
+  /// section on how to use this field.
+  /// The secrets provided in this field SHOULD be the same for all group
+  /// snapshot operations on the same group snapshot.
+  #[inline]
+  pub fn secrets(&self) -> &Secrets {
+    &self.secrets
+  }
+
+  /// Plugin specific parameters passed in as opaque key-value pairs.
+  /// This field is OPTIONAL. The Plugin is responsible for parsing and
+  /// validating these parameters. COs will treat these as opaque.
+  #[inline]
+  pub fn parameters(&self) -> &HashMap<String, String> {
+    &self.parameters
+  }
+}
+
+#[cfg(feature = "test-util")]
+impl CreateVolumeGroupSnapshotRequest {
+  #[inline]
+  pub fn new(name: impl Into<String>, source_volume_ids: Vec<String>) -> Self {
+    CreateVolumeGroupSnapshotRequest {
+      name: name.into(),
+      source_volume_ids,
+      secrets: Secrets::default(),
+      parameters: HashMap::new(),
+    }
+  }
+
+  #[inline]
+  pub fn with_secrets(mut self, secrets: HashMap<String, String>) -> Self {
+    self.secrets = secrets.into();
+    self
+  }
+
+  #[inline]
+  pub fn with_parameters(mut self, parameters: HashMap<String, String>) -> Self {
+    self.parameters = parameters;
+    self
+  }
+}
+
+impl TryFrom<proto::CreateVolumeGroupSnapshotRequest> for CreateVolumeGroupSnapshotRequest {
+  type Error = tonic::Status;
+
+  fn try_from(value: proto::CreateVolumeGroupSnapshotRequest) -> Result<Self, Self::Error> {
+    let name = match value.name {
+      v if v.is_empty() => {
+        return Err(tonic::Status::invalid_argument(
+          "CreateVolumeGroupSnapshotRequest.name is empty",
+        ))
+      }
+      v => v,
+    };
+
+    let source_volume_ids = match value.source_volume_ids {
+      v if v.is_empty() => {
+        return Err(tonic::Status::invalid_argument(
+          "CreateVolumeGroupSnapshotRequest.source_volume_ids is empty",
+        ))
+      }
+      v if v.iter().any(String::is_empty) => {
+        return Err(tonic::Status::invalid_argument(
+          "CreateVolumeGroupSnapshotRequest.source_volume_ids contains an empty id",
+        ))
+      }
+      v => v,
+    };
+
+    let secrets = value.secrets.into();
+    let parameters = value.parameters;
+
+    Ok(CreateVolumeGroupSnapshotRequest {
+      name,
+      source_volume_ids,
+      secrets,
+      parameters,
+    })
+  }
+}
+
+#[non_exhaustive]
+#[derive(Debug, Error)]
+pub enum CreateVolumeGroupSnapshotError {
+  /// Indicates that a group snapshot corresponding to the specified `name`
+  /// already exists but is incompatible with the specified `source_volume_ids`.
+  #[error("Group snapshot already exists but is incompatible: {0}")]
+  AlreadyExists(String),
+
+  /// Indicates that there is already an operation pending for the specified
+  /// group snapshot. The Plugin MAY return this error code to reject secondary
+  /// calls.
+  #[error("Operation pending for group snapshot: {0}")]
+  Pending(String),
+
+  /// There is not enough space on the storage system to handle the create
+  /// group snapshot request.
+  #[error("Not enough space to create group snapshot: {0}")]
+  NotEnoughSpace(String),
+
+  #[error(transparent)]
+  #[doc(hidden)]
+  Other(#[from] tonic::Status),
+}
+
+use tonic::{Code, Status};
+impl From<CreateVolumeGroupSnapshotError> for tonic::Status {
+  fn from(value: CreateVolumeGroupSnapshotError) -> Self {
+    match value {
+      CreateVolumeGroupSnapshotError::Other(v) => v,
+      value => {
+        let code = match &value {
+          CreateVolumeGroupSnapshotError::AlreadyExists(_) => Code::AlreadyExists,
+          CreateVolumeGroupSnapshotError::Pending(_) => Code::Aborted,
+          CreateVolumeGroupSnapshotError::NotEnoughSpace(_) => Code::ResourceExhausted,
+          CreateVolumeGroupSnapshotError::Other(_) => unreachable!(),
+        };
+
+        Status::new(code, value.to_string())
+      }
+    }
+  }
+}
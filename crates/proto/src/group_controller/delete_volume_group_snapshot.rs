@@ -0,0 +1,129 @@
+use super::Secrets;
+use crate::proto;
+#[cfg(feature = "test-util")]
+use std::collections::HashMap;
+use std::convert::TryFrom;
+use thiserror::Error;
+
+#[derive(Debug)]
+pub struct DeleteVolumeGroupSnapshotRequest {
+  group_snapshot_id: String,
+  snapshot_ids: Vec<String>,
+  secrets: Secrets,
+}
+
+impl DeleteVolumeGroupSnapshotRequest {
+  /// The ID of the group snapshot to be deleted.
+  #[inline]
+  pub fn group_snapshot_id(&self) -> &str {
+    &self.group_snapshot_id
+  }
+
+  /// The IDs of the snapshots that are part of the group snapshot. If the
+  /// plugin has the ability to detect a mismatch with the snapshots it
+  /// knows to be in the group, it MUST report an error.
+  #[inline]
+  pub fn snapshot_ids(&self) -> impl ExactSizeIterator<Item = &str> {
+    self.snapshot_ids.iter().map(|v| &**v)
+  }
+
+  /// Secrets required by plugin to complete the request. This field is
+  /// OPTIONAL. Refer to the `Secrets Requirements` section on how to use
+  /// this field.
+  #[inline]
+  pub fn secrets(&self) -> &Secrets {
+    &self.secrets
+  }
+}
+
+#[cfg(feature = "test-util")]
+impl DeleteVolumeGroupSnapshotRequest {
+  #[inline]
+  pub fn new(group_snapshot_id: impl Into<String>, snapshot_ids: Vec<String>) -> Self {
+    DeleteVolumeGroupSnapshotRequest {
+      group_snapshot_id: group_snapshot_id.into(),
+      snapshot_ids,
+      secrets: Secrets::default(),
+    }
+  }
+
+  #[inline]
+  pub fn with_secrets(mut self, secrets: HashMap<String, String>) -> Self {
+    self.secrets = secrets.into();
+    self
+  }
+}
+
+impl TryFrom<proto::DeleteVolumeGroupSnapshotRequest> for DeleteVolumeGroupSnapshotRequest {
+  type Error = tonic::Status;
+
+  fn try_from(value: proto::DeleteVolumeGroupSnapshotRequest) -> Result<Self, Self::Error> {
+    let group_snapshot_id = match value.group_snapshot_id {
+      v if v.is_empty() => {
+        return Err(tonic::Status::invalid_argument(
+          "DeleteVolumeGroupSnapshotRequest.group_snapshot_id is empty",
+        ))
+      }
+      v => v,
+    };
+
+    let snapshot_ids = match value.snapshot_ids {
+      v if v.is_empty() => {
+        return Err(tonic::Status::invalid_argument(
+          "DeleteVolumeGroupSnapshotRequest.snapshot_ids is empty",
+        ))
+      }
+      v if v.iter().any(String::is_empty) => {
+        return Err(tonic::Status::invalid_argument(
+          "DeleteVolumeGroupSnapshotRequest.snapshot_ids contains an empty id",
+        ))
+      }
+      v => v,
+    };
+
+    let secrets = value.secrets.into();
+
+    Ok(DeleteVolumeGroupSnapshotRequest {
+      group_snapshot_id,
+      snapshot_ids,
+      secrets,
+    })
+  }
+}
+
+#[non_exhaustive]
+#[derive(Debug, Error)]
+pub enum DeleteVolumeGroupSnapshotError {
+  /// Indicates that the `snapshot_ids` do not match the snapshots in the
+  /// group snapshot.
+  #[error("Snapshot list mismatch: {0}")]
+  SnapshotListMismatch(String),
+
+  /// Indicates that there is already an operation pending for the specified
+  /// group snapshot. The Plugin MAY return this error code to reject secondary
+  /// calls.
+  #[error("Operation pending for group snapshot: {0}")]
+  Pending(String),
+
+  #[error(transparent)]
+  #[doc(hidden)]
+  Other(#[from] tonic::Status),
+}
+
+use tonic::{Code, Status};
+impl From<DeleteVolumeGroupSnapshotError> for tonic::Status {
+  fn from(value: DeleteVolumeGroupSnapshotError) -> Self {
+    match value {
+      DeleteVolumeGroupSnapshotError::Other(v) => v,
+      value => {
+        let code = match &value {
+          DeleteVolumeGroupSnapshotError::SnapshotListMismatch(_) => Code::FailedPrecondition,
+          DeleteVolumeGroupSnapshotError::Pending(_) => Code::Aborted,
+          DeleteVolumeGroupSnapshotError::Other(_) => unreachable!(),
+        };
+
+        Status::new(code, value.to_string())
+      }
+    }
+  }
+}
@@ -0,0 +1,128 @@
+use super::Secrets;
+use crate::proto;
+#[cfg(feature = "test-util")]
+use std::collections::HashMap;
+use std::convert::TryFrom;
+use thiserror::Error;
+
+#[derive(Debug)]
+pub struct GetVolumeGroupSnapshotRequest {
+  group_snapshot_id: String,
+  snapshot_ids: Vec<String>,
+  secrets: Secrets,
+}
+
+impl GetVolumeGroupSnapshotRequest {
+  /// The ID of the group snapshot to fetch current information for.
+  #[inline]
+  pub fn group_snapshot_id(&self) -> &str {
+    &self.group_snapshot_id
+  }
+
+  /// The IDs of the snapshots that are part of the group snapshot. If the
+  /// plugin has the ability to detect a mismatch with the snapshots it
+  /// knows to be in the group, it MUST report an error.
+  #[inline]
+  pub fn snapshot_ids(&self) -> impl ExactSizeIterator<Item = &str> {
+    self.snapshot_ids.iter().map(|v| &**v)
+  }
+
+  /// Secrets required by plugin to complete the request. This field is
+  /// OPTIONAL. Refer to the `Secrets Requirements` section on how to use
+  /// this field.
+  #[inline]
+  pub fn secrets(&self) -> &Secrets {
+    &self.secrets
+  }
+}
+
+#[cfg(feature = "test-util")]
+impl GetVolumeGroupSnapshotRequest {
+  #[inline]
+  pub fn new(group_snapshot_id: impl Into<String>, snapshot_ids: Vec<String>) -> Self {
+    GetVolumeGroupSnapshotRequest {
+      group_snapshot_id: group_snapshot_id.into(),
+      snapshot_ids,
+      secrets: Secrets::default(),
+    }
+  }
+
+  #[inline]
+  pub fn with_secrets(mut self, secrets: HashMap<String, String>) -> Self {
+    self.secrets = secrets.into();
+    self
+  }
+}
+
+impl TryFrom<proto::GetVolumeGroupSnapshotRequest> for GetVolumeGroupSnapshotRequest {
+  type Error = tonic::Status;
+
+  fn try_from(value: proto::GetVolumeGroupSnapshotRequest) -> Result<Self, Self::Error> {
+    let group_snapshot_id = match value.group_snapshot_id {
+      v if v.is_empty() => {
+        return Err(tonic::Status::invalid_argument(
+          "GetVolumeGroupSnapshotRequest.group_snapshot_id is empty",
+        ))
+      }
+      v => v,
+    };
+
+    let snapshot_ids = match value.snapshot_ids {
+      v if v.is_empty() => {
+        return Err(tonic::Status::invalid_argument(
+          "GetVolumeGroupSnapshotRequest.snapshot_ids is empty",
+        ))
+      }
+      v if v.iter().any(String::is_empty) => {
+        return Err(tonic::Status::invalid_argument(
+          "GetVolumeGroupSnapshotRequest.snapshot_ids contains an empty id",
+        ))
+      }
+      v => v,
+    };
+
+    let secrets = value.secrets.into();
+
+    Ok(GetVolumeGroupSnapshotRequest {
+      group_snapshot_id,
+      snapshot_ids,
+      secrets,
+    })
+  }
+}
+
+#[non_exhaustive]
+#[derive(Debug, Error)]
+pub enum GetVolumeGroupSnapshotError {
+  /// Indicates that a group snapshot corresponding to the specified
+  /// `group_snapshot_id` does not exist.
+  #[error("Group snapshot does not exist: {0}")]
+  GroupSnapshotNotFound(String),
+
+  /// Indicates that the `snapshot_ids` do not match the snapshots in the
+  /// group snapshot.
+  #[error("Snapshot list mismatch: {0}")]
+  SnapshotListMismatch(String),
+
+  #[error(transparent)]
+  #[doc(hidden)]
+  Other(#[from] tonic::Status),
+}
+
+use tonic::{Code, Status};
+impl From<GetVolumeGroupSnapshotError> for tonic::Status {
+  fn from(value: GetVolumeGroupSnapshotError) -> Self {
+    match value {
+      GetVolumeGroupSnapshotError::Other(v) => v,
+      value => {
+        let code = match &value {
+          GetVolumeGroupSnapshotError::GroupSnapshotNotFound(_) => Code::NotFound,
+          GetVolumeGroupSnapshotError::SnapshotListMismatch(_) => Code::FailedPrecondition,
+          GetVolumeGroupSnapshotError::Other(_) => unreachable!(),
+        };
+
+        Status::new(code, value.to_string())
+      }
+    }
+  }
+}
@@ -0,0 +1,218 @@
+use super::Snapshot;
+use crate::proto;
+use prost_types::Timestamp;
+use std::convert::{TryFrom, TryInto};
+
+#[derive(Debug)]
+pub struct VolumeGroupSnapshot {
+  /// The identifier for this group snapshot, generated by the plugin.
+  /// This field MUST contain enough information to uniquely identify
+  /// this specific snapshot vs all other group snapshots supported by
+  /// this plugin.
+  /// This field SHALL be used by the CO in subsequent calls to refer to
+  /// this group snapshot.
+  /// The SP is NOT responsible for global uniqueness of
+  /// group_snapshot_id across multiple SPs.
+  /// This field is REQUIRED.
+  group_snapshot_id: String,
+  /// A list of snapshots belonging to this group.
+  /// This field is REQUIRED.
+  snapshots: Vec<Snapshot>,
+  /// Timestamp of when the volume group snapshot was taken.
+  /// This field is REQUIRED.
+  creation_time: Timestamp,
+  /// Indicates if all individual snapshots in the group snapshot
+  /// are ready to use as a `volume_content_source` in a
+  /// `CreateVolumeRequest`. The default value is false.
+  /// This field is REQUIRED.
+  ready_to_use: bool,
+}
+
+impl VolumeGroupSnapshot {
+  /// Create a group snapshot. Fails with `INTERNAL` if
+  /// `group_snapshot_id` is empty, as it is REQUIRED. At least one
+  /// snapshot must be added, which is checked when the group snapshot is
+  /// returned to the CO.
+  pub fn new(
+    group_snapshot_id: impl Into<String>,
+    creation_time: impl Into<Timestamp>,
+  ) -> Result<Self, tonic::Status> {
+    let group_snapshot_id = group_snapshot_id.into();
+    if group_snapshot_id.is_empty() {
+      return Err(tonic::Status::internal(
+        "VolumeGroupSnapshot.group_snapshot_id is empty",
+      ));
+    }
+
+    Ok(VolumeGroupSnapshot {
+      group_snapshot_id,
+      snapshots: Vec::new(),
+      creation_time: creation_time.into(),
+      ready_to_use: false,
+    })
+  }
+
+  /// Add a snapshot to the group. May be called multiple times. A
+  /// snapshot that does not have a `group_snapshot_id` is assigned the id
+  /// of the group.
+  #[inline]
+  pub fn with_snapshot(mut self, mut snapshot: Snapshot) -> Self {
+    if snapshot.group_snapshot_id().is_none() {
+      snapshot.set_group_snapshot_id(&self.group_snapshot_id);
+    }
+
+    self.snapshots.push(snapshot);
+    self
+  }
+
+  /// Set whether all snapshots in the group are ready to use. Defaults to
+  /// false, and MUST be false if any of the snapshots is not ready.
+  #[inline]
+  pub fn with_ready_to_use(mut self, ready_to_use: bool) -> Self {
+    self.ready_to_use = ready_to_use;
+    self
+  }
+
+  #[inline]
+  pub fn group_snapshot_id(&self) -> &str {
+    &self.group_snapshot_id
+  }
+
+  #[inline]
+  pub fn snapshots(&self) -> &[Snapshot] {
+    &self.snapshots
+  }
+
+  #[inline]
+  pub fn creation_time(&self) -> &Timestamp {
+    &self.creation_time
+  }
+
+  #[inline]
+  pub fn ready_to_use(&self) -> bool {
+    self.ready_to_use
+  }
+}
+
+impl TryFrom<VolumeGroupSnapshot> for proto::VolumeGroupSnapshot {
+  type Error = tonic::Status;
+
+  fn try_from(value: VolumeGroupSnapshot) -> Result<Self, Self::Error> {
+    if value.group_snapshot_id.is_empty() {
+      return Err(tonic::Status::internal(
+        "VolumeGroupSnapshot.group_snapshot_id is empty",
+      ));
+    }
+
+    if value.snapshots.is_empty() {
+      return Err(tonic::Status::internal(
+        "VolumeGroupSnapshot.snapshots is empty",
+      ));
+    }
+
+    for snapshot in &value.snapshots {
+      if let Some(v) = snapshot.group_snapshot_id() {
+        if v != value.group_snapshot_id {
+          return Err(tonic::Status::internal(format!(
+            "VolumeGroupSnapshot.snapshots contains snapshot {} of group {}",
+            snapshot.snapshot_id(),
+            v
+          )));
+        }
+      }
+
+      if value.ready_to_use && !snapshot.ready_to_use() {
+        return Err(tonic::Status::internal(format!(
+          "VolumeGroupSnapshot.ready_to_use is set, but snapshot {} is not ready",
+          snapshot.snapshot_id()
+        )));
+      }
+    }
+
+    let group_snapshot_id = value.group_snapshot_id;
+    let snapshots = value
+      .snapshots
+      .into_iter()
+      .map(TryInto::try_into)
+      .collect::<Result<_, _>>()?;
+    let creation_time = Some(value.creation_time);
+    let ready_to_use = value.ready_to_use;
+
+    Ok(proto::VolumeGroupSnapshot {
+      group_snapshot_id,
+      snapshots,
+      creation_time,
+      ready_to_use,
+    })
+  }
+}
+
+impl TryFrom<VolumeGroupSnapshot> for proto::CreateVolumeGroupSnapshotResponse {
+  type Error = tonic::Status;
+
+  fn try_from(value: VolumeGroupSnapshot) -> Result<Self, Self::Error> {
+    let group_snapshot = Some(value.try_into()?);
+
+    Ok(proto::CreateVolumeGroupSnapshotResponse { group_snapshot })
+  }
+}
+
+impl TryFrom<VolumeGroupSnapshot> for proto::GetVolumeGroupSnapshotResponse {
+  type Error = tonic::Status;
+
+  fn try_from(value: VolumeGroupSnapshot) -> Result<Self, Self::Error> {
+    let group_snapshot = Some(value.try_into()?);
+
+    Ok(proto::GetVolumeGroupSnapshotResponse { group_snapshot })
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use std::time::SystemTime;
+
+  fn snapshot(snapshot_id: &str, ready_to_use: bool) -> Snapshot {
+    Snapshot::new(snapshot_id, "vol-1", SystemTime::UNIX_EPOCH)
+      .unwrap()
+      .with_ready_to_use(ready_to_use)
+  }
+
+  #[test]
+  fn assigns_group_snapshot_id() {
+    let group_snapshot = VolumeGroupSnapshot::new("group-1", SystemTime::UNIX_EPOCH)
+      .unwrap()
+      .with_snapshot(snapshot("snap-1", true))
+      .with_snapshot(snapshot("snap-2", true))
+      .with_ready_to_use(true);
+
+    let group_snapshot = proto::VolumeGroupSnapshot::try_from(group_snapshot).unwrap();
+    for snapshot in group_snapshot.snapshots {
+      assert_eq!(snapshot.group_snapshot_id, "group-1");
+    }
+  }
+
+  #[test]
+  fn rejects_ready_group_with_unready_snapshot() {
+    let group_snapshot = VolumeGroupSnapshot::new("group-1", SystemTime::UNIX_EPOCH)
+      .unwrap()
+      .with_snapshot(snapshot("snap-1", true))
+      .with_snapshot(snapshot("snap-2", false))
+      .with_ready_to_use(true);
+
+    let err = proto::VolumeGroupSnapshot::try_from(group_snapshot).unwrap_err();
+    assert_eq!(err.code(), tonic::Code::Internal);
+  }
+
+  #[test]
+  fn rejects_empty_ids() {
+    let err = VolumeGroupSnapshot::new("", SystemTime::UNIX_EPOCH).unwrap_err();
+    assert_eq!(err.code(), tonic::Code::Internal);
+
+    let err = Snapshot::new("", "vol-1", SystemTime::UNIX_EPOCH).unwrap_err();
+    assert_eq!(err.code(), tonic::Code::Internal);
+
+    let err = Snapshot::new("snap-1", "", SystemTime::UNIX_EPOCH).unwrap_err();
+    assert_eq!(err.code(), tonic::Code::Internal);
+  }
+}
@@ -1,3 +1,7 @@
+// Most functions fail with the `tonic::Status` returned to the CO, which is
+// larger than clippy would like an error to be.
+#![allow(clippy::result_large_err)]
+
 macro_rules! unsupported {
   ($name:expr) => {{
     ::tracing::error!("Unsupported method {} called", $name);
@@ -12,20 +16,30 @@ macro_rules! unsupported {
 }
 
 pub mod controller;
+#[cfg(feature = "alpha")]
+pub mod group_controller;
 pub mod node;
+pub mod secrets;
+pub mod server;
+#[cfg(feature = "alpha")]
+pub mod snapshot_metadata;
 pub mod volume;
 
 mod plugin;
 mod proto;
-mod secrets;
-mod utils;
+mod response;
 
 use std::collections::HashMap;
 
 use lazy_static::lazy_static;
 
 pub use controller::ControllerService;
+#[cfg(feature = "alpha")]
+pub use group_controller::GroupControllerService;
 pub use node::NodeService;
+pub use server::PluginServer;
+#[cfg(feature = "alpha")]
+pub use snapshot_metadata::SnapshotMetadataService;
 
 #[derive(Eq, Clone, Copy, PartialEq, Debug, Hash)]
 pub enum VolumeExpansionSupport {
@@ -59,6 +73,16 @@ pub trait IdentityService: Send + Sync + 'static {
     VolumeExpansionSupport::None
   }
 
+  /// Whether or not this plugin opts in to advertising capabilities that
+  /// are marked as alpha in the CSI spec. Unless it does, alpha
+  /// capabilities returned by the plugin are left out of capability
+  /// responses.
+  #[cfg(feature = "alpha")]
+  #[inline]
+  fn enable_alpha_features(&self) -> bool {
+    false
+  }
+
   #[inline]
   fn ready(&self) -> bool {
     true
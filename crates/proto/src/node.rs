@@ -8,9 +8,12 @@ mod unpublish_volume;
 mod unstage_volume;
 
 use crate::{
-  plugin, proto,
+  controller::ControllerCapabilities,
+  plugin::require_volume_expansion,
+  proto,
+  response::{Advertised, ValidateResponse},
   secrets::*,
-  utils::{record_request, Record},
+  server::{Dispatch, OperationKey},
   IdentityService,
 };
 use async_trait::async_trait;
@@ -29,12 +32,25 @@ pub use unstage_volume::*;
 
 #[async_trait]
 pub trait NodeService: IdentityService {
-  /// Get the set of services provided by this controller.
+  /// Get the set of services provided by this node. Calls of RPCs that
+  /// require a capability that is not returned are rejected with
+  /// `UNIMPLEMENTED` before they reach the plugin, and capabilities
+  /// advertised without those they depend on are logged as warnings when
+  /// the server starts.
   #[inline]
   fn capabilities(&self) -> NodeCapabilities {
     NodeCapabilities::empty()
   }
 
+  /// The RPC methods the plugin implements, of those that require a
+  /// capability. When returned, methods implemented without advertising
+  /// their capability, and advertised capabilities whose methods are not
+  /// implemented, are logged as warnings when the server starts.
+  #[inline]
+  fn implemented_methods(&self) -> Option<NodeMethods> {
+    None
+  }
+
   /// A Node Plugin MUST implement this RPC call if it has `STAGE_UNSTAGE_VOLUME`
   /// node capability.
   ///
@@ -219,163 +235,323 @@ pub trait NodeService: IdentityService {
   }
 }
 
-struct Node<T: NodeService>(Arc<T>);
-
-#[async_trait]
-impl<T: NodeService> proto::identity_server::Identity for Node<T> {
-  #[instrument(
-    name = "identity.get_plugin_info",
-    skip(self, _request),
-    fields(name, vendor_version, manifest)
-  )]
-  async fn get_plugin_info(
-    &self,
-    _request: tonic::Request<proto::GetPluginInfoRequest>,
-  ) -> Result<tonic::Response<proto::GetPluginInfoResponse>, tonic::Status> {
-    let response = proto::GetPluginInfoResponse {
-      name: self.0.name().into(),
-      vendor_version: self.0.version().into(),
-      manifest: self.0.manifest().clone(),
-    };
-
-    Ok(tonic::Response::new(response))
-  }
-
-  // TODO: Instrument response
-  #[instrument(name = "identity.get_plugin_capabilities", skip(self, _request))]
-  async fn get_plugin_capabilities(
-    &self,
-    _request: tonic::Request<proto::GetPluginCapabilitiesRequest>,
-  ) -> Result<tonic::Response<proto::GetPluginCapabilitiesResponse>, tonic::Status> {
-    let response = plugin::get_capabilities(&*self.0);
+pub(crate) struct Node<T: NodeService> {
+  pub(crate) plugin: Arc<T>,
+  pub(crate) dispatch: Arc<Dispatch>,
+}
 
-    Ok(tonic::Response::new(response))
+impl<T: NodeService> Node<T> {
+  fn advertised(&self) -> Advertised {
+    Advertised {
+      accessibility_constraints: self.plugin.volume_accessibility_constraints_support(),
+      controller: ControllerCapabilities::empty(),
+      #[cfg(feature = "alpha")]
+      node: NodeCapabilities::advertised(&*self.plugin),
+    }
   }
 
-  #[instrument(name = "identity.probe", skip(self, _request), fields(ready))]
-  async fn probe(
-    &self,
-    _request: tonic::Request<proto::ProbeRequest>,
-  ) -> Result<tonic::Response<proto::ProbeResponse>, tonic::Status> {
-    let response = proto::ProbeResponse {
-      ready: Some(self.0.ready()),
-    };
+  /// Fails with `UNIMPLEMENTED` unless the plugin advertises `capability`,
+  /// so the plugin is only called for the RPCs it advertises.
+  fn require(&self, method: &str, capability: NodeCapabilities) -> Result<(), tonic::Status> {
+    if NodeCapabilities::advertised(&*self.plugin).contains(capability) {
+      return Ok(());
+    }
 
-    Ok(tonic::Response::new(response))
+    Err(tonic::Status::unimplemented(format!(
+      "{} is not supported, the {:?} capability is not advertised",
+      method, capability
+    )))
   }
 }
 
 #[async_trait]
 impl<T: NodeService> proto::node_server::Node for Node<T> {
-  #[instrument(name = "node.node_stage_volume", skip(self, request), fields(request))]
+  #[instrument(
+    name = "node.node_stage_volume",
+    skip(self, request),
+    fields(request, response, code, error, latency_ms)
+  )]
   async fn node_stage_volume(
     &self,
     request: tonic::Request<proto::NodeStageVolumeRequest>,
   ) -> Result<tonic::Response<proto::NodeStageVolumeResponse>, tonic::Status> {
-    let request = record_request(request.into_inner().try_into()?);
-    self.0.node_stage_volume(request).await?;
-    let response = proto::NodeStageVolumeResponse {};
-    Ok(tonic::Response::new(response))
+    self
+      .dispatch
+      .call("NodeStageVolume", request, |request, _call| async move {
+        self.require("NodeStageVolume", NodeCapabilities::STAGE_UNSTAGE_VOLUME)?;
+        let request = self.dispatch.check_request(request)?;
+        let request: NodeStageVolumeRequest = request.try_into()?;
+        let _lock = self
+          .dispatch
+          .lock(OperationKey::VolumeId(request.volume_id().to_owned()))?;
+        self.plugin.node_stage_volume(request).await?;
+        let response = proto::NodeStageVolumeResponse {};
+        Ok(response)
+      })
+      .await
   }
 
   #[instrument(
     name = "node.node_unstage_volume",
     skip(self, request),
-    fields(request)
+    fields(request, response, code, error, latency_ms)
   )]
   async fn node_unstage_volume(
     &self,
     request: tonic::Request<proto::NodeUnstageVolumeRequest>,
   ) -> Result<tonic::Response<proto::NodeUnstageVolumeResponse>, tonic::Status> {
-    let request = record_request(request.into_inner().try_into()?);
-    self.0.node_unstage_volume(request).await?;
-    let response = proto::NodeUnstageVolumeResponse {};
-    Ok(tonic::Response::new(response))
+    self
+      .dispatch
+      .call("NodeUnstageVolume", request, |request, _call| async move {
+        self.require("NodeUnstageVolume", NodeCapabilities::STAGE_UNSTAGE_VOLUME)?;
+        let request = self.dispatch.check_request(request)?;
+        let request: NodeUnstageVolumeRequest = request.try_into()?;
+        let _lock = self
+          .dispatch
+          .lock(OperationKey::VolumeId(request.volume_id().to_owned()))?;
+        self.plugin.node_unstage_volume(request).await?;
+        let response = proto::NodeUnstageVolumeResponse {};
+        Ok(response)
+      })
+      .await
   }
 
   #[instrument(
     name = "node.node_publish_volume",
     skip(self, request),
-    fields(request)
+    fields(request, response, code, error, latency_ms)
   )]
   async fn node_publish_volume(
     &self,
     request: tonic::Request<proto::NodePublishVolumeRequest>,
   ) -> Result<tonic::Response<proto::NodePublishVolumeResponse>, tonic::Status> {
-    let request = record_request(request.into_inner().try_into()?);
-    self.0.node_publish_volume(request).await?;
-    let response = proto::NodePublishVolumeResponse {};
-    Ok(tonic::Response::new(response))
+    self
+      .dispatch
+      .call("NodePublishVolume", request, |request, _call| async move {
+        let request = self.dispatch.check_request(request)?;
+        let request: NodePublishVolumeRequest = request.try_into()?;
+        let _lock = self.dispatch.lock(OperationKey::VolumeTargetPath(
+          request.volume_id().to_owned(),
+          request.target_path().to_owned(),
+        ))?;
+        self.plugin.node_publish_volume(request).await?;
+        let response = proto::NodePublishVolumeResponse {};
+        Ok(response)
+      })
+      .await
   }
 
   #[instrument(
     name = "node.node_unpublish_volume",
     skip(self, request),
-    fields(request)
+    fields(request, response, code, error, latency_ms)
   )]
   async fn node_unpublish_volume(
     &self,
     request: tonic::Request<proto::NodeUnpublishVolumeRequest>,
   ) -> Result<tonic::Response<proto::NodeUnpublishVolumeResponse>, tonic::Status> {
-    let request = record_request(request.into_inner().try_into()?);
-    self.0.node_unpublish_volume(request).await?;
-    let response = proto::NodeUnpublishVolumeResponse {};
-    Ok(tonic::Response::new(response))
+    self
+      .dispatch
+      .call(
+        "NodeUnpublishVolume",
+        request,
+        |request, _call| async move {
+          let request = self.dispatch.check_request(request)?;
+          let request: NodeUnpublishVolumeRequest = request.try_into()?;
+          let _lock = self.dispatch.lock(OperationKey::VolumeTargetPath(
+            request.volume_id().to_owned(),
+            request.target_path().to_owned(),
+          ))?;
+          self.plugin.node_unpublish_volume(request).await?;
+          let response = proto::NodeUnpublishVolumeResponse {};
+          Ok(response)
+        },
+      )
+      .await
   }
 
   #[instrument(
     name = "node.node_get_volume_stats",
     skip(self, request),
-    fields(request, response)
+    fields(request, response, code, error, latency_ms)
   )]
   async fn node_get_volume_stats(
     &self,
     request: tonic::Request<proto::NodeGetVolumeStatsRequest>,
   ) -> Result<tonic::Response<proto::NodeGetVolumeStatsResponse>, tonic::Status> {
-    let request = record_request(request.into_inner().try_into()?);
-    let response = self
-      .0
-      .node_get_volume_stats(request)
-      .await?
-      .record_response()
-      .try_into()?;
-    Ok(tonic::Response::new(response))
+    self
+      .dispatch
+      .call("NodeGetVolumeStats", request, |request, _call| async move {
+        self.require("NodeGetVolumeStats", NodeCapabilities::GET_VOLUME_STATS)?;
+        let request = self.dispatch.check_request(request)?;
+        let request = request.try_into()?;
+        let response = self.plugin.node_get_volume_stats(request).await?;
+        #[cfg(feature = "alpha")]
+        let response = response.validated("NodeGetVolumeStats", &self.advertised())?;
+        let response = response.try_into()?;
+        let response = self.dispatch.check_response(response)?;
+        Ok(response)
+      })
+      .await
   }
 
   #[instrument(
     name = "node.node_expand_volume",
     skip(self, request),
-    fields(request, response)
+    fields(request, response, code, error, latency_ms)
   )]
   async fn node_expand_volume(
     &self,
     request: tonic::Request<proto::NodeExpandVolumeRequest>,
   ) -> Result<tonic::Response<proto::NodeExpandVolumeResponse>, tonic::Status> {
-    let request = record_request(request.into_inner().try_into()?);
-    let response = self
-      .0
-      .node_expand_volume(request)
-      .await?
-      .record_response()
-      .try_into()?;
-    Ok(tonic::Response::new(response))
+    self
+      .dispatch
+      .call("NodeExpandVolume", request, |request, _call| async move {
+        self.require("NodeExpandVolume", NodeCapabilities::EXPAND_VOLUME)?;
+        require_volume_expansion(&*self.plugin, "NodeExpandVolume")?;
+        let request = self.dispatch.check_request(request)?;
+        let request: NodeExpandVolumeRequest = request.try_into()?;
+        let _lock = self
+          .dispatch
+          .lock(OperationKey::VolumeId(request.volume_id().to_owned()))?;
+        let response = self.plugin.node_expand_volume(request).await?.try_into()?;
+        let response = self.dispatch.check_response(response)?;
+        Ok(response)
+      })
+      .await
   }
 
-  #[instrument(name = "node.node_get_capabilities", skip(self), fields(response))]
+  #[instrument(
+    name = "node.node_get_capabilities",
+    skip(self, request),
+    fields(request, response, code, error, latency_ms)
+  )]
   async fn node_get_capabilities(
     &self,
-    _: tonic::Request<proto::NodeGetCapabilitiesRequest>,
+    request: tonic::Request<proto::NodeGetCapabilitiesRequest>,
   ) -> Result<tonic::Response<proto::NodeGetCapabilitiesResponse>, tonic::Status> {
-    let response = self.0.capabilities().record_response().try_into()?;
-    Ok(tonic::Response::new(response))
+    self
+      .dispatch
+      .call("NodeGetCapabilities", request, |_, _call| async move {
+        let response = NodeCapabilities::advertised(&*self.plugin).try_into()?;
+        Ok(response)
+      })
+      .await
   }
 
-  #[instrument(name = "node.node_get_info", skip(self), fields(response))]
+  #[instrument(
+    name = "node.node_get_info",
+    skip(self, request),
+    fields(request, response, code, error, latency_ms)
+  )]
   async fn node_get_info(
     &self,
-    _: tonic::Request<proto::NodeGetInfoRequest>,
+    request: tonic::Request<proto::NodeGetInfoRequest>,
   ) -> Result<tonic::Response<proto::NodeGetInfoResponse>, tonic::Status> {
-    let response = self.0.node_get_info().await?.record_response().try_into()?;
-    Ok(tonic::Response::new(response))
+    self
+      .dispatch
+      .call("NodeGetInfo", request, |_, _call| async move {
+        let response = self
+          .plugin
+          .node_get_info()
+          .await?
+          .validated("NodeGetInfo", &self.advertised())?
+          .try_into()?;
+        let response = self.dispatch.check_response(response)?;
+        Ok(response)
+      })
+      .await
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use proto::node_server::Node as _;
+  use tokio::sync::Barrier;
+
+  /// Publishes a volume once two publish calls are in-flight.
+  struct Plugin(Barrier);
+
+  impl IdentityService for Plugin {
+    fn name(&self) -> &str {
+      "csi.example.com"
+    }
+
+    fn version(&self) -> &str {
+      "0.1.0"
+    }
+  }
+
+  #[async_trait]
+  impl NodeService for Plugin {
+    async fn node_publish_volume(
+      &self,
+      _request: NodePublishVolumeRequest,
+    ) -> Result<(), NodePublishVolumeError> {
+      self.0.wait().await;
+      Ok(())
+    }
+
+    async fn node_unpublish_volume(
+      &self,
+      _request: NodeUnpublishVolumeRequest,
+    ) -> Result<(), NodeUnpublishVolumeError> {
+      Ok(())
+    }
+  }
+
+  fn publish(target_path: &str) -> tonic::Request<proto::NodePublishVolumeRequest> {
+    tonic::Request::new(proto::NodePublishVolumeRequest {
+      volume_id: "vol-1".into(),
+      target_path: std::env::temp_dir()
+        .join(target_path)
+        .to_string_lossy()
+        .into_owned(),
+      volume_capability: Some(
+        VolumeCapability::new(AccessMode::MultiNodeMultiWriter, AccessType::Block)
+          .try_into()
+          .unwrap(),
+      ),
+      ..Default::default()
+    })
+  }
+
+  fn node() -> Node<Plugin> {
+    let dispatch = Arc::new(Dispatch::default());
+    dispatch.enable_locking();
+    Node {
+      plugin: Arc::new(Plugin(Barrier::new(2))),
+      dispatch,
+    }
+  }
+
+  #[tokio::test]
+  async fn publishes_at_several_target_paths_concurrently() {
+    let node = node();
+    let (first, second) = futures::future::join(
+      node.node_publish_volume(publish("target-1")),
+      node.node_publish_volume(publish("target-2")),
+    )
+    .await;
+
+    first.unwrap();
+    second.unwrap();
+  }
+
+  #[tokio::test]
+  async fn rejects_concurrent_publish_at_the_same_target_path() {
+    let node = node();
+    let (first, second) = futures::future::select(
+      Box::pin(node.node_publish_volume(publish("target-1"))),
+      Box::pin(node.node_publish_volume(publish("target-1"))),
+    )
+    .await
+    .factor_first();
+
+    // The call holding the lock waits for another publish at the barrier,
+    // so only the rejected one completes.
+    drop(second);
+    assert_eq!(first.unwrap_err().code(), tonic::Code::Aborted);
   }
 }
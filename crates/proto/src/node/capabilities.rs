@@ -2,7 +2,8 @@ use std::convert::TryFrom;
 
 use bitflags::bitflags;
 
-use crate::proto;
+use crate::{proto, NodeService, VolumeExpansionSupport};
+use tracing::warn;
 
 #[rustfmt::skip]
 bitflags! {
@@ -10,7 +11,124 @@ bitflags! {
     const STAGE_UNSTAGE_VOLUME         = 0b_0000_0000_0000_0001;
     const GET_VOLUME_STATS             = 0b_0000_0000_0000_0010;
     const EXPAND_VOLUME                = 0b_0000_0000_0000_0100;
+    #[cfg(feature = "alpha")]
     const VOLUME_CONDITION             = 0b_0000_0000_0000_1000;
+
+    /// Indicates the SP supports the SINGLE_NODE_SINGLE_WRITER and/or
+    /// SINGLE_NODE_MULTI_WRITER access modes.
+    const SINGLE_NODE_MULTI_WRITER     = 0b_0000_0000_0001_0000;
+
+    /// Indicates that Node service supports mounting volumes
+    /// with provided volume group identifier during node stage
+    /// or node publish RPC calls.
+    const VOLUME_MOUNT_GROUP           = 0b_0000_0000_0010_0000;
+  }
+}
+
+#[rustfmt::skip]
+bitflags! {
+  /// The RPC methods of [`NodeService`] that a plugin implements only if
+  /// it advertises their capability, see
+  /// [`NodeService::implemented_methods`].
+  pub struct NodeMethods: u32 {
+    const NODE_STAGE_VOLUME            = 0b_0000_0000_0000_0001;
+    const NODE_UNSTAGE_VOLUME          = 0b_0000_0000_0000_0010;
+    const NODE_GET_VOLUME_STATS        = 0b_0000_0000_0000_0100;
+    const NODE_EXPAND_VOLUME           = 0b_0000_0000_0000_1000;
+  }
+}
+
+impl NodeMethods {
+  /// The capability each method requires.
+  #[rustfmt::skip]
+  const CAPABILITIES: &'static [(Self, NodeCapabilities)] = &[
+    (Self::NODE_STAGE_VOLUME,     NodeCapabilities::STAGE_UNSTAGE_VOLUME),
+    (Self::NODE_UNSTAGE_VOLUME,   NodeCapabilities::STAGE_UNSTAGE_VOLUME),
+    (Self::NODE_GET_VOLUME_STATS, NodeCapabilities::GET_VOLUME_STATS),
+    (Self::NODE_EXPAND_VOLUME,    NodeCapabilities::EXPAND_VOLUME),
+  ];
+
+  /// Split the methods that do not match `capabilities` into those that
+  /// are implemented without their capability, and those that are not
+  /// implemented although their capability is advertised.
+  fn mismatched(self, capabilities: NodeCapabilities) -> (Self, Self) {
+    let mut unadvertised = Self::empty();
+    let mut unimplemented = Self::empty();
+    for &(method, capability) in Self::CAPABILITIES {
+      match (self.contains(method), capabilities.contains(capability)) {
+        (true, false) => unadvertised |= method,
+        (false, true) => unimplemented |= method,
+        _ => {}
+      }
+    }
+
+    (unadvertised, unimplemented)
+  }
+}
+
+impl NodeCapabilities {
+  /// Capabilities marked as alpha in the CSI spec.
+  #[cfg(feature = "alpha")]
+  pub(crate) const ALPHA: Self = Self::VOLUME_CONDITION;
+
+  /// The capabilities advertised by `plugin`, leaving out alpha
+  /// capabilities unless the plugin has enabled alpha features.
+  pub(crate) fn advertised<T: NodeService + ?Sized>(plugin: &T) -> Self {
+    let capabilities = plugin.capabilities();
+    #[cfg(feature = "alpha")]
+    let capabilities = if plugin.enable_alpha_features() {
+      capabilities
+    } else {
+      capabilities - Self::ALPHA
+    };
+
+    capabilities
+  }
+
+  /// Log a warning for each capability of `plugin` that is advertised
+  /// without the capabilities or plugin capabilities it depends on, for
+  /// alpha capabilities left out because alpha features are not enabled,
+  /// and for the methods the plugin declares as implemented that do not
+  /// match the advertised capabilities.
+  pub(crate) fn check<T: NodeService + ?Sized>(plugin: &T) {
+    #[cfg(feature = "alpha")]
+    if !plugin.enable_alpha_features() && plugin.capabilities().intersects(Self::ALPHA) {
+      warn!(
+        capabilities = ?(plugin.capabilities() & Self::ALPHA),
+        "Not advertising alpha node capabilities, alpha features are not enabled by the plugin"
+      );
+    }
+
+    let capabilities = Self::advertised(plugin);
+    #[cfg(feature = "alpha")]
+    if capabilities.contains(Self::VOLUME_CONDITION)
+      && !capabilities.contains(Self::GET_VOLUME_STATS)
+    {
+      warn!("Node capability VOLUME_CONDITION is advertised without GET_VOLUME_STATS");
+    }
+
+    if capabilities.contains(Self::EXPAND_VOLUME)
+      && plugin.volume_expansion_support() == VolumeExpansionSupport::None
+    {
+      warn!("Node capability EXPAND_VOLUME is advertised, but the plugin does not support volume expansion");
+    }
+
+    if let Some(methods) = plugin.implemented_methods() {
+      let (unadvertised, unimplemented) = methods.mismatched(capabilities);
+      if !unadvertised.is_empty() {
+        warn!(
+          methods = ?unadvertised,
+          "Node methods are implemented, but their capabilities are not advertised"
+        );
+      }
+
+      if !unimplemented.is_empty() {
+        warn!(
+          methods = ?unimplemented,
+          "Node capabilities are advertised, but their methods are not implemented"
+        );
+      }
+    }
   }
 }
 
@@ -37,7 +155,7 @@ impl TryFrom<NodeCapabilities> for proto::NodeGetCapabilitiesResponse {
       }
     }
 
-    let mut capabilities = Vec::with_capacity(4 /* number of different capabilities */);
+    let mut capabilities = Vec::with_capacity(6 /* number of different capabilities */);
     push_cap(
       &mut capabilities,
       value,
@@ -56,13 +174,42 @@ impl TryFrom<NodeCapabilities> for proto::NodeGetCapabilitiesResponse {
       NodeCapabilities::EXPAND_VOLUME,
       Type::ExpandVolume,
     );
+    #[cfg(feature = "alpha")]
     push_cap(
       &mut capabilities,
       value,
       NodeCapabilities::VOLUME_CONDITION,
       Type::VolumeCondition,
     );
+    push_cap(
+      &mut capabilities,
+      value,
+      NodeCapabilities::SINGLE_NODE_MULTI_WRITER,
+      Type::SingleNodeMultiWriter,
+    );
+    push_cap(
+      &mut capabilities,
+      value,
+      NodeCapabilities::VOLUME_MOUNT_GROUP,
+      Type::VolumeMountGroup,
+    );
 
     Ok(proto::NodeGetCapabilitiesResponse { capabilities })
   }
 }
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use test_case::test_case;
+
+  type Methods = NodeMethods;
+  type Capabilities = NodeCapabilities;
+
+  #[test_case(Methods::NODE_STAGE_VOLUME | Methods::NODE_UNSTAGE_VOLUME, Capabilities::STAGE_UNSTAGE_VOLUME => (Methods::empty(), Methods::empty()) ; "matching")]
+  #[test_case(Methods::NODE_GET_VOLUME_STATS, Capabilities::empty() => (Methods::NODE_GET_VOLUME_STATS, Methods::empty()) ; "implemented without capability")]
+  #[test_case(Methods::NODE_STAGE_VOLUME, Capabilities::STAGE_UNSTAGE_VOLUME | Capabilities::EXPAND_VOLUME => (Methods::empty(), Methods::NODE_UNSTAGE_VOLUME | Methods::NODE_EXPAND_VOLUME) ; "advertised without method")]
+  fn mismatched_methods(methods: Methods, capabilities: Capabilities) -> (Methods, Methods) {
+    methods.mismatched(capabilities)
+  }
+}
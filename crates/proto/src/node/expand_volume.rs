@@ -1,5 +1,7 @@
-use super::{CapacityRange, VolumeCapability};
+use super::{CapacityRange, Secrets, VolumeCapability};
 use crate::proto;
+#[cfg(feature = "test-util")]
+use std::collections::HashMap;
 use std::{
   convert::{TryFrom, TryInto},
   num::NonZeroU64,
@@ -14,6 +16,7 @@ pub struct NodeExpandVolumeRequest {
   capacity_range: Option<CapacityRange>,
   staging_target_path: Option<PathBuf>,
   volume_capability: Option<VolumeCapability>,
+  secrets: Secrets,
 }
 
 impl NodeExpandVolumeRequest {
@@ -71,6 +74,53 @@ impl NodeExpandVolumeRequest {
   pub fn volume_capability(&self) -> Option<&VolumeCapability> {
     self.volume_capability.as_ref()
   }
+
+  /// Secrets required by plugin to complete node expand volume request.
+  /// This field is OPTIONAL. Refer to the `Secrets Requirements`
+  /// section on how to use this field.
+  #[inline]
+  pub fn secrets(&self) -> &Secrets {
+    &self.secrets
+  }
+}
+
+#[cfg(feature = "test-util")]
+impl NodeExpandVolumeRequest {
+  #[inline]
+  pub fn new(volume_id: impl Into<String>, volume_path: impl Into<PathBuf>) -> Self {
+    NodeExpandVolumeRequest {
+      volume_id: volume_id.into(),
+      volume_path: volume_path.into(),
+      capacity_range: None,
+      staging_target_path: None,
+      volume_capability: None,
+      secrets: Secrets::default(),
+    }
+  }
+
+  #[inline]
+  pub fn with_capacity_range(mut self, capacity_range: CapacityRange) -> Self {
+    self.capacity_range = Some(capacity_range);
+    self
+  }
+
+  #[inline]
+  pub fn with_staging_target_path(mut self, staging_target_path: impl Into<PathBuf>) -> Self {
+    self.staging_target_path = Some(staging_target_path.into());
+    self
+  }
+
+  #[inline]
+  pub fn with_volume_capability(mut self, volume_capability: VolumeCapability) -> Self {
+    self.volume_capability = Some(volume_capability);
+    self
+  }
+
+  #[inline]
+  pub fn with_secrets(mut self, secrets: HashMap<String, String>) -> Self {
+    self.secrets = secrets.into();
+    self
+  }
 }
 
 impl TryFrom<proto::NodeExpandVolumeRequest> for NodeExpandVolumeRequest {
@@ -116,6 +166,7 @@ impl TryFrom<proto::NodeExpandVolumeRequest> for NodeExpandVolumeRequest {
 
     let capacity_range = value.capacity_range.map(TryInto::try_into).transpose()?;
     let volume_capability = value.volume_capability.map(TryInto::try_into).transpose()?;
+    let secrets = value.secrets.into();
 
     Ok(NodeExpandVolumeRequest {
       volume_id,
@@ -123,6 +174,7 @@ impl TryFrom<proto::NodeExpandVolumeRequest> for NodeExpandVolumeRequest {
       capacity_range,
       staging_target_path,
       volume_capability,
+      secrets,
     })
   }
 }
@@ -133,6 +185,28 @@ pub struct NodeExpandVolumeResponse {
   capacity_bytes: Option<NonZeroU64>,
 }
 
+impl NodeExpandVolumeResponse {
+  /// Create a response with the capacity of the volume in bytes, where 0
+  /// means the capacity is unknown. Fails with `INTERNAL` if
+  /// `capacity_bytes` is out of range.
+  pub fn new(capacity_bytes: u64) -> Result<Self, tonic::Status> {
+    if capacity_bytes > i64::MAX as u64 {
+      return Err(tonic::Status::internal(
+        "NodeExpandVolumeResponse.capacity_bytes is out of range",
+      ));
+    }
+
+    Ok(NodeExpandVolumeResponse {
+      capacity_bytes: NonZeroU64::new(capacity_bytes),
+    })
+  }
+
+  #[inline]
+  pub fn capacity_bytes(&self) -> Option<NonZeroU64> {
+    self.capacity_bytes
+  }
+}
+
 impl TryFrom<NodeExpandVolumeResponse> for proto::NodeExpandVolumeResponse {
   type Error = tonic::Status;
 
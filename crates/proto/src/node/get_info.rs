@@ -1,6 +1,9 @@
 use super::Topology;
-use crate::proto;
-use std::{convert::TryFrom, num::NonZeroU64};
+use crate::{
+  proto,
+  response::{Advertised, ValidateResponse},
+};
+use std::{collections::HashSet, convert::TryFrom, num::NonZeroU64};
 use thiserror::Error;
 
 #[derive(Debug)]
@@ -48,15 +51,186 @@ pub struct NodeGetInfoResponse {
   accessible_topology: Option<Topology>,
 }
 
+impl NodeGetInfoResponse {
+  /// The maximum size of `node_id` in bytes.
+  pub const MAX_NODE_ID_LEN: usize = 192;
+
+  /// Create a response. Fails with `INTERNAL` if `node_id` is empty, as
+  /// it is REQUIRED, or longer than [`MAX_NODE_ID_LEN`](Self::MAX_NODE_ID_LEN).
+  pub fn new(node_id: impl Into<String>) -> Result<Self, tonic::Status> {
+    let node_id = node_id.into();
+    validate_node_id(&node_id)?;
+
+    Ok(NodeGetInfoResponse {
+      node_id,
+      max_volumes_per_node: None,
+      accessible_topology: None,
+    })
+  }
+
+  /// Set the maximum number of volumes that controller can publish to the
+  /// node. A value of 0 leaves the decision to the CO, which is also the
+  /// default.
+  #[inline]
+  pub fn with_max_volumes_per_node(mut self, max_volumes_per_node: u64) -> Self {
+    self.max_volumes_per_node = NonZeroU64::new(max_volumes_per_node);
+    self
+  }
+
+  /// Set where the node is accessible from. The plugin MUST also report
+  /// the `VOLUME_ACCESSIBILITY_CONSTRAINTS` plugin capability. Fails with
+  /// `INTERNAL` if the topology breaks the rules of the spec for its keys
+  /// and segments.
+  pub fn with_accessible_topology(
+    mut self,
+    accessible_topology: Topology,
+  ) -> Result<Self, tonic::Status> {
+    validate_topology(&accessible_topology).map_err(|e| {
+      tonic::Status::internal(format!("NodeGetInfoResponse.accessible_topology {}", e))
+    })?;
+
+    self.accessible_topology = Some(accessible_topology);
+    Ok(self)
+  }
+
+  #[inline]
+  pub fn node_id(&self) -> &str {
+    &self.node_id
+  }
+
+  #[inline]
+  pub fn max_volumes_per_node(&self) -> Option<NonZeroU64> {
+    self.max_volumes_per_node
+  }
+
+  #[inline]
+  pub fn accessible_topology(&self) -> Option<&Topology> {
+    self.accessible_topology.as_ref()
+  }
+}
+
+fn validate_node_id(node_id: &str) -> Result<(), tonic::Status> {
+  if node_id.is_empty() {
+    return Err(tonic::Status::internal(
+      "NodeGetInfoResponse.node_id is empty",
+    ));
+  }
+
+  if node_id.len() > NodeGetInfoResponse::MAX_NODE_ID_LEN {
+    return Err(tonic::Status::internal(format!(
+      "NodeGetInfoResponse.node_id exceeds {} bytes",
+      NodeGetInfoResponse::MAX_NODE_ID_LEN
+    )));
+  }
+
+  Ok(())
+}
+
+const MAX_TOPOLOGY_LEN: usize = 63;
+
+/// Check a topology against the rules from the spec, see `Topology` in
+/// `csi.proto`.
+fn validate_topology(topology: &Topology) -> Result<(), String> {
+  #[inline]
+  fn is_valid(s: &str, edge: impl Fn(char) -> bool, inner: impl Fn(char) -> bool) -> bool {
+    let (first, last) = match (s.chars().next(), s.chars().last()) {
+      (Some(first), Some(last)) => (first, last),
+      _ => return false,
+    };
+
+    s.len() <= MAX_TOPOLOGY_LEN && edge(first) && edge(last) && s.chars().all(inner)
+  }
+
+  let is_name = |s: &str| {
+    is_valid(
+      s,
+      |c| c.is_ascii_alphanumeric(),
+      |c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'),
+    )
+  };
+  let is_prefix = |s: &str| {
+    is_valid(
+      s,
+      |c| c.is_ascii_lowercase() || c.is_ascii_digit(),
+      |c| c.is_ascii_lowercase() || c.is_ascii_digit() || matches!(c, '-' | '.'),
+    )
+  };
+
+  let mut prefixes = HashSet::new();
+  let mut unprefixed = false;
+  let mut keys = HashSet::new();
+  for (key, segment) in topology {
+    let (prefix, name) = match key.split_once('/') {
+      Some((prefix, name)) => (Some(prefix), name),
+      None => (None, key.as_str()),
+    };
+
+    if !is_name(name) || !prefix.into_iter().all(is_prefix) {
+      return Err(format!("has an invalid key: {:?}", key));
+    }
+
+    if !is_name(segment) {
+      return Err(format!(
+        "has an invalid segment for {:?}: {:?}",
+        key, segment
+      ));
+    }
+
+    match prefix {
+      Some(prefix) => {
+        prefixes.insert(prefix);
+      }
+      None => unprefixed = true,
+    }
+    if !keys.insert(key.to_lowercase()) {
+      return Err(format!("has duplicate keys: {:?}", key.to_lowercase()));
+    }
+  }
+
+  // If a key prefix is specified, it MUST be identical across all keys.
+  if prefixes.len() > 1 {
+    return Err("has keys with different prefixes".into());
+  }
+
+  if !prefixes.is_empty() && unprefixed {
+    return Err("has keys with and without a prefix".into());
+  }
+
+  Ok(())
+}
+
+impl ValidateResponse for NodeGetInfoResponse {
+  fn validate(&self, advertised: &Advertised) -> Result<(), String> {
+    if self.accessible_topology.is_some() && !advertised.accessibility_constraints {
+      return Err(
+        "accessible_topology is set, but the VOLUME_ACCESSIBILITY_CONSTRAINTS plugin capability is not advertised"
+          .into(),
+      );
+    }
+
+    Ok(())
+  }
+}
+
 impl TryFrom<NodeGetInfoResponse> for proto::NodeGetInfoResponse {
   type Error = tonic::Status;
 
   fn try_from(value: NodeGetInfoResponse) -> Result<Self, Self::Error> {
+    validate_node_id(&value.node_id)?;
+
+    if let Some(topology) = &value.accessible_topology {
+      validate_topology(topology).map_err(|e| {
+        tonic::Status::internal(format!("NodeGetInfoResponse.accessible_topology {}", e))
+      })?;
+    }
+
     let node_id = value.node_id;
-    let max_volumes_per_node = value
-      .max_volumes_per_node
-      .map(|v| v.get() as i64)
-      .unwrap_or_default();
+    let max_volumes_per_node = match value.max_volumes_per_node {
+      None => 0,
+      Some(v) => i64::try_from(v.get()).map_err(|_| {
+        tonic::Status::internal("NodeGetInfoResponse.max_volumes_per_node is out of range")
+      })?,
+    };
     let accessible_topology = value
       .accessible_topology
       .map(|segments| proto::Topology { segments });
@@ -84,3 +258,49 @@ impl From<NodeGetInfoError> for tonic::Status {
     }
   }
 }
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use test_case::test_case;
+
+  #[test_case(&[("zone", "Z1")] => true ; "name only")]
+  #[test_case(&[("example.com/zone", "Z1"), ("example.com/rack", "R_3")] => true ; "with prefix")]
+  #[test_case(&[("Example.com/zone", "Z1")] => false ; "uppercase prefix")]
+  #[test_case(&[("zone", "Z1"), ("example.com/rack", "R3")] => false ; "with and without prefix")]
+  #[test_case(&[("example.com/zone", "Z1"), ("other.com/rack", "R3")] => false ; "different prefixes")]
+  #[test_case(&[("zone", "Z1"), ("example.com/rack", "R3"), ("other.com/row", "W1")] => false ; "different prefixes and no prefix")]
+  #[test_case(&[("zone", "Z1"), ("Zone", "Z2")] => false ; "case-insensitive duplicate")]
+  #[test_case(&[("zone", "")] => false ; "empty segment")]
+  #[test_case(&[("zone", "-Z1")] => false ; "segment starts with dash")]
+  #[test_case(&[("example.com/", "Z1")] => false ; "empty name")]
+  fn topology(segments: &[(&str, &str)]) -> bool {
+    let topology = segments
+      .iter()
+      .map(|(k, v)| (k.to_string(), v.to_string()))
+      .collect();
+
+    validate_topology(&topology).is_ok()
+  }
+
+  #[test_case("node-1" => true ; "valid")]
+  #[test_case("" => false ; "empty")]
+  #[test_case(&"n".repeat(193) => false ; "too long")]
+  fn node_id(node_id: &str) -> bool {
+    NodeGetInfoResponse::new(node_id).is_ok()
+  }
+
+  #[test]
+  fn rejects_invalid_topology() {
+    let topology = [("zone", "Z1"), ("example.com/rack", "R3")]
+      .iter()
+      .map(|(k, v)| (k.to_string(), v.to_string()))
+      .collect();
+
+    let err = NodeGetInfoResponse::new("node-1")
+      .unwrap()
+      .with_accessible_topology(topology)
+      .unwrap_err();
+    assert_eq!(err.code(), tonic::Code::Internal);
+  }
+}
@@ -1,5 +1,9 @@
-use super::{VolumeCondition, VolumeUsage};
+use super::VolumeUsage;
+#[cfg(feature = "alpha")]
+use super::{NodeCapabilities, VolumeCondition};
 use crate::proto;
+#[cfg(feature = "alpha")]
+use crate::response::{Advertised, ValidateResponse};
 use std::{
   convert::{TryFrom, TryInto},
   path::{Path, PathBuf},
@@ -49,6 +53,24 @@ impl NodeGetVolumeStatsRequest {
   }
 }
 
+#[cfg(feature = "test-util")]
+impl NodeGetVolumeStatsRequest {
+  #[inline]
+  pub fn new(volume_id: impl Into<String>, volume_path: impl Into<PathBuf>) -> Self {
+    NodeGetVolumeStatsRequest {
+      volume_id: volume_id.into(),
+      volume_path: volume_path.into(),
+      staging_target_path: None,
+    }
+  }
+
+  #[inline]
+  pub fn with_staging_target_path(mut self, staging_target_path: impl Into<PathBuf>) -> Self {
+    self.staging_target_path = Some(staging_target_path.into());
+    self
+  }
+}
+
 impl TryFrom<proto::NodeGetVolumeStatsRequest> for NodeGetVolumeStatsRequest {
   type Error = tonic::Status;
 
@@ -107,9 +129,57 @@ pub struct NodeGetVolumeStatsResponse {
   /// This field is OPTIONAL.
   /// This field MUST be specified if the VOLUME_CONDITION node
   /// capability is supported.
+  #[cfg(feature = "alpha")]
   volume_condition: Option<VolumeCondition>,
 }
 
+impl NodeGetVolumeStatsResponse {
+  #[inline]
+  pub fn new(usage: Vec<VolumeUsage>) -> Self {
+    NodeGetVolumeStatsResponse {
+      usage,
+      #[cfg(feature = "alpha")]
+      volume_condition: None,
+    }
+  }
+
+  #[cfg(feature = "alpha")]
+  #[inline]
+  pub fn with_volume_condition(mut self, volume_condition: VolumeCondition) -> Self {
+    self.volume_condition = Some(volume_condition);
+    self
+  }
+
+  #[inline]
+  pub fn usage(&self) -> &[VolumeUsage] {
+    &self.usage
+  }
+
+  #[cfg(feature = "alpha")]
+  #[inline]
+  pub fn volume_condition(&self) -> Option<&VolumeCondition> {
+    self.volume_condition.as_ref()
+  }
+}
+
+#[cfg(feature = "alpha")]
+impl ValidateResponse for NodeGetVolumeStatsResponse {
+  fn validate(&self, advertised: &Advertised) -> Result<(), String> {
+    let volume_condition = advertised.node.contains(NodeCapabilities::VOLUME_CONDITION);
+    match (&self.volume_condition, volume_condition) {
+      (Some(_), false) => Err(
+        "volume_condition is set, but the VOLUME_CONDITION node capability is not advertised"
+          .into(),
+      ),
+      (None, true) => Err(
+        "volume_condition is not set, but the VOLUME_CONDITION node capability is advertised"
+          .into(),
+      ),
+      _ => Ok(()),
+    }
+  }
+}
+
 impl TryFrom<NodeGetVolumeStatsResponse> for proto::NodeGetVolumeStatsResponse {
   type Error = tonic::Status;
 
@@ -119,10 +189,12 @@ impl TryFrom<NodeGetVolumeStatsResponse> for proto::NodeGetVolumeStatsResponse {
       .into_iter()
       .map(TryInto::try_into)
       .collect::<Result<_, _>>()?;
+    #[cfg(feature = "alpha")]
     let volume_condition = value.volume_condition.map(TryInto::try_into).transpose()?;
 
     Ok(proto::NodeGetVolumeStatsResponse {
       usage,
+      #[cfg(feature = "alpha")]
       volume_condition,
     })
   }
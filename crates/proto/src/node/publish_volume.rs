@@ -92,8 +92,8 @@ impl NodePublishVolumeRequest {
   /// This field is OPTIONAL. Refer to the `Secrets Requirements`
   /// section on how to use this field.
   #[inline]
-  pub fn secrets(&self) -> &HashMap<String, String> {
-    self.secrets.as_ref()
+  pub fn secrets(&self) -> &Secrets {
+    &self.secrets
   }
 
   /// Volume context as returned by SP in
@@ -106,6 +106,59 @@ impl NodePublishVolumeRequest {
   }
 }
 
+#[cfg(feature = "test-util")]
+impl NodePublishVolumeRequest {
+  /// Unlike when receiving a request, the paths are not checked
+  /// against the filesystem.
+  #[inline]
+  pub fn new(
+    volume_id: impl Into<String>,
+    target_path: impl Into<PathBuf>,
+    volume_capability: VolumeCapability,
+  ) -> Self {
+    NodePublishVolumeRequest {
+      volume_id: volume_id.into(),
+      publish_context: HashMap::new(),
+      staging_target_path: None,
+      target_path: target_path.into(),
+      volume_capability,
+      readonly: false,
+      secrets: Secrets::default(),
+      volume_context: HashMap::new(),
+    }
+  }
+
+  #[inline]
+  pub fn with_publish_context(mut self, publish_context: HashMap<String, String>) -> Self {
+    self.publish_context = publish_context;
+    self
+  }
+
+  #[inline]
+  pub fn with_staging_target_path(mut self, staging_target_path: impl Into<PathBuf>) -> Self {
+    self.staging_target_path = Some(staging_target_path.into());
+    self
+  }
+
+  #[inline]
+  pub fn with_readonly(mut self, readonly: bool) -> Self {
+    self.readonly = readonly;
+    self
+  }
+
+  #[inline]
+  pub fn with_secrets(mut self, secrets: HashMap<String, String>) -> Self {
+    self.secrets = secrets.into();
+    self
+  }
+
+  #[inline]
+  pub fn with_volume_context(mut self, volume_context: HashMap<String, String>) -> Self {
+    self.volume_context = volume_context;
+    self
+  }
+}
+
 impl TryFrom<proto::NodePublishVolumeRequest> for NodePublishVolumeRequest {
   type Error = tonic::Status;
 
@@ -66,8 +66,8 @@ impl NodeStageVolumeRequest {
   /// This field is OPTIONAL. Refer to the `Secrets Requirements`
   /// section on how to use this field.
   #[inline]
-  pub fn secrets(&self) -> &HashMap<String, String> {
-    self.secrets.as_ref()
+  pub fn secrets(&self) -> &Secrets {
+    &self.secrets
   }
 
   /// Volume context as returned by SP in
@@ -80,6 +80,45 @@ impl NodeStageVolumeRequest {
   }
 }
 
+#[cfg(feature = "test-util")]
+impl NodeStageVolumeRequest {
+  /// Unlike when receiving a request, the paths are not checked
+  /// against the filesystem.
+  #[inline]
+  pub fn new(
+    volume_id: impl Into<String>,
+    staging_target_path: impl Into<PathBuf>,
+    volume_capability: VolumeCapability,
+  ) -> Self {
+    NodeStageVolumeRequest {
+      volume_id: volume_id.into(),
+      publish_context: HashMap::new(),
+      staging_target_path: staging_target_path.into(),
+      volume_capability,
+      secrets: Secrets::default(),
+      volume_context: HashMap::new(),
+    }
+  }
+
+  #[inline]
+  pub fn with_publish_context(mut self, publish_context: HashMap<String, String>) -> Self {
+    self.publish_context = publish_context;
+    self
+  }
+
+  #[inline]
+  pub fn with_secrets(mut self, secrets: HashMap<String, String>) -> Self {
+    self.secrets = secrets.into();
+    self
+  }
+
+  #[inline]
+  pub fn with_volume_context(mut self, volume_context: HashMap<String, String>) -> Self {
+    self.volume_context = volume_context;
+    self
+  }
+}
+
 impl TryFrom<proto::NodeStageVolumeRequest> for NodeStageVolumeRequest {
   type Error = tonic::Status;
 
@@ -32,6 +32,19 @@ impl NodeUnpublishVolumeRequest {
   }
 }
 
+#[cfg(feature = "test-util")]
+impl NodeUnpublishVolumeRequest {
+  /// Unlike when receiving a request, the target path is not checked
+  /// against the filesystem.
+  #[inline]
+  pub fn new(volume_id: impl Into<String>, target_path: impl Into<PathBuf>) -> Self {
+    NodeUnpublishVolumeRequest {
+      volume_id: volume_id.into(),
+      target_path: target_path.into(),
+    }
+  }
+}
+
 impl TryFrom<proto::NodeUnpublishVolumeRequest> for NodeUnpublishVolumeRequest {
   type Error = tonic::Status;
 
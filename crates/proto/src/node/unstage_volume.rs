@@ -32,6 +32,17 @@ impl NodeUnstageVolumeRequest {
   }
 }
 
+#[cfg(feature = "test-util")]
+impl NodeUnstageVolumeRequest {
+  #[inline]
+  pub fn new(volume_id: impl Into<String>, staging_target_path: impl Into<PathBuf>) -> Self {
+    NodeUnstageVolumeRequest {
+      volume_id: volume_id.into(),
+      staging_target_path: staging_target_path.into(),
+    }
+  }
+}
+
 impl TryFrom<proto::NodeUnstageVolumeRequest> for NodeUnstageVolumeRequest {
   type Error = tonic::Status;
 
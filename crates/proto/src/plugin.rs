@@ -1,9 +1,72 @@
-use crate::{proto, IdentityService, VolumeExpansionSupport};
-use tracing::debug;
+use crate::{proto, server::Dispatch, IdentityService, VolumeExpansionSupport};
+use async_trait::async_trait;
+use std::sync::Arc;
+use tracing::{debug, instrument};
 
-pub(crate) fn get_capabilities(s: &impl IdentityService) -> proto::GetPluginCapabilitiesResponse {
+/// The services served next to the `Identity` service. They decide which
+/// service plugin capabilities are advertised.
+#[derive(Debug, Default, Clone, Copy)]
+pub(crate) struct Services {
+  pub(crate) controller: bool,
+  #[cfg(feature = "alpha")]
+  pub(crate) group_controller: bool,
+  #[cfg(feature = "alpha")]
+  pub(crate) snapshot_metadata: bool,
+}
+
+/// Fails with `UNIMPLEMENTED` if the plugin does not support volume
+/// expansion, so expansion calls only reach plugins that advertise it.
+pub(crate) fn require_volume_expansion(
+  s: &impl IdentityService,
+  method: &str,
+) -> Result<(), tonic::Status> {
+  match s.volume_expansion_support() {
+    VolumeExpansionSupport::None => Err(tonic::Status::unimplemented(format!(
+      "{} is not supported, the plugin does not support volume expansion",
+      method
+    ))),
+    _ => Ok(()),
+  }
+}
+
+pub(crate) fn get_capabilities(
+  s: &impl IdentityService,
+  services: Services,
+) -> proto::GetPluginCapabilitiesResponse {
   let mut response = proto::GetPluginCapabilitiesResponse::default();
 
+  if services.controller {
+    response.capabilities.push(proto::PluginCapability {
+      r#type: Some(proto::plugin_capability::Type::Service(
+        proto::plugin_capability::Service {
+          r#type: proto::plugin_capability::service::Type::ControllerService.into(),
+        },
+      )),
+    });
+  }
+
+  #[cfg(feature = "alpha")]
+  if services.group_controller && s.enable_alpha_features() {
+    response.capabilities.push(proto::PluginCapability {
+      r#type: Some(proto::plugin_capability::Type::Service(
+        proto::plugin_capability::Service {
+          r#type: proto::plugin_capability::service::Type::GroupControllerService.into(),
+        },
+      )),
+    });
+  }
+
+  #[cfg(feature = "alpha")]
+  if services.snapshot_metadata && s.enable_alpha_features() {
+    response.capabilities.push(proto::PluginCapability {
+      r#type: Some(proto::plugin_capability::Type::Service(
+        proto::plugin_capability::Service {
+          r#type: proto::plugin_capability::service::Type::SnapshotMetadataService.into(),
+        },
+      )),
+    });
+  }
+
   let volume_accessibility_constraints_support = s.volume_accessibility_constraints_support();
   if volume_accessibility_constraints_support {
     response.capabilities.push(proto::PluginCapability {
@@ -39,8 +102,173 @@ pub(crate) fn get_capabilities(s: &impl IdentityService) -> proto::GetPluginCapa
   }
 
   debug!(
+    ?services,
     ?volume_accessibility_constraints_support,
     ?volume_expansion_support
   );
   response
 }
+
+/// The `Identity` service. There is exactly one of these per server,
+/// regardless of whether it serves the controller, the node, or both.
+pub(crate) struct Identity<T: IdentityService> {
+  plugin: Arc<T>,
+  dispatch: Arc<Dispatch>,
+  services: Services,
+}
+
+impl<T: IdentityService> Identity<T> {
+  /// Create the identity service, advertising the service plugin
+  /// capabilities of `services`.
+  #[inline]
+  pub(crate) fn new(plugin: Arc<T>, dispatch: Arc<Dispatch>, services: Services) -> Self {
+    Identity {
+      plugin,
+      dispatch,
+      services,
+    }
+  }
+}
+
+#[async_trait]
+impl<T: IdentityService> proto::identity_server::Identity for Identity<T> {
+  #[instrument(
+    name = "identity.get_plugin_info",
+    skip(self, request),
+    fields(request, response, code, error, latency_ms)
+  )]
+  async fn get_plugin_info(
+    &self,
+    request: tonic::Request<proto::GetPluginInfoRequest>,
+  ) -> Result<tonic::Response<proto::GetPluginInfoResponse>, tonic::Status> {
+    self
+      .dispatch
+      .trace("GetPluginInfo", request, |_| async move {
+        Ok(proto::GetPluginInfoResponse {
+          name: self.plugin.name().into(),
+          vendor_version: self.plugin.version().into(),
+          manifest: self.plugin.manifest().clone(),
+        })
+      })
+      .await
+  }
+
+  #[instrument(
+    name = "identity.get_plugin_capabilities",
+    skip(self, request),
+    fields(request, response, code, error, latency_ms)
+  )]
+  async fn get_plugin_capabilities(
+    &self,
+    request: tonic::Request<proto::GetPluginCapabilitiesRequest>,
+  ) -> Result<tonic::Response<proto::GetPluginCapabilitiesResponse>, tonic::Status> {
+    self
+      .dispatch
+      .trace("GetPluginCapabilities", request, |_| async move {
+        Ok(get_capabilities(&*self.plugin, self.services))
+      })
+      .await
+  }
+
+  #[instrument(
+    name = "identity.probe",
+    skip(self, request),
+    fields(request, response, code, error, latency_ms)
+  )]
+  async fn probe(
+    &self,
+    request: tonic::Request<proto::ProbeRequest>,
+  ) -> Result<tonic::Response<proto::ProbeResponse>, tonic::Status> {
+    self
+      .dispatch
+      .trace("Probe", request, |_| async move {
+        Ok(proto::ProbeResponse {
+          ready: Some(self.plugin.ready()),
+        })
+      })
+      .await
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use proto::plugin_capability::{service, Type};
+  use test_case::test_case;
+
+  #[derive(Default)]
+  struct Plugin {
+    #[cfg(feature = "alpha")]
+    enable_alpha_features: bool,
+  }
+
+  impl IdentityService for Plugin {
+    fn name(&self) -> &str {
+      "csi.example.com"
+    }
+
+    fn version(&self) -> &str {
+      "0.1.0"
+    }
+
+    fn volume_accessibility_constraints_support(&self) -> bool {
+      true
+    }
+
+    fn volume_expansion_support(&self) -> VolumeExpansionSupport {
+      VolumeExpansionSupport::Online
+    }
+
+    #[cfg(feature = "alpha")]
+    fn enable_alpha_features(&self) -> bool {
+      self.enable_alpha_features
+    }
+  }
+
+  fn service_types(response: &proto::GetPluginCapabilitiesResponse) -> Vec<i32> {
+    response
+      .capabilities
+      .iter()
+      .filter_map(|c| match &c.r#type {
+        Some(Type::Service(s)) => Some(s.r#type),
+        _ => None,
+      })
+      .collect()
+  }
+
+  #[test_case(true => vec![service::Type::ControllerService as i32, service::Type::VolumeAccessibilityConstraints as i32] ; "with controller")]
+  #[test_case(false => vec![service::Type::VolumeAccessibilityConstraints as i32] ; "without controller")]
+  fn capabilities(controller: bool) -> Vec<i32> {
+    let services = Services {
+      controller,
+      #[cfg(feature = "alpha")]
+      group_controller: false,
+      #[cfg(feature = "alpha")]
+      snapshot_metadata: false,
+    };
+    let response = get_capabilities(&Plugin::default(), services);
+    let services = service_types(&response);
+
+    assert_eq!(response.capabilities.len(), services.len() + 1);
+    services
+  }
+
+  #[cfg(feature = "alpha")]
+  #[test_case(true => vec![service::Type::ControllerService as i32, service::Type::GroupControllerService as i32, service::Type::SnapshotMetadataService as i32, service::Type::VolumeAccessibilityConstraints as i32] ; "alpha enabled")]
+  #[test_case(false => vec![service::Type::ControllerService as i32, service::Type::VolumeAccessibilityConstraints as i32] ; "alpha disabled")]
+  fn alpha_capabilities(enable_alpha_features: bool) -> Vec<i32> {
+    let plugin = Plugin {
+      enable_alpha_features,
+    };
+    let response = get_capabilities(
+      &plugin,
+      Services {
+        controller: true,
+        group_controller: true,
+        snapshot_metadata: true,
+      },
+    );
+
+    service_types(&response)
+  }
+}
@@ -65,6 +65,23 @@ pub mod plugin_capability {
       /// returned by NodeGetInfo to ensure that a given volume is
       /// accessible from a given node when scheduling workloads.
       VolumeAccessibilityConstraints = 2,
+      /// GROUP_CONTROLLER_SERVICE indicates that the Plugin provides
+      /// RPCs for operating on groups of volumes. Plugins MAY provide
+      /// this capability.
+      /// The presence of this capability determines whether the CO will
+      /// attempt to invoke the REQUIRED GroupController service RPCs, as
+      /// well as specific RPCs as indicated by
+      /// GroupControllerGetCapabilities.
+      #[cfg(feature = "alpha")]
+      GroupControllerService = 3,
+      /// SNAPSHOT_METADATA_SERVICE indicates that the Plugin provides
+      /// RPCs to retrieve metadata on the allocated blocks of a single
+      /// snapshot, or the changed blocks between a pair of snapshots of
+      /// the same block volume.
+      /// The presence of this capability determines whether the CO will
+      /// attempt to invoke the OPTIONAL SnapshotMetadata service RPCs.
+      #[cfg(feature = "alpha")]
+      SnapshotMetadataService = 4,
     }
   }
   #[derive(Clone, PartialEq, ::prost::Message)]
@@ -154,7 +171,7 @@ pub struct ProbeResponse {
   #[prost(message, optional, tag = "1")]
   pub ready: ::core::option::Option<bool>,
 }
-#[derive(Clone, PartialEq, ::prost::Message)]
+#[derive(Clone, PartialEq, Default)]
 pub struct CreateVolumeRequest {
   /// The suggested name for the storage space. This field is REQUIRED.
   /// It serves two purposes:
@@ -182,7 +199,6 @@ pub struct CreateVolumeRequest {
   /// except those containing the following banned characters:
   /// U+0000-U+0008, U+000B, U+000C, U+000E-U+001F, U+007F-U+009F.
   /// (These are control characters other than commonly used whitespace.)
-  #[prost(string, tag = "1")]
   pub name: ::prost::alloc::string::String,
   /// This field is OPTIONAL. This allows the CO to specify the capacity
   /// requirement of the volume to be provisioned. If not specified, the
@@ -190,7 +206,6 @@ pub struct CreateVolumeRequest {
   /// specified it MUST always be honored, even when creating volumes
   /// from a source; which MAY force some backends to internally extend
   /// the volume after creating it.
-  #[prost(message, optional, tag = "2")]
   pub capacity_range: ::core::option::Option<CapacityRange>,
   /// The capabilities that the provisioned volume MUST have. SP MUST
   /// provision a volume that will satisfy ALL of the capabilities
@@ -206,23 +221,19 @@ pub struct CreateVolumeRequest {
   /// specified volume capabilities are not supported by the SP, the call
   /// MUST return the appropriate gRPC error code.
   /// This field is REQUIRED.
-  #[prost(message, repeated, tag = "3")]
   pub volume_capabilities: ::prost::alloc::vec::Vec<VolumeCapability>,
   /// Plugin specific parameters passed in as opaque key-value pairs.
   /// This field is OPTIONAL. The Plugin is responsible for parsing and
   /// validating these parameters. COs will treat these as opaque.
-  #[prost(map = "string, string", tag = "4")]
   pub parameters:
     ::std::collections::HashMap<::prost::alloc::string::String, ::prost::alloc::string::String>,
   /// Secrets required by plugin to complete volume creation request.
   /// This field is OPTIONAL. Refer to the `Secrets Requirements`
   /// section on how to use this field.
-  #[prost(map = "string, string", tag = "5")]
   pub secrets:
     ::std::collections::HashMap<::prost::alloc::string::String, ::prost::alloc::string::String>,
   /// If specified, the new volume will be pre-populated with data from
   /// this source. This field is OPTIONAL.
-  #[prost(message, optional, tag = "6")]
   pub volume_content_source: ::core::option::Option<VolumeContentSource>,
   /// Specifies where (regions, zones, racks, etc.) the provisioned
   /// volume MUST be accessible from.
@@ -235,7 +246,6 @@ pub struct CreateVolumeRequest {
   /// If this field is not specified and the SP has the
   /// VOLUME_ACCESSIBILITY_CONSTRAINTS plugin capability, the SP MAY
   /// choose where the provisioned volume is accessible from.
-  #[prost(message, optional, tag = "7")]
   pub accessibility_requirements: ::core::option::Option<TopologyRequirement>,
 }
 /// Specifies what source the volume will be created from. One of the
@@ -312,6 +322,19 @@ pub mod volume_capability {
     /// SHALL NOT exceed 4 KiB.
     #[prost(string, repeated, tag = "2")]
     pub mount_flags: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    /// If SP has VOLUME_MOUNT_GROUP node capability and CO provides
+    /// this field then SP MUST ensure that the volume_mount_group
+    /// parameter is passed as the group identifier to the underlying
+    /// operating system mount system call, with the understanding
+    /// that the set of available mount call parameters and/or
+    /// mount implementations may vary across operating systems.
+    /// Additionally, new file and/or directory entries written to
+    /// the underlying filesystem SHOULD be permission-labeled in such a
+    /// manner, unless otherwise modified by a workload, that they are
+    /// both readable and writable by said mount group identifier.
+    /// This is an OPTIONAL field.
+    #[prost(string, tag = "3")]
+    pub volume_mount_group: ::prost::alloc::string::String,
   }
   /// Specify how a volume can be accessed.
   #[derive(Clone, PartialEq, ::prost::Message)]
@@ -340,6 +363,16 @@ pub mod volume_capability {
       /// Can be published as read/write at multiple nodes
       /// simultaneously.
       MultiNodeMultiWriter = 5,
+      /// Can only be published once as read/write at a single workload
+      /// on a single node, at any given time. SHOULD be used instead of
+      /// SINGLE_NODE_WRITER for COs using the experimental
+      /// SINGLE_NODE_MULTI_WRITER capability.
+      SingleNodeSingleWriter = 6,
+      /// Can be published as read/write at multiple workloads on a
+      /// single node simultaneously. SHOULD be used instead of
+      /// SINGLE_NODE_WRITER for COs using the experimental
+      /// SINGLE_NODE_MULTI_WRITER capability.
+      SingleNodeMultiWriter = 7,
     }
   }
   /// Specifies what API the volume will be accessed using. One of the
@@ -605,55 +638,47 @@ pub struct Topology {
   pub segments:
     ::std::collections::HashMap<::prost::alloc::string::String, ::prost::alloc::string::String>,
 }
-#[derive(Clone, PartialEq, ::prost::Message)]
+#[derive(Clone, PartialEq, Default)]
 pub struct DeleteVolumeRequest {
   /// The ID of the volume to be deprovisioned.
   /// This field is REQUIRED.
-  #[prost(string, tag = "1")]
   pub volume_id: ::prost::alloc::string::String,
   /// Secrets required by plugin to complete volume deletion request.
   /// This field is OPTIONAL. Refer to the `Secrets Requirements`
   /// section on how to use this field.
-  #[prost(map = "string, string", tag = "2")]
   pub secrets:
     ::std::collections::HashMap<::prost::alloc::string::String, ::prost::alloc::string::String>,
 }
 /// Intentionally empty.
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct DeleteVolumeResponse {}
-#[derive(Clone, PartialEq, ::prost::Message)]
+#[derive(Clone, PartialEq, Default)]
 pub struct ControllerPublishVolumeRequest {
   /// The ID of the volume to be used on a node.
   /// This field is REQUIRED.
-  #[prost(string, tag = "1")]
   pub volume_id: ::prost::alloc::string::String,
   /// The ID of the node. This field is REQUIRED. The CO SHALL set this
   /// field to match the node ID returned by `NodeGetInfo`.
-  #[prost(string, tag = "2")]
   pub node_id: ::prost::alloc::string::String,
   /// Volume capability describing how the CO intends to use this volume.
   /// SP MUST ensure the CO can use the published volume as described.
   /// Otherwise SP MUST return the appropriate gRPC error code.
   /// This is a REQUIRED field.
-  #[prost(message, optional, tag = "3")]
   pub volume_capability: ::core::option::Option<VolumeCapability>,
   /// Indicates SP MUST publish the volume in readonly mode.
   /// CO MUST set this field to false if SP does not have the
   /// PUBLISH_READONLY controller capability.
   /// This is a REQUIRED field.
-  #[prost(bool, tag = "4")]
   pub readonly: bool,
   /// Secrets required by plugin to complete controller publish volume
   /// request. This field is OPTIONAL. Refer to the
   /// `Secrets Requirements` section on how to use this field.
-  #[prost(map = "string, string", tag = "5")]
   pub secrets:
     ::std::collections::HashMap<::prost::alloc::string::String, ::prost::alloc::string::String>,
   /// Volume context as returned by SP in
   /// CreateVolumeResponse.Volume.volume_context.
   /// This field is OPTIONAL and MUST match the volume_context of the
   /// volume identified by `volume_id`.
-  #[prost(map = "string, string", tag = "6")]
   pub volume_context:
     ::std::collections::HashMap<::prost::alloc::string::String, ::prost::alloc::string::String>,
 }
@@ -676,56 +701,48 @@ pub struct ControllerPublishVolumeResponse {
   pub publish_context:
     ::std::collections::HashMap<::prost::alloc::string::String, ::prost::alloc::string::String>,
 }
-#[derive(Clone, PartialEq, ::prost::Message)]
+#[derive(Clone, PartialEq, Default)]
 pub struct ControllerUnpublishVolumeRequest {
   /// The ID of the volume. This field is REQUIRED.
-  #[prost(string, tag = "1")]
   pub volume_id: ::prost::alloc::string::String,
   /// The ID of the node. This field is OPTIONAL. The CO SHOULD set this
   /// field to match the node ID returned by `NodeGetInfo` or leave it
   /// unset. If the value is set, the SP MUST unpublish the volume from
   /// the specified node. If the value is unset, the SP MUST unpublish
   /// the volume from all nodes it is published to.
-  #[prost(string, tag = "2")]
   pub node_id: ::prost::alloc::string::String,
   /// Secrets required by plugin to complete controller unpublish volume
   /// request. This SHOULD be the same secrets passed to the
   /// ControllerPublishVolume call for the specified volume.
   /// This field is OPTIONAL. Refer to the `Secrets Requirements`
   /// section on how to use this field.
-  #[prost(map = "string, string", tag = "3")]
   pub secrets:
     ::std::collections::HashMap<::prost::alloc::string::String, ::prost::alloc::string::String>,
 }
 /// Intentionally empty.
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ControllerUnpublishVolumeResponse {}
-#[derive(Clone, PartialEq, ::prost::Message)]
+#[derive(Clone, PartialEq, Default)]
 pub struct ValidateVolumeCapabilitiesRequest {
   /// The ID of the volume to check. This field is REQUIRED.
-  #[prost(string, tag = "1")]
   pub volume_id: ::prost::alloc::string::String,
   /// Volume context as returned by SP in
   /// CreateVolumeResponse.Volume.volume_context.
   /// This field is OPTIONAL and MUST match the volume_context of the
   /// volume identified by `volume_id`.
-  #[prost(map = "string, string", tag = "2")]
   pub volume_context:
     ::std::collections::HashMap<::prost::alloc::string::String, ::prost::alloc::string::String>,
   /// The capabilities that the CO wants to check for the volume. This
   /// call SHALL return "confirmed" only if all the volume capabilities
   /// specified below are supported. This field is REQUIRED.
-  #[prost(message, repeated, tag = "3")]
   pub volume_capabilities: ::prost::alloc::vec::Vec<VolumeCapability>,
   /// See CreateVolumeRequest.parameters.
   /// This field is OPTIONAL.
-  #[prost(map = "string, string", tag = "4")]
   pub parameters:
     ::std::collections::HashMap<::prost::alloc::string::String, ::prost::alloc::string::String>,
   /// Secrets required by plugin to complete volume validation request.
   /// This field is OPTIONAL. Refer to the `Secrets Requirements`
   /// section on how to use this field.
-  #[prost(map = "string, string", tag = "5")]
   pub secrets:
     ::std::collections::HashMap<::prost::alloc::string::String, ::prost::alloc::string::String>,
 }
@@ -820,6 +837,7 @@ pub mod list_volumes_response {
     /// This field MUST be specified if the
     /// VOLUME_CONDITION controller capability is supported.
     #[prost(message, optional, tag = "2")]
+    #[cfg(feature = "alpha")]
     pub volume_condition: ::core::option::Option<super::VolumeCondition>,
   }
   #[derive(Clone, PartialEq, ::prost::Message)]
@@ -834,6 +852,7 @@ pub mod list_volumes_response {
     pub status: ::core::option::Option<VolumeStatus>,
   }
 }
+#[cfg(feature = "alpha")]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ControllerGetVolumeRequest {
   /// The ID of the volume to fetch current volume information for.
@@ -841,6 +860,7 @@ pub struct ControllerGetVolumeRequest {
   #[prost(string, tag = "1")]
   pub volume_id: ::prost::alloc::string::String,
 }
+#[cfg(feature = "alpha")]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ControllerGetVolumeResponse {
   /// This field is REQUIRED
@@ -852,6 +872,7 @@ pub struct ControllerGetVolumeResponse {
 }
 /// Nested message and enum types in `ControllerGetVolumeResponse`.
 pub mod controller_get_volume_response {
+  #[cfg(feature = "alpha")]
   #[derive(Clone, PartialEq, ::prost::Message)]
   pub struct VolumeStatus {
     /// A list of all the `node_id` of nodes that this volume is
@@ -970,11 +991,22 @@ pub mod controller_service_capability {
       /// is the truth.
       /// Note that, for alpha, `VolumeCondition` is intended be
       /// informative for humans only, not for automation.
+      #[cfg(feature = "alpha")]
       VolumeCondition = 11,
       /// Indicates the SP supports the ControllerGetVolume RPC.
       /// This enables COs to, for example, fetch per volume
       /// condition after a volume is provisioned.
+      #[cfg(feature = "alpha")]
       GetVolume = 12,
+      /// Indicates the SP supports the SINGLE_NODE_SINGLE_WRITER and/or
+      /// SINGLE_NODE_MULTI_WRITER access modes.
+      /// These access modes are intended to replace the
+      /// SINGLE_NODE_WRITER access mode to clarify the number of writers
+      /// for a volume on a single node. Plugins MUST accept and allow
+      /// use of the SINGLE_NODE_WRITER access mode when either
+      /// SINGLE_NODE_SINGLE_WRITER and/or SINGLE_NODE_MULTI_WRITER are
+      /// supported, in order to permit older COs to continue working.
+      SingleNodeMultiWriter = 13,
     }
   }
   #[derive(Clone, PartialEq, ::prost::Oneof)]
@@ -984,11 +1016,10 @@ pub mod controller_service_capability {
     Rpc(Rpc),
   }
 }
-#[derive(Clone, PartialEq, ::prost::Message)]
+#[derive(Clone, PartialEq, Default)]
 pub struct CreateSnapshotRequest {
   /// The ID of the source volume to be snapshotted.
   /// This field is REQUIRED.
-  #[prost(string, tag = "1")]
   pub source_volume_id: ::prost::alloc::string::String,
   /// The suggested name for the snapshot. This field is REQUIRED for
   /// idempotency.
@@ -996,12 +1027,10 @@ pub struct CreateSnapshotRequest {
   /// except those containing the following banned characters:
   /// U+0000-U+0008, U+000B, U+000C, U+000E-U+001F, U+007F-U+009F.
   /// (These are control characters other than commonly used whitespace.)
-  #[prost(string, tag = "2")]
   pub name: ::prost::alloc::string::String,
   /// Secrets required by plugin to complete snapshot creation request.
   /// This field is OPTIONAL. Refer to the `Secrets Requirements`
   /// section on how to use this field.
-  #[prost(map = "string, string", tag = "3")]
   pub secrets:
     ::std::collections::HashMap<::prost::alloc::string::String, ::prost::alloc::string::String>,
   /// Plugin specific parameters passed in as opaque key-value pairs.
@@ -1014,7 +1043,6 @@ pub struct CreateSnapshotRequest {
   /// - Specify if the snapshot should be replicated to some place.
   /// - Specify primary or secondary for replication systems that
   ///   support snapshotting only on primary.
-  #[prost(map = "string, string", tag = "4")]
   pub parameters:
     ::std::collections::HashMap<::prost::alloc::string::String, ::prost::alloc::string::String>,
 }
@@ -1063,17 +1091,31 @@ pub struct Snapshot {
   /// value is false. This field is REQUIRED.
   #[prost(bool, tag = "5")]
   pub ready_to_use: bool,
+  /// The ID of the volume group snapshot that this snapshot is part of.
+  /// It uniquely identifies the group snapshot on the storage system.
+  /// This field is OPTIONAL.
+  /// If this snapshot is a member of a volume group snapshot, and it
+  /// MUST NOT be deleted as a stand alone snapshot, then the SP
+  /// MUST provide the ID of the volume group snapshot in this field.
+  /// If provided, CO MUST use this field in subsequent volume group
+  /// snapshot operations to indicate that this snapshot is part of the
+  /// specified group snapshot.
+  /// If not provided, CO SHALL treat the snapshot as independent,
+  /// and SP SHALL allow it to be deleted separately.
+  /// If this message is inside a VolumeGroupSnapshot message, the value
+  /// MUST be the same as the group_snapshot_id in that message.
+  #[prost(string, tag = "6")]
+  #[cfg(feature = "alpha")]
+  pub group_snapshot_id: ::prost::alloc::string::String,
 }
-#[derive(Clone, PartialEq, ::prost::Message)]
+#[derive(Clone, PartialEq, Default)]
 pub struct DeleteSnapshotRequest {
   /// The ID of the snapshot to be deleted.
   /// This field is REQUIRED.
-  #[prost(string, tag = "1")]
   pub snapshot_id: ::prost::alloc::string::String,
   /// Secrets required by plugin to complete snapshot deletion request.
   /// This field is OPTIONAL. Refer to the `Secrets Requirements`
   /// section on how to use this field.
-  #[prost(map = "string, string", tag = "2")]
   pub secrets:
     ::std::collections::HashMap<::prost::alloc::string::String, ::prost::alloc::string::String>,
 }
@@ -1081,7 +1123,7 @@ pub struct DeleteSnapshotRequest {
 pub struct DeleteSnapshotResponse {}
 /// List all snapshots on the storage system regardless of how they were
 /// created.
-#[derive(Clone, PartialEq, ::prost::Message)]
+#[derive(Clone, PartialEq, Default)]
 pub struct ListSnapshotsRequest {
   /// If specified (non-zero value), the Plugin MUST NOT return more
   /// entries than this number in the response. If the actual number of
@@ -1091,29 +1133,24 @@ pub struct ListSnapshotsRequest {
   /// not specified (zero value), it means there is no restriction on the
   /// number of entries that can be returned.
   /// The value of this field MUST NOT be negative.
-  #[prost(int32, tag = "1")]
   pub max_entries: i32,
   /// A token to specify where to start paginating. Set this field to
   /// `next_token` returned by a previous `ListSnapshots` call to get the
   /// next page of entries. This field is OPTIONAL.
   /// An empty string is equal to an unspecified field value.
-  #[prost(string, tag = "2")]
   pub starting_token: ::prost::alloc::string::String,
   /// Identity information for the source volume. This field is OPTIONAL.
   /// It can be used to list snapshots by volume.
-  #[prost(string, tag = "3")]
   pub source_volume_id: ::prost::alloc::string::String,
   /// Identity information for a specific snapshot. This field is
   /// OPTIONAL. It can be used to list only a specific snapshot.
   /// ListSnapshots will return with current snapshot information
   /// and will not block if the snapshot is being processed after
   /// it is cut.
-  #[prost(string, tag = "4")]
   pub snapshot_id: ::prost::alloc::string::String,
   /// Secrets required by plugin to complete ListSnapshot request.
   /// This field is OPTIONAL. Refer to the `Secrets Requirements`
   /// section on how to use this field.
-  #[prost(map = "string, string", tag = "5")]
   pub secrets:
     ::std::collections::HashMap<::prost::alloc::string::String, ::prost::alloc::string::String>,
 }
@@ -1138,18 +1175,15 @@ pub mod list_snapshots_response {
     pub snapshot: ::core::option::Option<super::Snapshot>,
   }
 }
-#[derive(Clone, PartialEq, ::prost::Message)]
+#[derive(Clone, PartialEq, Default)]
 pub struct ControllerExpandVolumeRequest {
   /// The ID of the volume to expand. This field is REQUIRED.
-  #[prost(string, tag = "1")]
   pub volume_id: ::prost::alloc::string::String,
   /// This allows CO to specify the capacity requirements of the volume
   /// after expansion. This field is REQUIRED.
-  #[prost(message, optional, tag = "2")]
   pub capacity_range: ::core::option::Option<CapacityRange>,
   /// Secrets required by the plugin for expanding the volume.
   /// This field is OPTIONAL.
-  #[prost(map = "string, string", tag = "3")]
   pub secrets:
     ::std::collections::HashMap<::prost::alloc::string::String, ::prost::alloc::string::String>,
   /// Volume capability describing how the CO intends to use this volume.
@@ -1159,7 +1193,6 @@ pub struct ControllerExpandVolumeRequest {
   /// node_expansion_required to false in ControllerExpandVolumeResponse
   /// to skip invocation of NodeExpandVolume on the node by the CO.
   /// This is an OPTIONAL field.
-  #[prost(message, optional, tag = "4")]
   pub volume_capability: ::core::option::Option<VolumeCapability>,
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -1173,17 +1206,15 @@ pub struct ControllerExpandVolumeResponse {
   #[prost(bool, tag = "2")]
   pub node_expansion_required: bool,
 }
-#[derive(Clone, PartialEq, ::prost::Message)]
+#[derive(Clone, PartialEq, Default)]
 pub struct NodeStageVolumeRequest {
   /// The ID of the volume to publish. This field is REQUIRED.
-  #[prost(string, tag = "1")]
   pub volume_id: ::prost::alloc::string::String,
   /// The CO SHALL set this field to the value returned by
   /// `ControllerPublishVolume` if the corresponding Controller Plugin
   /// has `PUBLISH_UNPUBLISH_VOLUME` controller capability, and SHALL be
   /// left unset if the corresponding Controller Plugin does not have
   /// this capability. This is an OPTIONAL field.
-  #[prost(map = "string, string", tag = "2")]
   pub publish_context:
     ::std::collections::HashMap<::prost::alloc::string::String, ::prost::alloc::string::String>,
   /// The path to which the volume MAY be staged. It MUST be an
@@ -1199,25 +1230,21 @@ pub struct NodeStageVolumeRequest {
   /// SP SHOULD support the maximum path length allowed by the operating
   /// system/filesystem, but, at a minimum, SP MUST accept a max path
   /// length of at least 128 bytes.
-  #[prost(string, tag = "3")]
   pub staging_target_path: ::prost::alloc::string::String,
   /// Volume capability describing how the CO intends to use this volume.
   /// SP MUST ensure the CO can use the staged volume as described.
   /// Otherwise SP MUST return the appropriate gRPC error code.
   /// This is a REQUIRED field.
-  #[prost(message, optional, tag = "4")]
   pub volume_capability: ::core::option::Option<VolumeCapability>,
   /// Secrets required by plugin to complete node stage volume request.
   /// This field is OPTIONAL. Refer to the `Secrets Requirements`
   /// section on how to use this field.
-  #[prost(map = "string, string", tag = "5")]
   pub secrets:
     ::std::collections::HashMap<::prost::alloc::string::String, ::prost::alloc::string::String>,
   /// Volume context as returned by SP in
   /// CreateVolumeResponse.Volume.volume_context.
   /// This field is OPTIONAL and MUST match the volume_context of the
   /// volume identified by `volume_id`.
-  #[prost(map = "string, string", tag = "6")]
   pub volume_context:
     ::std::collections::HashMap<::prost::alloc::string::String, ::prost::alloc::string::String>,
 }
@@ -1242,17 +1269,15 @@ pub struct NodeUnstageVolumeRequest {
 /// Intentionally empty.
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct NodeUnstageVolumeResponse {}
-#[derive(Clone, PartialEq, ::prost::Message)]
+#[derive(Clone, PartialEq, Default)]
 pub struct NodePublishVolumeRequest {
   /// The ID of the volume to publish. This field is REQUIRED.
-  #[prost(string, tag = "1")]
   pub volume_id: ::prost::alloc::string::String,
   /// The CO SHALL set this field to the value returned by
   /// `ControllerPublishVolume` if the corresponding Controller Plugin
   /// has `PUBLISH_UNPUBLISH_VOLUME` controller capability, and SHALL be
   /// left unset if the corresponding Controller Plugin does not have
   /// this capability. This is an OPTIONAL field.
-  #[prost(map = "string, string", tag = "2")]
   pub publish_context:
     ::std::collections::HashMap<::prost::alloc::string::String, ::prost::alloc::string::String>,
   /// The path to which the volume was staged by `NodeStageVolume`.
@@ -1265,7 +1290,6 @@ pub struct NodePublishVolumeRequest {
   /// SP SHOULD support the maximum path length allowed by the operating
   /// system/filesystem, but, at a minimum, SP MUST accept a max path
   /// length of at least 128 bytes.
-  #[prost(string, tag = "3")]
   pub staging_target_path: ::prost::alloc::string::String,
   /// The path to which the volume will be published. It MUST be an
   /// absolute path in the root filesystem of the process serving this
@@ -1283,29 +1307,24 @@ pub struct NodePublishVolumeRequest {
   /// SP SHOULD support the maximum path length allowed by the operating
   /// system/filesystem, but, at a minimum, SP MUST accept a max path
   /// length of at least 128 bytes.
-  #[prost(string, tag = "4")]
   pub target_path: ::prost::alloc::string::String,
   /// Volume capability describing how the CO intends to use this volume.
   /// SP MUST ensure the CO can use the published volume as described.
   /// Otherwise SP MUST return the appropriate gRPC error code.
   /// This is a REQUIRED field.
-  #[prost(message, optional, tag = "5")]
   pub volume_capability: ::core::option::Option<VolumeCapability>,
   /// Indicates SP MUST publish the volume in readonly mode.
   /// This field is REQUIRED.
-  #[prost(bool, tag = "6")]
   pub readonly: bool,
   /// Secrets required by plugin to complete node publish volume request.
   /// This field is OPTIONAL. Refer to the `Secrets Requirements`
   /// section on how to use this field.
-  #[prost(map = "string, string", tag = "7")]
   pub secrets:
     ::std::collections::HashMap<::prost::alloc::string::String, ::prost::alloc::string::String>,
   /// Volume context as returned by SP in
   /// CreateVolumeResponse.Volume.volume_context.
   /// This field is OPTIONAL and MUST match the volume_context of the
   /// volume identified by `volume_id`.
-  #[prost(map = "string, string", tag = "8")]
   pub volume_context:
     ::std::collections::HashMap<::prost::alloc::string::String, ::prost::alloc::string::String>,
 }
@@ -1369,6 +1388,7 @@ pub struct NodeGetVolumeStatsResponse {
   /// This field MUST be specified if the VOLUME_CONDITION node
   /// capability is supported.
   #[prost(message, optional, tag = "2")]
+  #[cfg(feature = "alpha")]
   pub volume_condition: ::core::option::Option<VolumeCondition>,
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -1400,6 +1420,7 @@ pub mod volume_usage {
   }
 }
 /// VolumeCondition represents the current condition of a volume.
+#[cfg(feature = "alpha")]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct VolumeCondition {
   /// Normal volumes are available for use and operating optimally.
@@ -1459,7 +1480,22 @@ pub mod node_service_capability {
       /// is the truth.
       /// Note that, for alpha, `VolumeCondition` is intended to be
       /// informative for humans only, not for automation.
+      #[cfg(feature = "alpha")]
       VolumeCondition = 4,
+      /// Indicates the SP supports the SINGLE_NODE_SINGLE_WRITER and/or
+      /// SINGLE_NODE_MULTI_WRITER access modes.
+      /// These access modes are intended to replace the
+      /// SINGLE_NODE_WRITER access mode to clarify the number of writers
+      /// for a volume on a single node. Plugins MUST accept and allow
+      /// use of the SINGLE_NODE_WRITER access mode (subject to the
+      /// processing rules for NodePublishVolume), when either
+      /// SINGLE_NODE_SINGLE_WRITER and/or SINGLE_NODE_MULTI_WRITER are
+      /// supported, in order to permit older COs to continue working.
+      SingleNodeMultiWriter = 5,
+      /// Indicates that Node service supports mounting volumes
+      /// with provided volume group identifier during node stage
+      /// or node publish RPC calls.
+      VolumeMountGroup = 6,
     }
   }
   #[derive(Clone, PartialEq, ::prost::Oneof)]
@@ -1514,17 +1550,15 @@ pub struct NodeGetInfoResponse {
   #[prost(message, optional, tag = "3")]
   pub accessible_topology: ::core::option::Option<Topology>,
 }
-#[derive(Clone, PartialEq, ::prost::Message)]
+#[derive(Clone, PartialEq, Default)]
 pub struct NodeExpandVolumeRequest {
   /// The ID of the volume. This field is REQUIRED.
-  #[prost(string, tag = "1")]
   pub volume_id: ::prost::alloc::string::String,
   /// The path on which volume is available. This field is REQUIRED.
   /// This field overrides the general CSI size limit.
   /// SP SHOULD support the maximum path length allowed by the operating
   /// system/filesystem, but, at a minimum, SP MUST accept a max path
   /// length of at least 128 bytes.
-  #[prost(string, tag = "2")]
   pub volume_path: ::prost::alloc::string::String,
   /// This allows CO to specify the capacity requirements of the volume
   /// after expansion. If capacity_range is omitted then a plugin MAY
@@ -1532,7 +1566,6 @@ pub struct NodeExpandVolumeRequest {
   /// capacity to which the volume can be expanded. In such cases a
   /// plugin MAY expand the volume to its maximum capacity.
   /// This field is OPTIONAL.
-  #[prost(message, optional, tag = "3")]
   pub capacity_range: ::core::option::Option<CapacityRange>,
   /// The path where the volume is staged, if the plugin has the
   /// STAGE_UNSTAGE_VOLUME capability, otherwise empty.
@@ -1543,7 +1576,6 @@ pub struct NodeExpandVolumeRequest {
   /// SP SHOULD support the maximum path length allowed by the operating
   /// system/filesystem, but, at a minimum, SP MUST accept a max path
   /// length of at least 128 bytes.
-  #[prost(string, tag = "4")]
   pub staging_target_path: ::prost::alloc::string::String,
   /// Volume capability describing how the CO intends to use this volume.
   /// This allows SP to determine if volume is being used as a block
@@ -1554,8 +1586,12 @@ pub struct NodeExpandVolumeRequest {
   /// volume_capability is omitted the SP MAY determine
   /// access_type from given volume_path for the volume and perform
   /// node expansion. This is an OPTIONAL field.
-  #[prost(message, optional, tag = "5")]
   pub volume_capability: ::core::option::Option<VolumeCapability>,
+  /// Secrets required by plugin to complete node expand volume request.
+  /// This field is OPTIONAL. Refer to the `Secrets Requirements`
+  /// section on how to use this field.
+  pub secrets:
+    ::std::collections::HashMap<::prost::alloc::string::String, ::prost::alloc::string::String>,
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct NodeExpandVolumeResponse {
@@ -1563,6 +1599,360 @@ pub struct NodeExpandVolumeResponse {
   #[prost(int64, tag = "1")]
   pub capacity_bytes: i64,
 }
+#[cfg(feature = "alpha")]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GroupControllerGetCapabilitiesRequest {}
+#[cfg(feature = "alpha")]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GroupControllerGetCapabilitiesResponse {
+  /// All the capabilities that the group controller service supports.
+  /// This field is OPTIONAL.
+  #[prost(message, repeated, tag = "1")]
+  pub capabilities: ::prost::alloc::vec::Vec<GroupControllerServiceCapability>,
+}
+/// Specifies a capability of the group controller service.
+#[cfg(feature = "alpha")]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GroupControllerServiceCapability {
+  #[prost(oneof = "group_controller_service_capability::Type", tags = "1")]
+  pub r#type: ::core::option::Option<group_controller_service_capability::Type>,
+}
+/// Nested message and enum types in `GroupControllerServiceCapability`.
+pub mod group_controller_service_capability {
+  #[cfg(feature = "alpha")]
+  #[derive(Clone, PartialEq, ::prost::Message)]
+  pub struct Rpc {
+    #[prost(enumeration = "rpc::Type", tag = "1")]
+    pub r#type: i32,
+  }
+  /// Nested message and enum types in `RPC`.
+  pub mod rpc {
+    #[cfg(feature = "alpha")]
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+    #[repr(i32)]
+    pub enum Type {
+      Unknown = 0,
+      /// Indicates that the group controller plugin supports
+      /// creating, deleting, and getting details of a volume
+      /// group snapshot.
+      CreateDeleteGetVolumeGroupSnapshot = 1,
+    }
+  }
+  #[cfg(feature = "alpha")]
+  #[derive(Clone, PartialEq, ::prost::Oneof)]
+  pub enum Type {
+    /// RPC that the controller supports.
+    #[prost(message, tag = "1")]
+    Rpc(Rpc),
+  }
+}
+#[cfg(feature = "alpha")]
+#[derive(Clone, PartialEq, Default)]
+pub struct CreateVolumeGroupSnapshotRequest {
+  /// The suggested name for the group snapshot. This field is REQUIRED
+  /// for idempotency.
+  /// Any Unicode string that conforms to the length limit is allowed
+  /// except those containing the following banned characters:
+  /// U+0000-U+0008, U+000B, U+000C, U+000E-U+001F, U+007F-U+009F.
+  /// (These are control characters other than commonly used whitespace.)
+  pub name: ::prost::alloc::string::String,
+  /// volume IDs of the source volumes to be snapshotted together.
+  /// This field is REQUIRED.
+  pub source_volume_ids: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+  /// Secrets required by plugin to complete
+  /// ControllerCreateVolumeGroupSnapshot request.
+  /// This field is OPTIONAL. Refer to the `Secrets Requirements`
+  /// section on how to use this field.
+  /// The secrets provided in this field SHOULD be the same for
+  /// all group snapshot operations on the same group snapshot.
+  pub secrets:
+    ::std::collections::HashMap<::prost::alloc::string::String, ::prost::alloc::string::String>,
+  /// Plugin specific parameters passed in as opaque key-value pairs.
+  /// This field is OPTIONAL. The Plugin is responsible for parsing and
+  /// validating these parameters. COs will treat these as opaque.
+  pub parameters:
+    ::std::collections::HashMap<::prost::alloc::string::String, ::prost::alloc::string::String>,
+}
+#[cfg(feature = "alpha")]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CreateVolumeGroupSnapshotResponse {
+  /// Contains all attributes of the newly created group snapshot.
+  /// This field is REQUIRED.
+  #[prost(message, optional, tag = "1")]
+  pub group_snapshot: ::core::option::Option<VolumeGroupSnapshot>,
+}
+#[cfg(feature = "alpha")]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct VolumeGroupSnapshot {
+  /// The identifier for this group snapshot, generated by the plugin.
+  /// This field MUST contain enough information to uniquely identify
+  /// this specific snapshot vs all other group snapshots supported by
+  /// this plugin.
+  /// This field SHALL be used by the CO in subsequent calls to refer to
+  /// this group snapshot.
+  /// The SP is NOT responsible for global uniqueness of
+  /// group_snapshot_id across multiple SPs.
+  /// This field is REQUIRED.
+  #[prost(string, tag = "1")]
+  pub group_snapshot_id: ::prost::alloc::string::String,
+  /// A list of snapshots belonging to this group.
+  /// This field is REQUIRED.
+  #[prost(message, repeated, tag = "2")]
+  pub snapshots: ::prost::alloc::vec::Vec<Snapshot>,
+  /// Timestamp of when the volume group snapshot was taken.
+  /// This field is REQUIRED.
+  #[prost(message, optional, tag = "3")]
+  pub creation_time: ::core::option::Option<::prost_types::Timestamp>,
+  /// Indicates if all individual snapshots in the group snapshot
+  /// are ready to use as a `volume_content_source` in a
+  /// `CreateVolumeRequest`. The default value is false.
+  /// If any snapshot in the list of snapshots in this message have
+  /// ready_to_use set to false, the SP MUST set this field to false.
+  /// If all of the snapshots in the list of snapshots in this message
+  /// have ready_to_use set to true, the SP SHOULD set this field to
+  /// true.
+  /// This field is REQUIRED.
+  #[prost(bool, tag = "4")]
+  pub ready_to_use: bool,
+}
+#[cfg(feature = "alpha")]
+#[derive(Clone, PartialEq, Default)]
+pub struct DeleteVolumeGroupSnapshotRequest {
+  /// The ID of the group snapshot to be deleted.
+  /// This field is REQUIRED.
+  pub group_snapshot_id: ::prost::alloc::string::String,
+  /// A list of snapshot IDs that are part of this group snapshot.
+  /// If SP does not need to rely on this field to delete the snapshots
+  /// in the group, it SHOULD check this field and report an error
+  /// if it has the ability to detect a mismatch.
+  /// Some SPs require this list to delete the snapshots in the group.
+  /// If SP needs to use this field to delete the snapshots in the
+  /// group, it MUST report an error if it has the ability to detect
+  /// a mismatch.
+  /// This field is REQUIRED.
+  pub snapshot_ids: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+  /// Secrets required by plugin to complete group snapshot deletion
+  /// request.
+  /// This field is OPTIONAL. Refer to the `Secrets Requirements`
+  /// section on how to use this field.
+  /// The secrets provided in this field SHOULD be the same for
+  /// all group snapshot operations on the same group snapshot.
+  pub secrets:
+    ::std::collections::HashMap<::prost::alloc::string::String, ::prost::alloc::string::String>,
+}
+#[cfg(feature = "alpha")]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DeleteVolumeGroupSnapshotResponse {}
+#[cfg(feature = "alpha")]
+#[derive(Clone, PartialEq, Default)]
+pub struct GetVolumeGroupSnapshotRequest {
+  /// The ID of the group snapshot to fetch current group snapshot
+  /// information for.
+  /// This field is REQUIRED.
+  pub group_snapshot_id: ::prost::alloc::string::String,
+  /// A list of snapshot IDs that are part of this group snapshot.
+  /// If SP does not need to rely on this field to get the snapshots
+  /// in the group, it SHOULD check this field and report an error
+  /// if it has the ability to detect a mismatch.
+  /// Some SPs require this list to get the snapshots in the group.
+  /// If SP needs to use this field to get the snapshots in the
+  /// group, it MUST report an error if it has the ability to detect
+  /// a mismatch.
+  /// This field is REQUIRED.
+  pub snapshot_ids: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+  /// Secrets required by plugin to complete
+  /// GetVolumeGroupSnapshot request.
+  /// This field is OPTIONAL. Refer to the `Secrets Requirements`
+  /// section on how to use this field.
+  /// The secrets provided in this field SHOULD be the same for
+  /// all group snapshot operations on the same group snapshot.
+  pub secrets:
+    ::std::collections::HashMap<::prost::alloc::string::String, ::prost::alloc::string::String>,
+}
+#[cfg(feature = "alpha")]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetVolumeGroupSnapshotResponse {
+  /// This field is REQUIRED
+  #[prost(message, optional, tag = "1")]
+  pub group_snapshot: ::core::option::Option<VolumeGroupSnapshot>,
+}
+/// BlockMetadata specifies a data range.
+#[cfg(feature = "alpha")]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BlockMetadata {
+  /// This is the zero based byte position in the volume or snapshot,
+  /// measured from the start of the object.
+  /// This field is REQUIRED.
+  #[prost(int64, tag = "1")]
+  pub byte_offset: i64,
+  /// This is the size of the data range.
+  /// size_bytes MUST be greater than zero.
+  /// This field is REQUIRED.
+  #[prost(int64, tag = "2")]
+  pub size_bytes: i64,
+}
+/// The GetMetadataAllocatedRequest message is used to solicit metadata
+/// on the allocated blocks of a snapshot: i.e. this identifies the
+/// data ranges that have valid data as they were the target of some
+/// previous write operation on the volume.
+#[cfg(feature = "alpha")]
+#[derive(Clone, PartialEq, Default)]
+pub struct GetMetadataAllocatedRequest {
+  /// This is the identifier of the snapshot.
+  /// This field is REQUIRED.
+  pub snapshot_id: ::prost::alloc::string::String,
+  /// This indicates the zero based starting byte position in the volume
+  /// snapshot from which the result should be computed.
+  /// It is intended to be used to continue a previously interrupted
+  /// call.
+  /// The CO SHOULD specify this value to be the offset of the byte
+  /// position immediately after the last byte of the last data range
+  /// received, if continuing an interrupted operation, or zero if not.
+  /// The SP MUST ensure that the returned response stream does not
+  /// contain BlockMetadata tuples that end before the requested
+  /// starting_offset: i.e. if S is the requested starting_offset, and
+  /// B0 is block_metadata[0] of the first message in the response
+  /// stream, then (S < B0.byte_offset + B0.size_bytes) must be true.
+  /// This field is REQUIRED.
+  pub starting_offset: i64,
+  /// This is an optional parameter, and if non-zero it specifies the
+  /// maximum number of tuples to be returned in each
+  /// GetMetadataAllocatedResponse message returned by the RPC stream.
+  /// The plugin will determine an appropriate value if 0, and is
+  /// always free to send less than the requested value.
+  /// This field is OPTIONAL.
+  pub max_results: i32,
+  /// Secrets required by plugin to complete the request.
+  /// This field is OPTIONAL. Refer to the `Secrets Requirements`
+  /// section on how to use this field.
+  pub secrets:
+    ::std::collections::HashMap<::prost::alloc::string::String, ::prost::alloc::string::String>,
+}
+/// GetMetadataAllocatedResponse messages are returned in a gRPC stream.
+/// Cumulatively, they provide information on the allocated data
+/// ranges in the snapshot.
+#[cfg(feature = "alpha")]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetMetadataAllocatedResponse {
+  /// This specifies the style used in the BlockMetadata sequence.
+  /// This value must be the same in all such messages returned by
+  /// the stream.
+  /// If block_metadata_type is FIXED_LENGTH, then the size_bytes field
+  /// of each message in the block_metadata list MUST be constant.
+  /// This field is REQUIRED.
+  #[prost(enumeration = "BlockMetadataType", tag = "1")]
+  pub block_metadata_type: i32,
+  /// This returns the capacity of the underlying volume in bytes.
+  /// This value must be the same in all such messages returned by
+  /// the stream.
+  /// This field is REQUIRED.
+  #[prost(int64, tag = "2")]
+  pub volume_capacity_bytes: i64,
+  /// This is a list of data range tuples.
+  /// If the value of max_results in the GetMetadataAllocatedRequest
+  /// message is greater than zero, then the number of entries in this
+  /// list MUST be less than or equal to that value.
+  /// The SP MUST respect the value of starting_offset in the request.
+  /// The byte_offset fields of adjacent BlockMetadata messages
+  /// MUST be strictly increasing and messages MUST NOT overlap:
+  /// i.e. for any two BlockMetadata messages, A and B, if A is returned
+  /// before B, then (A.byte_offset + A.size_bytes <= B.byte_offset)
+  /// MUST be true.
+  /// This MUST also be true if A and B are from block_metadata lists in
+  /// different GetMetadataAllocatedResponse messages in the gRPC stream.
+  /// This field is OPTIONAL.
+  #[prost(message, repeated, tag = "3")]
+  pub block_metadata: ::prost::alloc::vec::Vec<BlockMetadata>,
+}
+/// The GetMetadataDeltaRequest message is used to solicit metadata on
+/// the data ranges that have changed between two snapshots.
+#[cfg(feature = "alpha")]
+#[derive(Clone, PartialEq, Default)]
+pub struct GetMetadataDeltaRequest {
+  /// This is the identifier of the snapshot against which changes
+  /// are to be computed.
+  /// This field is REQUIRED.
+  pub base_snapshot_id: ::prost::alloc::string::String,
+  /// This is the identifier of a second snapshot in the same volume,
+  /// created after the base snapshot.
+  /// This field is REQUIRED.
+  pub target_snapshot_id: ::prost::alloc::string::String,
+  /// This indicates the zero based starting byte position in the volume
+  /// snapshot from which the result should be computed.
+  /// It is intended to be used to continue a previously interrupted
+  /// call.
+  /// The CO SHOULD specify this value to be the offset of the byte
+  /// position immediately after the last byte of the last data range
+  /// received, if continuing an interrupted operation, or zero if not.
+  /// The SP MUST ensure that the returned response stream does not
+  /// contain BlockMetadata tuples that end before the requested
+  /// starting_offset: i.e. if S is the requested starting_offset, and
+  /// B0 is block_metadata[0] of the first message in the response
+  /// stream, then (S < B0.byte_offset + B0.size_bytes) must be true.
+  /// This field is REQUIRED.
+  pub starting_offset: i64,
+  /// This is an optional parameter, and if non-zero it specifies the
+  /// maximum number of tuples to be returned in each
+  /// GetMetadataDeltaResponse message returned by the RPC stream.
+  /// The plugin will determine an appropriate value if 0, and is
+  /// always free to send less than the requested value.
+  /// This field is OPTIONAL.
+  pub max_results: i32,
+  /// Secrets required by plugin to complete the request.
+  /// This field is OPTIONAL. Refer to the `Secrets Requirements`
+  /// section on how to use this field.
+  pub secrets:
+    ::std::collections::HashMap<::prost::alloc::string::String, ::prost::alloc::string::String>,
+}
+/// GetMetadataDeltaResponse messages are returned in a gRPC stream.
+/// Cumulatively, they provide information on the data ranges that
+/// have changed between the base and target snapshots specified
+/// in the GetMetadataDeltaRequest message.
+#[cfg(feature = "alpha")]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetMetadataDeltaResponse {
+  /// This specifies the style used in the BlockMetadata sequence.
+  /// This value must be the same in all such messages returned by
+  /// the stream.
+  /// If block_metadata_type is FIXED_LENGTH, then the size_bytes field
+  /// of each message in the block_metadata list MUST be constant.
+  /// This field is REQUIRED.
+  #[prost(enumeration = "BlockMetadataType", tag = "1")]
+  pub block_metadata_type: i32,
+  /// This returns the capacity of the underlying volume in bytes.
+  /// This value must be the same in all such messages returned by
+  /// the stream.
+  /// This field is REQUIRED.
+  #[prost(int64, tag = "2")]
+  pub volume_capacity_bytes: i64,
+  /// This is a list of data range tuples.
+  /// If the value of max_results in the GetMetadataDeltaRequest message
+  /// is greater than zero, then the number of entries in this list MUST
+  /// be less than or equal to that value.
+  /// The SP MUST respect the value of starting_offset in the request.
+  /// The byte_offset fields of adjacent BlockMetadata messages
+  /// MUST be strictly increasing and messages MUST NOT overlap:
+  /// i.e. for any two BlockMetadata messages, A and B, if A is returned
+  /// before B, then (A.byte_offset + A.size_bytes <= B.byte_offset)
+  /// MUST be true.
+  /// This MUST also be true if A and B are from block_metadata lists in
+  /// different GetMetadataDeltaResponse messages in the gRPC stream.
+  /// This field is OPTIONAL.
+  #[prost(message, repeated, tag = "3")]
+  pub block_metadata: ::prost::alloc::vec::Vec<BlockMetadata>,
+}
+#[cfg(feature = "alpha")]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum BlockMetadataType {
+  Unknown = 0,
+  /// The FIXED_LENGTH value indicates that data ranges are
+  /// returned in fixed size blocks.
+  FixedLength = 1,
+  /// The VARIABLE_LENGTH value indicates that data ranges
+  /// are returned in potentially variable sized extents.
+  VariableLength = 2,
+}
 #[doc = r" Generated server implementations."]
 pub mod identity_server {
   #![allow(unused_variables, dead_code, missing_docs)]
@@ -1740,6 +2130,7 @@ pub mod identity_server {
     const NAME: &'static str = "csi.v1.Identity";
   }
 }
+#[cfg(feature = "alpha")]
 #[doc = r" Generated server implementations."]
 pub mod controller_server {
   #![allow(unused_variables, dead_code, missing_docs)]
@@ -2280,52 +2671,69 @@ pub mod controller_server {
     const NAME: &'static str = "csi.v1.Controller";
   }
 }
+#[cfg(not(feature = "alpha"))]
 #[doc = r" Generated server implementations."]
-pub mod node_server {
+pub mod controller_server {
   #![allow(unused_variables, dead_code, missing_docs)]
   use tonic::codegen::*;
-  #[doc = "Generated trait containing gRPC methods that should be implemented for use with NodeServer."]
+  #[doc = "Generated trait containing gRPC methods that should be implemented for use with ControllerServer."]
   #[async_trait]
-  pub trait Node: Send + Sync + 'static {
-    async fn node_stage_volume(
+  pub trait Controller: Send + Sync + 'static {
+    async fn create_volume(
       &self,
-      request: tonic::Request<super::NodeStageVolumeRequest>,
-    ) -> Result<tonic::Response<super::NodeStageVolumeResponse>, tonic::Status>;
-    async fn node_unstage_volume(
+      request: tonic::Request<super::CreateVolumeRequest>,
+    ) -> Result<tonic::Response<super::CreateVolumeResponse>, tonic::Status>;
+    async fn delete_volume(
       &self,
-      request: tonic::Request<super::NodeUnstageVolumeRequest>,
-    ) -> Result<tonic::Response<super::NodeUnstageVolumeResponse>, tonic::Status>;
-    async fn node_publish_volume(
+      request: tonic::Request<super::DeleteVolumeRequest>,
+    ) -> Result<tonic::Response<super::DeleteVolumeResponse>, tonic::Status>;
+    async fn controller_publish_volume(
       &self,
-      request: tonic::Request<super::NodePublishVolumeRequest>,
-    ) -> Result<tonic::Response<super::NodePublishVolumeResponse>, tonic::Status>;
-    async fn node_unpublish_volume(
+      request: tonic::Request<super::ControllerPublishVolumeRequest>,
+    ) -> Result<tonic::Response<super::ControllerPublishVolumeResponse>, tonic::Status>;
+    async fn controller_unpublish_volume(
       &self,
-      request: tonic::Request<super::NodeUnpublishVolumeRequest>,
-    ) -> Result<tonic::Response<super::NodeUnpublishVolumeResponse>, tonic::Status>;
-    async fn node_get_volume_stats(
+      request: tonic::Request<super::ControllerUnpublishVolumeRequest>,
+    ) -> Result<tonic::Response<super::ControllerUnpublishVolumeResponse>, tonic::Status>;
+    async fn validate_volume_capabilities(
       &self,
-      request: tonic::Request<super::NodeGetVolumeStatsRequest>,
-    ) -> Result<tonic::Response<super::NodeGetVolumeStatsResponse>, tonic::Status>;
-    async fn node_expand_volume(
+      request: tonic::Request<super::ValidateVolumeCapabilitiesRequest>,
+    ) -> Result<tonic::Response<super::ValidateVolumeCapabilitiesResponse>, tonic::Status>;
+    async fn list_volumes(
       &self,
-      request: tonic::Request<super::NodeExpandVolumeRequest>,
-    ) -> Result<tonic::Response<super::NodeExpandVolumeResponse>, tonic::Status>;
-    async fn node_get_capabilities(
+      request: tonic::Request<super::ListVolumesRequest>,
+    ) -> Result<tonic::Response<super::ListVolumesResponse>, tonic::Status>;
+    async fn get_capacity(
       &self,
-      request: tonic::Request<super::NodeGetCapabilitiesRequest>,
-    ) -> Result<tonic::Response<super::NodeGetCapabilitiesResponse>, tonic::Status>;
-    async fn node_get_info(
+      request: tonic::Request<super::GetCapacityRequest>,
+    ) -> Result<tonic::Response<super::GetCapacityResponse>, tonic::Status>;
+    async fn controller_get_capabilities(
       &self,
-      request: tonic::Request<super::NodeGetInfoRequest>,
-    ) -> Result<tonic::Response<super::NodeGetInfoResponse>, tonic::Status>;
+      request: tonic::Request<super::ControllerGetCapabilitiesRequest>,
+    ) -> Result<tonic::Response<super::ControllerGetCapabilitiesResponse>, tonic::Status>;
+    async fn create_snapshot(
+      &self,
+      request: tonic::Request<super::CreateSnapshotRequest>,
+    ) -> Result<tonic::Response<super::CreateSnapshotResponse>, tonic::Status>;
+    async fn delete_snapshot(
+      &self,
+      request: tonic::Request<super::DeleteSnapshotRequest>,
+    ) -> Result<tonic::Response<super::DeleteSnapshotResponse>, tonic::Status>;
+    async fn list_snapshots(
+      &self,
+      request: tonic::Request<super::ListSnapshotsRequest>,
+    ) -> Result<tonic::Response<super::ListSnapshotsResponse>, tonic::Status>;
+    async fn controller_expand_volume(
+      &self,
+      request: tonic::Request<super::ControllerExpandVolumeRequest>,
+    ) -> Result<tonic::Response<super::ControllerExpandVolumeResponse>, tonic::Status>;
   }
   #[derive(Debug)]
-  pub struct NodeServer<T: Node> {
+  pub struct ControllerServer<T: Controller> {
     inner: _Inner<T>,
   }
   struct _Inner<T>(Arc<T>, Option<tonic::Interceptor>);
-  impl<T: Node> NodeServer<T> {
+  impl<T: Controller> ControllerServer<T> {
     pub fn new(inner: T) -> Self {
       let inner = Arc::new(inner);
       let inner = _Inner(inner, None);
@@ -2337,9 +2745,9 @@ pub mod node_server {
       Self { inner }
     }
   }
-  impl<T, B> Service<http::Request<B>> for NodeServer<T>
+  impl<T, B> Service<http::Request<B>> for ControllerServer<T>
   where
-    T: Node,
+    T: Controller,
     B: HttpBody + Send + Sync + 'static,
     B::Error: Into<StdError> + Send + 'static,
   {
@@ -2352,18 +2760,18 @@ pub mod node_server {
     fn call(&mut self, req: http::Request<B>) -> Self::Future {
       let inner = self.inner.clone();
       match req.uri().path() {
-        "/csi.v1.Node/NodeStageVolume" => {
+        "/csi.v1.Controller/CreateVolume" => {
           #[allow(non_camel_case_types)]
-          struct NodeStageVolumeSvc<T: Node>(pub Arc<T>);
-          impl<T: Node> tonic::server::UnaryService<super::NodeStageVolumeRequest> for NodeStageVolumeSvc<T> {
-            type Response = super::NodeStageVolumeResponse;
+          struct CreateVolumeSvc<T: Controller>(pub Arc<T>);
+          impl<T: Controller> tonic::server::UnaryService<super::CreateVolumeRequest> for CreateVolumeSvc<T> {
+            type Response = super::CreateVolumeResponse;
             type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
             fn call(
               &mut self,
-              request: tonic::Request<super::NodeStageVolumeRequest>,
+              request: tonic::Request<super::CreateVolumeRequest>,
             ) -> Self::Future {
               let inner = self.0.clone();
-              let fut = async move { (*inner).node_stage_volume(request).await };
+              let fut = async move { (*inner).create_volume(request).await };
               Box::pin(fut)
             }
           }
@@ -2371,7 +2779,7 @@ pub mod node_server {
           let fut = async move {
             let interceptor = inner.1.clone();
             let inner = inner.0;
-            let method = NodeStageVolumeSvc(inner);
+            let method = CreateVolumeSvc(inner);
             let codec = tonic::codec::ProstCodec::default();
             let mut grpc = if let Some(interceptor) = interceptor {
               tonic::server::Grpc::with_interceptor(codec, interceptor)
@@ -2383,20 +2791,51 @@ pub mod node_server {
           };
           Box::pin(fut)
         }
-        "/csi.v1.Node/NodeUnstageVolume" => {
+        "/csi.v1.Controller/DeleteVolume" => {
           #[allow(non_camel_case_types)]
-          struct NodeUnstageVolumeSvc<T: Node>(pub Arc<T>);
-          impl<T: Node> tonic::server::UnaryService<super::NodeUnstageVolumeRequest>
-            for NodeUnstageVolumeSvc<T>
+          struct DeleteVolumeSvc<T: Controller>(pub Arc<T>);
+          impl<T: Controller> tonic::server::UnaryService<super::DeleteVolumeRequest> for DeleteVolumeSvc<T> {
+            type Response = super::DeleteVolumeResponse;
+            type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+            fn call(
+              &mut self,
+              request: tonic::Request<super::DeleteVolumeRequest>,
+            ) -> Self::Future {
+              let inner = self.0.clone();
+              let fut = async move { (*inner).delete_volume(request).await };
+              Box::pin(fut)
+            }
+          }
+          let inner = self.inner.clone();
+          let fut = async move {
+            let interceptor = inner.1.clone();
+            let inner = inner.0;
+            let method = DeleteVolumeSvc(inner);
+            let codec = tonic::codec::ProstCodec::default();
+            let mut grpc = if let Some(interceptor) = interceptor {
+              tonic::server::Grpc::with_interceptor(codec, interceptor)
+            } else {
+              tonic::server::Grpc::new(codec)
+            };
+            let res = grpc.unary(method, req).await;
+            Ok(res)
+          };
+          Box::pin(fut)
+        }
+        "/csi.v1.Controller/ControllerPublishVolume" => {
+          #[allow(non_camel_case_types)]
+          struct ControllerPublishVolumeSvc<T: Controller>(pub Arc<T>);
+          impl<T: Controller> tonic::server::UnaryService<super::ControllerPublishVolumeRequest>
+            for ControllerPublishVolumeSvc<T>
           {
-            type Response = super::NodeUnstageVolumeResponse;
+            type Response = super::ControllerPublishVolumeResponse;
             type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
             fn call(
               &mut self,
-              request: tonic::Request<super::NodeUnstageVolumeRequest>,
+              request: tonic::Request<super::ControllerPublishVolumeRequest>,
             ) -> Self::Future {
               let inner = self.0.clone();
-              let fut = async move { (*inner).node_unstage_volume(request).await };
+              let fut = async move { (*inner).controller_publish_volume(request).await };
               Box::pin(fut)
             }
           }
@@ -2404,7 +2843,7 @@ pub mod node_server {
           let fut = async move {
             let interceptor = inner.1.clone();
             let inner = inner.0;
-            let method = NodeUnstageVolumeSvc(inner);
+            let method = ControllerPublishVolumeSvc(inner);
             let codec = tonic::codec::ProstCodec::default();
             let mut grpc = if let Some(interceptor) = interceptor {
               tonic::server::Grpc::with_interceptor(codec, interceptor)
@@ -2416,20 +2855,20 @@ pub mod node_server {
           };
           Box::pin(fut)
         }
-        "/csi.v1.Node/NodePublishVolume" => {
+        "/csi.v1.Controller/ControllerUnpublishVolume" => {
           #[allow(non_camel_case_types)]
-          struct NodePublishVolumeSvc<T: Node>(pub Arc<T>);
-          impl<T: Node> tonic::server::UnaryService<super::NodePublishVolumeRequest>
-            for NodePublishVolumeSvc<T>
+          struct ControllerUnpublishVolumeSvc<T: Controller>(pub Arc<T>);
+          impl<T: Controller> tonic::server::UnaryService<super::ControllerUnpublishVolumeRequest>
+            for ControllerUnpublishVolumeSvc<T>
           {
-            type Response = super::NodePublishVolumeResponse;
+            type Response = super::ControllerUnpublishVolumeResponse;
             type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
             fn call(
               &mut self,
-              request: tonic::Request<super::NodePublishVolumeRequest>,
+              request: tonic::Request<super::ControllerUnpublishVolumeRequest>,
             ) -> Self::Future {
               let inner = self.0.clone();
-              let fut = async move { (*inner).node_publish_volume(request).await };
+              let fut = async move { (*inner).controller_unpublish_volume(request).await };
               Box::pin(fut)
             }
           }
@@ -2437,7 +2876,7 @@ pub mod node_server {
           let fut = async move {
             let interceptor = inner.1.clone();
             let inner = inner.0;
-            let method = NodePublishVolumeSvc(inner);
+            let method = ControllerUnpublishVolumeSvc(inner);
             let codec = tonic::codec::ProstCodec::default();
             let mut grpc = if let Some(interceptor) = interceptor {
               tonic::server::Grpc::with_interceptor(codec, interceptor)
@@ -2449,20 +2888,20 @@ pub mod node_server {
           };
           Box::pin(fut)
         }
-        "/csi.v1.Node/NodeUnpublishVolume" => {
+        "/csi.v1.Controller/ValidateVolumeCapabilities" => {
           #[allow(non_camel_case_types)]
-          struct NodeUnpublishVolumeSvc<T: Node>(pub Arc<T>);
-          impl<T: Node> tonic::server::UnaryService<super::NodeUnpublishVolumeRequest>
-            for NodeUnpublishVolumeSvc<T>
+          struct ValidateVolumeCapabilitiesSvc<T: Controller>(pub Arc<T>);
+          impl<T: Controller> tonic::server::UnaryService<super::ValidateVolumeCapabilitiesRequest>
+            for ValidateVolumeCapabilitiesSvc<T>
           {
-            type Response = super::NodeUnpublishVolumeResponse;
+            type Response = super::ValidateVolumeCapabilitiesResponse;
             type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
             fn call(
               &mut self,
-              request: tonic::Request<super::NodeUnpublishVolumeRequest>,
+              request: tonic::Request<super::ValidateVolumeCapabilitiesRequest>,
             ) -> Self::Future {
               let inner = self.0.clone();
-              let fut = async move { (*inner).node_unpublish_volume(request).await };
+              let fut = async move { (*inner).validate_volume_capabilities(request).await };
               Box::pin(fut)
             }
           }
@@ -2470,7 +2909,7 @@ pub mod node_server {
           let fut = async move {
             let interceptor = inner.1.clone();
             let inner = inner.0;
-            let method = NodeUnpublishVolumeSvc(inner);
+            let method = ValidateVolumeCapabilitiesSvc(inner);
             let codec = tonic::codec::ProstCodec::default();
             let mut grpc = if let Some(interceptor) = interceptor {
               tonic::server::Grpc::with_interceptor(codec, interceptor)
@@ -2482,20 +2921,76 @@ pub mod node_server {
           };
           Box::pin(fut)
         }
-        "/csi.v1.Node/NodeGetVolumeStats" => {
+        "/csi.v1.Controller/ListVolumes" => {
           #[allow(non_camel_case_types)]
-          struct NodeGetVolumeStatsSvc<T: Node>(pub Arc<T>);
-          impl<T: Node> tonic::server::UnaryService<super::NodeGetVolumeStatsRequest>
-            for NodeGetVolumeStatsSvc<T>
+          struct ListVolumesSvc<T: Controller>(pub Arc<T>);
+          impl<T: Controller> tonic::server::UnaryService<super::ListVolumesRequest> for ListVolumesSvc<T> {
+            type Response = super::ListVolumesResponse;
+            type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+            fn call(&mut self, request: tonic::Request<super::ListVolumesRequest>) -> Self::Future {
+              let inner = self.0.clone();
+              let fut = async move { (*inner).list_volumes(request).await };
+              Box::pin(fut)
+            }
+          }
+          let inner = self.inner.clone();
+          let fut = async move {
+            let interceptor = inner.1.clone();
+            let inner = inner.0;
+            let method = ListVolumesSvc(inner);
+            let codec = tonic::codec::ProstCodec::default();
+            let mut grpc = if let Some(interceptor) = interceptor {
+              tonic::server::Grpc::with_interceptor(codec, interceptor)
+            } else {
+              tonic::server::Grpc::new(codec)
+            };
+            let res = grpc.unary(method, req).await;
+            Ok(res)
+          };
+          Box::pin(fut)
+        }
+        "/csi.v1.Controller/GetCapacity" => {
+          #[allow(non_camel_case_types)]
+          struct GetCapacitySvc<T: Controller>(pub Arc<T>);
+          impl<T: Controller> tonic::server::UnaryService<super::GetCapacityRequest> for GetCapacitySvc<T> {
+            type Response = super::GetCapacityResponse;
+            type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+            fn call(&mut self, request: tonic::Request<super::GetCapacityRequest>) -> Self::Future {
+              let inner = self.0.clone();
+              let fut = async move { (*inner).get_capacity(request).await };
+              Box::pin(fut)
+            }
+          }
+          let inner = self.inner.clone();
+          let fut = async move {
+            let interceptor = inner.1.clone();
+            let inner = inner.0;
+            let method = GetCapacitySvc(inner);
+            let codec = tonic::codec::ProstCodec::default();
+            let mut grpc = if let Some(interceptor) = interceptor {
+              tonic::server::Grpc::with_interceptor(codec, interceptor)
+            } else {
+              tonic::server::Grpc::new(codec)
+            };
+            let res = grpc.unary(method, req).await;
+            Ok(res)
+          };
+          Box::pin(fut)
+        }
+        "/csi.v1.Controller/ControllerGetCapabilities" => {
+          #[allow(non_camel_case_types)]
+          struct ControllerGetCapabilitiesSvc<T: Controller>(pub Arc<T>);
+          impl<T: Controller> tonic::server::UnaryService<super::ControllerGetCapabilitiesRequest>
+            for ControllerGetCapabilitiesSvc<T>
           {
-            type Response = super::NodeGetVolumeStatsResponse;
+            type Response = super::ControllerGetCapabilitiesResponse;
             type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
             fn call(
               &mut self,
-              request: tonic::Request<super::NodeGetVolumeStatsRequest>,
+              request: tonic::Request<super::ControllerGetCapabilitiesRequest>,
             ) -> Self::Future {
               let inner = self.0.clone();
-              let fut = async move { (*inner).node_get_volume_stats(request).await };
+              let fut = async move { (*inner).controller_get_capabilities(request).await };
               Box::pin(fut)
             }
           }
@@ -2503,7 +2998,7 @@ pub mod node_server {
           let fut = async move {
             let interceptor = inner.1.clone();
             let inner = inner.0;
-            let method = NodeGetVolumeStatsSvc(inner);
+            let method = ControllerGetCapabilitiesSvc(inner);
             let codec = tonic::codec::ProstCodec::default();
             let mut grpc = if let Some(interceptor) = interceptor {
               tonic::server::Grpc::with_interceptor(codec, interceptor)
@@ -2515,20 +3010,20 @@ pub mod node_server {
           };
           Box::pin(fut)
         }
-        "/csi.v1.Node/NodeExpandVolume" => {
+        "/csi.v1.Controller/CreateSnapshot" => {
           #[allow(non_camel_case_types)]
-          struct NodeExpandVolumeSvc<T: Node>(pub Arc<T>);
-          impl<T: Node> tonic::server::UnaryService<super::NodeExpandVolumeRequest>
-            for NodeExpandVolumeSvc<T>
+          struct CreateSnapshotSvc<T: Controller>(pub Arc<T>);
+          impl<T: Controller> tonic::server::UnaryService<super::CreateSnapshotRequest>
+            for CreateSnapshotSvc<T>
           {
-            type Response = super::NodeExpandVolumeResponse;
+            type Response = super::CreateSnapshotResponse;
             type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
             fn call(
               &mut self,
-              request: tonic::Request<super::NodeExpandVolumeRequest>,
+              request: tonic::Request<super::CreateSnapshotRequest>,
             ) -> Self::Future {
               let inner = self.0.clone();
-              let fut = async move { (*inner).node_expand_volume(request).await };
+              let fut = async move { (*inner).create_snapshot(request).await };
               Box::pin(fut)
             }
           }
@@ -2536,7 +3031,7 @@ pub mod node_server {
           let fut = async move {
             let interceptor = inner.1.clone();
             let inner = inner.0;
-            let method = NodeExpandVolumeSvc(inner);
+            let method = CreateSnapshotSvc(inner);
             let codec = tonic::codec::ProstCodec::default();
             let mut grpc = if let Some(interceptor) = interceptor {
               tonic::server::Grpc::with_interceptor(codec, interceptor)
@@ -2548,20 +3043,20 @@ pub mod node_server {
           };
           Box::pin(fut)
         }
-        "/csi.v1.Node/NodeGetCapabilities" => {
+        "/csi.v1.Controller/DeleteSnapshot" => {
           #[allow(non_camel_case_types)]
-          struct NodeGetCapabilitiesSvc<T: Node>(pub Arc<T>);
-          impl<T: Node> tonic::server::UnaryService<super::NodeGetCapabilitiesRequest>
-            for NodeGetCapabilitiesSvc<T>
+          struct DeleteSnapshotSvc<T: Controller>(pub Arc<T>);
+          impl<T: Controller> tonic::server::UnaryService<super::DeleteSnapshotRequest>
+            for DeleteSnapshotSvc<T>
           {
-            type Response = super::NodeGetCapabilitiesResponse;
+            type Response = super::DeleteSnapshotResponse;
             type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
             fn call(
               &mut self,
-              request: tonic::Request<super::NodeGetCapabilitiesRequest>,
+              request: tonic::Request<super::DeleteSnapshotRequest>,
             ) -> Self::Future {
               let inner = self.0.clone();
-              let fut = async move { (*inner).node_get_capabilities(request).await };
+              let fut = async move { (*inner).delete_snapshot(request).await };
               Box::pin(fut)
             }
           }
@@ -2569,7 +3064,7 @@ pub mod node_server {
           let fut = async move {
             let interceptor = inner.1.clone();
             let inner = inner.0;
-            let method = NodeGetCapabilitiesSvc(inner);
+            let method = DeleteSnapshotSvc(inner);
             let codec = tonic::codec::ProstCodec::default();
             let mut grpc = if let Some(interceptor) = interceptor {
               tonic::server::Grpc::with_interceptor(codec, interceptor)
@@ -2581,15 +3076,20 @@ pub mod node_server {
           };
           Box::pin(fut)
         }
-        "/csi.v1.Node/NodeGetInfo" => {
+        "/csi.v1.Controller/ListSnapshots" => {
           #[allow(non_camel_case_types)]
-          struct NodeGetInfoSvc<T: Node>(pub Arc<T>);
-          impl<T: Node> tonic::server::UnaryService<super::NodeGetInfoRequest> for NodeGetInfoSvc<T> {
-            type Response = super::NodeGetInfoResponse;
+          struct ListSnapshotsSvc<T: Controller>(pub Arc<T>);
+          impl<T: Controller> tonic::server::UnaryService<super::ListSnapshotsRequest>
+            for ListSnapshotsSvc<T>
+          {
+            type Response = super::ListSnapshotsResponse;
             type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
-            fn call(&mut self, request: tonic::Request<super::NodeGetInfoRequest>) -> Self::Future {
+            fn call(
+              &mut self,
+              request: tonic::Request<super::ListSnapshotsRequest>,
+            ) -> Self::Future {
               let inner = self.0.clone();
-              let fut = async move { (*inner).node_get_info(request).await };
+              let fut = async move { (*inner).list_snapshots(request).await };
               Box::pin(fut)
             }
           }
@@ -2597,7 +3097,40 @@ pub mod node_server {
           let fut = async move {
             let interceptor = inner.1.clone();
             let inner = inner.0;
-            let method = NodeGetInfoSvc(inner);
+            let method = ListSnapshotsSvc(inner);
+            let codec = tonic::codec::ProstCodec::default();
+            let mut grpc = if let Some(interceptor) = interceptor {
+              tonic::server::Grpc::with_interceptor(codec, interceptor)
+            } else {
+              tonic::server::Grpc::new(codec)
+            };
+            let res = grpc.unary(method, req).await;
+            Ok(res)
+          };
+          Box::pin(fut)
+        }
+        "/csi.v1.Controller/ControllerExpandVolume" => {
+          #[allow(non_camel_case_types)]
+          struct ControllerExpandVolumeSvc<T: Controller>(pub Arc<T>);
+          impl<T: Controller> tonic::server::UnaryService<super::ControllerExpandVolumeRequest>
+            for ControllerExpandVolumeSvc<T>
+          {
+            type Response = super::ControllerExpandVolumeResponse;
+            type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+            fn call(
+              &mut self,
+              request: tonic::Request<super::ControllerExpandVolumeRequest>,
+            ) -> Self::Future {
+              let inner = self.0.clone();
+              let fut = async move { (*inner).controller_expand_volume(request).await };
+              Box::pin(fut)
+            }
+          }
+          let inner = self.inner.clone();
+          let fut = async move {
+            let interceptor = inner.1.clone();
+            let inner = inner.0;
+            let method = ControllerExpandVolumeSvc(inner);
             let codec = tonic::codec::ProstCodec::default();
             let mut grpc = if let Some(interceptor) = interceptor {
               tonic::server::Grpc::with_interceptor(codec, interceptor)
@@ -2622,13 +3155,13 @@ pub mod node_server {
       }
     }
   }
-  impl<T: Node> Clone for NodeServer<T> {
+  impl<T: Controller> Clone for ControllerServer<T> {
     fn clone(&self) -> Self {
       let inner = self.inner.clone();
       Self { inner }
     }
   }
-  impl<T: Node> Clone for _Inner<T> {
+  impl<T: Controller> Clone for _Inner<T> {
     fn clone(&self) -> Self {
       Self(self.0.clone(), self.1.clone())
     }
@@ -2638,7 +3171,3187 @@ pub mod node_server {
       write!(f, "{:?}", self.0)
     }
   }
-  impl<T: Node> tonic::transport::NamedService for NodeServer<T> {
-    const NAME: &'static str = "csi.v1.Node";
+  impl<T: Controller> tonic::transport::NamedService for ControllerServer<T> {
+    const NAME: &'static str = "csi.v1.Controller";
+  }
+}
+#[doc = r" Generated server implementations."]
+pub mod node_server {
+  #![allow(unused_variables, dead_code, missing_docs)]
+  use tonic::codegen::*;
+  #[doc = "Generated trait containing gRPC methods that should be implemented for use with NodeServer."]
+  #[async_trait]
+  pub trait Node: Send + Sync + 'static {
+    async fn node_stage_volume(
+      &self,
+      request: tonic::Request<super::NodeStageVolumeRequest>,
+    ) -> Result<tonic::Response<super::NodeStageVolumeResponse>, tonic::Status>;
+    async fn node_unstage_volume(
+      &self,
+      request: tonic::Request<super::NodeUnstageVolumeRequest>,
+    ) -> Result<tonic::Response<super::NodeUnstageVolumeResponse>, tonic::Status>;
+    async fn node_publish_volume(
+      &self,
+      request: tonic::Request<super::NodePublishVolumeRequest>,
+    ) -> Result<tonic::Response<super::NodePublishVolumeResponse>, tonic::Status>;
+    async fn node_unpublish_volume(
+      &self,
+      request: tonic::Request<super::NodeUnpublishVolumeRequest>,
+    ) -> Result<tonic::Response<super::NodeUnpublishVolumeResponse>, tonic::Status>;
+    async fn node_get_volume_stats(
+      &self,
+      request: tonic::Request<super::NodeGetVolumeStatsRequest>,
+    ) -> Result<tonic::Response<super::NodeGetVolumeStatsResponse>, tonic::Status>;
+    async fn node_expand_volume(
+      &self,
+      request: tonic::Request<super::NodeExpandVolumeRequest>,
+    ) -> Result<tonic::Response<super::NodeExpandVolumeResponse>, tonic::Status>;
+    async fn node_get_capabilities(
+      &self,
+      request: tonic::Request<super::NodeGetCapabilitiesRequest>,
+    ) -> Result<tonic::Response<super::NodeGetCapabilitiesResponse>, tonic::Status>;
+    async fn node_get_info(
+      &self,
+      request: tonic::Request<super::NodeGetInfoRequest>,
+    ) -> Result<tonic::Response<super::NodeGetInfoResponse>, tonic::Status>;
+  }
+  #[derive(Debug)]
+  pub struct NodeServer<T: Node> {
+    inner: _Inner<T>,
+  }
+  struct _Inner<T>(Arc<T>, Option<tonic::Interceptor>);
+  impl<T: Node> NodeServer<T> {
+    pub fn new(inner: T) -> Self {
+      let inner = Arc::new(inner);
+      let inner = _Inner(inner, None);
+      Self { inner }
+    }
+    pub fn with_interceptor(inner: T, interceptor: impl Into<tonic::Interceptor>) -> Self {
+      let inner = Arc::new(inner);
+      let inner = _Inner(inner, Some(interceptor.into()));
+      Self { inner }
+    }
+  }
+  impl<T, B> Service<http::Request<B>> for NodeServer<T>
+  where
+    T: Node,
+    B: HttpBody + Send + Sync + 'static,
+    B::Error: Into<StdError> + Send + 'static,
+  {
+    type Response = http::Response<tonic::body::BoxBody>;
+    type Error = Never;
+    type Future = BoxFuture<Self::Response, Self::Error>;
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+      Poll::Ready(Ok(()))
+    }
+    fn call(&mut self, req: http::Request<B>) -> Self::Future {
+      let inner = self.inner.clone();
+      match req.uri().path() {
+        "/csi.v1.Node/NodeStageVolume" => {
+          #[allow(non_camel_case_types)]
+          struct NodeStageVolumeSvc<T: Node>(pub Arc<T>);
+          impl<T: Node> tonic::server::UnaryService<super::NodeStageVolumeRequest> for NodeStageVolumeSvc<T> {
+            type Response = super::NodeStageVolumeResponse;
+            type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+            fn call(
+              &mut self,
+              request: tonic::Request<super::NodeStageVolumeRequest>,
+            ) -> Self::Future {
+              let inner = self.0.clone();
+              let fut = async move { (*inner).node_stage_volume(request).await };
+              Box::pin(fut)
+            }
+          }
+          let inner = self.inner.clone();
+          let fut = async move {
+            let interceptor = inner.1.clone();
+            let inner = inner.0;
+            let method = NodeStageVolumeSvc(inner);
+            let codec = tonic::codec::ProstCodec::default();
+            let mut grpc = if let Some(interceptor) = interceptor {
+              tonic::server::Grpc::with_interceptor(codec, interceptor)
+            } else {
+              tonic::server::Grpc::new(codec)
+            };
+            let res = grpc.unary(method, req).await;
+            Ok(res)
+          };
+          Box::pin(fut)
+        }
+        "/csi.v1.Node/NodeUnstageVolume" => {
+          #[allow(non_camel_case_types)]
+          struct NodeUnstageVolumeSvc<T: Node>(pub Arc<T>);
+          impl<T: Node> tonic::server::UnaryService<super::NodeUnstageVolumeRequest>
+            for NodeUnstageVolumeSvc<T>
+          {
+            type Response = super::NodeUnstageVolumeResponse;
+            type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+            fn call(
+              &mut self,
+              request: tonic::Request<super::NodeUnstageVolumeRequest>,
+            ) -> Self::Future {
+              let inner = self.0.clone();
+              let fut = async move { (*inner).node_unstage_volume(request).await };
+              Box::pin(fut)
+            }
+          }
+          let inner = self.inner.clone();
+          let fut = async move {
+            let interceptor = inner.1.clone();
+            let inner = inner.0;
+            let method = NodeUnstageVolumeSvc(inner);
+            let codec = tonic::codec::ProstCodec::default();
+            let mut grpc = if let Some(interceptor) = interceptor {
+              tonic::server::Grpc::with_interceptor(codec, interceptor)
+            } else {
+              tonic::server::Grpc::new(codec)
+            };
+            let res = grpc.unary(method, req).await;
+            Ok(res)
+          };
+          Box::pin(fut)
+        }
+        "/csi.v1.Node/NodePublishVolume" => {
+          #[allow(non_camel_case_types)]
+          struct NodePublishVolumeSvc<T: Node>(pub Arc<T>);
+          impl<T: Node> tonic::server::UnaryService<super::NodePublishVolumeRequest>
+            for NodePublishVolumeSvc<T>
+          {
+            type Response = super::NodePublishVolumeResponse;
+            type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+            fn call(
+              &mut self,
+              request: tonic::Request<super::NodePublishVolumeRequest>,
+            ) -> Self::Future {
+              let inner = self.0.clone();
+              let fut = async move { (*inner).node_publish_volume(request).await };
+              Box::pin(fut)
+            }
+          }
+          let inner = self.inner.clone();
+          let fut = async move {
+            let interceptor = inner.1.clone();
+            let inner = inner.0;
+            let method = NodePublishVolumeSvc(inner);
+            let codec = tonic::codec::ProstCodec::default();
+            let mut grpc = if let Some(interceptor) = interceptor {
+              tonic::server::Grpc::with_interceptor(codec, interceptor)
+            } else {
+              tonic::server::Grpc::new(codec)
+            };
+            let res = grpc.unary(method, req).await;
+            Ok(res)
+          };
+          Box::pin(fut)
+        }
+        "/csi.v1.Node/NodeUnpublishVolume" => {
+          #[allow(non_camel_case_types)]
+          struct NodeUnpublishVolumeSvc<T: Node>(pub Arc<T>);
+          impl<T: Node> tonic::server::UnaryService<super::NodeUnpublishVolumeRequest>
+            for NodeUnpublishVolumeSvc<T>
+          {
+            type Response = super::NodeUnpublishVolumeResponse;
+            type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+            fn call(
+              &mut self,
+              request: tonic::Request<super::NodeUnpublishVolumeRequest>,
+            ) -> Self::Future {
+              let inner = self.0.clone();
+              let fut = async move { (*inner).node_unpublish_volume(request).await };
+              Box::pin(fut)
+            }
+          }
+          let inner = self.inner.clone();
+          let fut = async move {
+            let interceptor = inner.1.clone();
+            let inner = inner.0;
+            let method = NodeUnpublishVolumeSvc(inner);
+            let codec = tonic::codec::ProstCodec::default();
+            let mut grpc = if let Some(interceptor) = interceptor {
+              tonic::server::Grpc::with_interceptor(codec, interceptor)
+            } else {
+              tonic::server::Grpc::new(codec)
+            };
+            let res = grpc.unary(method, req).await;
+            Ok(res)
+          };
+          Box::pin(fut)
+        }
+        "/csi.v1.Node/NodeGetVolumeStats" => {
+          #[allow(non_camel_case_types)]
+          struct NodeGetVolumeStatsSvc<T: Node>(pub Arc<T>);
+          impl<T: Node> tonic::server::UnaryService<super::NodeGetVolumeStatsRequest>
+            for NodeGetVolumeStatsSvc<T>
+          {
+            type Response = super::NodeGetVolumeStatsResponse;
+            type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+            fn call(
+              &mut self,
+              request: tonic::Request<super::NodeGetVolumeStatsRequest>,
+            ) -> Self::Future {
+              let inner = self.0.clone();
+              let fut = async move { (*inner).node_get_volume_stats(request).await };
+              Box::pin(fut)
+            }
+          }
+          let inner = self.inner.clone();
+          let fut = async move {
+            let interceptor = inner.1.clone();
+            let inner = inner.0;
+            let method = NodeGetVolumeStatsSvc(inner);
+            let codec = tonic::codec::ProstCodec::default();
+            let mut grpc = if let Some(interceptor) = interceptor {
+              tonic::server::Grpc::with_interceptor(codec, interceptor)
+            } else {
+              tonic::server::Grpc::new(codec)
+            };
+            let res = grpc.unary(method, req).await;
+            Ok(res)
+          };
+          Box::pin(fut)
+        }
+        "/csi.v1.Node/NodeExpandVolume" => {
+          #[allow(non_camel_case_types)]
+          struct NodeExpandVolumeSvc<T: Node>(pub Arc<T>);
+          impl<T: Node> tonic::server::UnaryService<super::NodeExpandVolumeRequest>
+            for NodeExpandVolumeSvc<T>
+          {
+            type Response = super::NodeExpandVolumeResponse;
+            type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+            fn call(
+              &mut self,
+              request: tonic::Request<super::NodeExpandVolumeRequest>,
+            ) -> Self::Future {
+              let inner = self.0.clone();
+              let fut = async move { (*inner).node_expand_volume(request).await };
+              Box::pin(fut)
+            }
+          }
+          let inner = self.inner.clone();
+          let fut = async move {
+            let interceptor = inner.1.clone();
+            let inner = inner.0;
+            let method = NodeExpandVolumeSvc(inner);
+            let codec = tonic::codec::ProstCodec::default();
+            let mut grpc = if let Some(interceptor) = interceptor {
+              tonic::server::Grpc::with_interceptor(codec, interceptor)
+            } else {
+              tonic::server::Grpc::new(codec)
+            };
+            let res = grpc.unary(method, req).await;
+            Ok(res)
+          };
+          Box::pin(fut)
+        }
+        "/csi.v1.Node/NodeGetCapabilities" => {
+          #[allow(non_camel_case_types)]
+          struct NodeGetCapabilitiesSvc<T: Node>(pub Arc<T>);
+          impl<T: Node> tonic::server::UnaryService<super::NodeGetCapabilitiesRequest>
+            for NodeGetCapabilitiesSvc<T>
+          {
+            type Response = super::NodeGetCapabilitiesResponse;
+            type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+            fn call(
+              &mut self,
+              request: tonic::Request<super::NodeGetCapabilitiesRequest>,
+            ) -> Self::Future {
+              let inner = self.0.clone();
+              let fut = async move { (*inner).node_get_capabilities(request).await };
+              Box::pin(fut)
+            }
+          }
+          let inner = self.inner.clone();
+          let fut = async move {
+            let interceptor = inner.1.clone();
+            let inner = inner.0;
+            let method = NodeGetCapabilitiesSvc(inner);
+            let codec = tonic::codec::ProstCodec::default();
+            let mut grpc = if let Some(interceptor) = interceptor {
+              tonic::server::Grpc::with_interceptor(codec, interceptor)
+            } else {
+              tonic::server::Grpc::new(codec)
+            };
+            let res = grpc.unary(method, req).await;
+            Ok(res)
+          };
+          Box::pin(fut)
+        }
+        "/csi.v1.Node/NodeGetInfo" => {
+          #[allow(non_camel_case_types)]
+          struct NodeGetInfoSvc<T: Node>(pub Arc<T>);
+          impl<T: Node> tonic::server::UnaryService<super::NodeGetInfoRequest> for NodeGetInfoSvc<T> {
+            type Response = super::NodeGetInfoResponse;
+            type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+            fn call(&mut self, request: tonic::Request<super::NodeGetInfoRequest>) -> Self::Future {
+              let inner = self.0.clone();
+              let fut = async move { (*inner).node_get_info(request).await };
+              Box::pin(fut)
+            }
+          }
+          let inner = self.inner.clone();
+          let fut = async move {
+            let interceptor = inner.1.clone();
+            let inner = inner.0;
+            let method = NodeGetInfoSvc(inner);
+            let codec = tonic::codec::ProstCodec::default();
+            let mut grpc = if let Some(interceptor) = interceptor {
+              tonic::server::Grpc::with_interceptor(codec, interceptor)
+            } else {
+              tonic::server::Grpc::new(codec)
+            };
+            let res = grpc.unary(method, req).await;
+            Ok(res)
+          };
+          Box::pin(fut)
+        }
+        _ => Box::pin(async move {
+          Ok(
+            http::Response::builder()
+              .status(200)
+              .header("grpc-status", "12")
+              .header("content-type", "application/grpc")
+              .body(tonic::body::BoxBody::empty())
+              .unwrap(),
+          )
+        }),
+      }
+    }
+  }
+  impl<T: Node> Clone for NodeServer<T> {
+    fn clone(&self) -> Self {
+      let inner = self.inner.clone();
+      Self { inner }
+    }
+  }
+  impl<T: Node> Clone for _Inner<T> {
+    fn clone(&self) -> Self {
+      Self(self.0.clone(), self.1.clone())
+    }
+  }
+  impl<T: std::fmt::Debug> std::fmt::Debug for _Inner<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+      write!(f, "{:?}", self.0)
+    }
+  }
+  impl<T: Node> tonic::transport::NamedService for NodeServer<T> {
+    const NAME: &'static str = "csi.v1.Node";
+  }
+}
+#[cfg(feature = "alpha")]
+#[doc = r" Generated server implementations."]
+pub mod group_controller_server {
+  #![allow(unused_variables, dead_code, missing_docs)]
+  use tonic::codegen::*;
+  #[doc = "Generated trait containing gRPC methods that should be implemented for use with GroupControllerServer."]
+  #[async_trait]
+  pub trait GroupController: Send + Sync + 'static {
+    async fn group_controller_get_capabilities(
+      &self,
+      request: tonic::Request<super::GroupControllerGetCapabilitiesRequest>,
+    ) -> Result<tonic::Response<super::GroupControllerGetCapabilitiesResponse>, tonic::Status>;
+    async fn create_volume_group_snapshot(
+      &self,
+      request: tonic::Request<super::CreateVolumeGroupSnapshotRequest>,
+    ) -> Result<tonic::Response<super::CreateVolumeGroupSnapshotResponse>, tonic::Status>;
+    async fn delete_volume_group_snapshot(
+      &self,
+      request: tonic::Request<super::DeleteVolumeGroupSnapshotRequest>,
+    ) -> Result<tonic::Response<super::DeleteVolumeGroupSnapshotResponse>, tonic::Status>;
+    async fn get_volume_group_snapshot(
+      &self,
+      request: tonic::Request<super::GetVolumeGroupSnapshotRequest>,
+    ) -> Result<tonic::Response<super::GetVolumeGroupSnapshotResponse>, tonic::Status>;
+  }
+  #[derive(Debug)]
+  pub struct GroupControllerServer<T: GroupController> {
+    inner: _Inner<T>,
+  }
+  struct _Inner<T>(Arc<T>, Option<tonic::Interceptor>);
+  impl<T: GroupController> GroupControllerServer<T> {
+    pub fn new(inner: T) -> Self {
+      let inner = Arc::new(inner);
+      let inner = _Inner(inner, None);
+      Self { inner }
+    }
+    pub fn with_interceptor(inner: T, interceptor: impl Into<tonic::Interceptor>) -> Self {
+      let inner = Arc::new(inner);
+      let inner = _Inner(inner, Some(interceptor.into()));
+      Self { inner }
+    }
+  }
+  impl<T, B> Service<http::Request<B>> for GroupControllerServer<T>
+  where
+    T: GroupController,
+    B: HttpBody + Send + Sync + 'static,
+    B::Error: Into<StdError> + Send + 'static,
+  {
+    type Response = http::Response<tonic::body::BoxBody>;
+    type Error = Never;
+    type Future = BoxFuture<Self::Response, Self::Error>;
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+      Poll::Ready(Ok(()))
+    }
+    fn call(&mut self, req: http::Request<B>) -> Self::Future {
+      let inner = self.inner.clone();
+      match req.uri().path() {
+        "/csi.v1.GroupController/GroupControllerGetCapabilities" => {
+          #[allow(non_camel_case_types)]
+          struct GroupControllerGetCapabilitiesSvc<T: GroupController>(pub Arc<T>);
+          impl<T: GroupController>
+            tonic::server::UnaryService<super::GroupControllerGetCapabilitiesRequest>
+            for GroupControllerGetCapabilitiesSvc<T>
+          {
+            type Response = super::GroupControllerGetCapabilitiesResponse;
+            type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+            fn call(
+              &mut self,
+              request: tonic::Request<super::GroupControllerGetCapabilitiesRequest>,
+            ) -> Self::Future {
+              let inner = self.0.clone();
+              let fut = async move { (*inner).group_controller_get_capabilities(request).await };
+              Box::pin(fut)
+            }
+          }
+          let inner = self.inner.clone();
+          let fut = async move {
+            let interceptor = inner.1.clone();
+            let inner = inner.0;
+            let method = GroupControllerGetCapabilitiesSvc(inner);
+            let codec = tonic::codec::ProstCodec::default();
+            let mut grpc = if let Some(interceptor) = interceptor {
+              tonic::server::Grpc::with_interceptor(codec, interceptor)
+            } else {
+              tonic::server::Grpc::new(codec)
+            };
+            let res = grpc.unary(method, req).await;
+            Ok(res)
+          };
+          Box::pin(fut)
+        }
+        "/csi.v1.GroupController/CreateVolumeGroupSnapshot" => {
+          #[allow(non_camel_case_types)]
+          struct CreateVolumeGroupSnapshotSvc<T: GroupController>(pub Arc<T>);
+          impl<T: GroupController>
+            tonic::server::UnaryService<super::CreateVolumeGroupSnapshotRequest>
+            for CreateVolumeGroupSnapshotSvc<T>
+          {
+            type Response = super::CreateVolumeGroupSnapshotResponse;
+            type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+            fn call(
+              &mut self,
+              request: tonic::Request<super::CreateVolumeGroupSnapshotRequest>,
+            ) -> Self::Future {
+              let inner = self.0.clone();
+              let fut = async move { (*inner).create_volume_group_snapshot(request).await };
+              Box::pin(fut)
+            }
+          }
+          let inner = self.inner.clone();
+          let fut = async move {
+            let interceptor = inner.1.clone();
+            let inner = inner.0;
+            let method = CreateVolumeGroupSnapshotSvc(inner);
+            let codec = tonic::codec::ProstCodec::default();
+            let mut grpc = if let Some(interceptor) = interceptor {
+              tonic::server::Grpc::with_interceptor(codec, interceptor)
+            } else {
+              tonic::server::Grpc::new(codec)
+            };
+            let res = grpc.unary(method, req).await;
+            Ok(res)
+          };
+          Box::pin(fut)
+        }
+        "/csi.v1.GroupController/DeleteVolumeGroupSnapshot" => {
+          #[allow(non_camel_case_types)]
+          struct DeleteVolumeGroupSnapshotSvc<T: GroupController>(pub Arc<T>);
+          impl<T: GroupController>
+            tonic::server::UnaryService<super::DeleteVolumeGroupSnapshotRequest>
+            for DeleteVolumeGroupSnapshotSvc<T>
+          {
+            type Response = super::DeleteVolumeGroupSnapshotResponse;
+            type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+            fn call(
+              &mut self,
+              request: tonic::Request<super::DeleteVolumeGroupSnapshotRequest>,
+            ) -> Self::Future {
+              let inner = self.0.clone();
+              let fut = async move { (*inner).delete_volume_group_snapshot(request).await };
+              Box::pin(fut)
+            }
+          }
+          let inner = self.inner.clone();
+          let fut = async move {
+            let interceptor = inner.1.clone();
+            let inner = inner.0;
+            let method = DeleteVolumeGroupSnapshotSvc(inner);
+            let codec = tonic::codec::ProstCodec::default();
+            let mut grpc = if let Some(interceptor) = interceptor {
+              tonic::server::Grpc::with_interceptor(codec, interceptor)
+            } else {
+              tonic::server::Grpc::new(codec)
+            };
+            let res = grpc.unary(method, req).await;
+            Ok(res)
+          };
+          Box::pin(fut)
+        }
+        "/csi.v1.GroupController/GetVolumeGroupSnapshot" => {
+          #[allow(non_camel_case_types)]
+          struct GetVolumeGroupSnapshotSvc<T: GroupController>(pub Arc<T>);
+          impl<T: GroupController> tonic::server::UnaryService<super::GetVolumeGroupSnapshotRequest>
+            for GetVolumeGroupSnapshotSvc<T>
+          {
+            type Response = super::GetVolumeGroupSnapshotResponse;
+            type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+            fn call(
+              &mut self,
+              request: tonic::Request<super::GetVolumeGroupSnapshotRequest>,
+            ) -> Self::Future {
+              let inner = self.0.clone();
+              let fut = async move { (*inner).get_volume_group_snapshot(request).await };
+              Box::pin(fut)
+            }
+          }
+          let inner = self.inner.clone();
+          let fut = async move {
+            let interceptor = inner.1.clone();
+            let inner = inner.0;
+            let method = GetVolumeGroupSnapshotSvc(inner);
+            let codec = tonic::codec::ProstCodec::default();
+            let mut grpc = if let Some(interceptor) = interceptor {
+              tonic::server::Grpc::with_interceptor(codec, interceptor)
+            } else {
+              tonic::server::Grpc::new(codec)
+            };
+            let res = grpc.unary(method, req).await;
+            Ok(res)
+          };
+          Box::pin(fut)
+        }
+        _ => Box::pin(async move {
+          Ok(
+            http::Response::builder()
+              .status(200)
+              .header("grpc-status", "12")
+              .header("content-type", "application/grpc")
+              .body(tonic::body::BoxBody::empty())
+              .unwrap(),
+          )
+        }),
+      }
+    }
+  }
+  impl<T: GroupController> Clone for GroupControllerServer<T> {
+    fn clone(&self) -> Self {
+      let inner = self.inner.clone();
+      Self { inner }
+    }
+  }
+  impl<T: GroupController> Clone for _Inner<T> {
+    fn clone(&self) -> Self {
+      Self(self.0.clone(), self.1.clone())
+    }
+  }
+  impl<T: std::fmt::Debug> std::fmt::Debug for _Inner<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+      write!(f, "{:?}", self.0)
+    }
+  }
+  impl<T: GroupController> tonic::transport::NamedService for GroupControllerServer<T> {
+    const NAME: &'static str = "csi.v1.GroupController";
+  }
+}
+#[cfg(feature = "alpha")]
+#[doc = r" Generated server implementations."]
+pub mod snapshot_metadata_server {
+  #![allow(unused_variables, dead_code, missing_docs)]
+  use tonic::codegen::*;
+  #[doc = "Generated trait containing gRPC methods that should be implemented for use with SnapshotMetadataServer."]
+  #[async_trait]
+  pub trait SnapshotMetadata: Send + Sync + 'static {
+    #[doc = "Server streaming response type for the GetMetadataAllocated method."]
+    type GetMetadataAllocatedStream: Stream<Item = Result<super::GetMetadataAllocatedResponse, tonic::Status>>
+      + Send
+      + Sync
+      + 'static;
+    #[doc = " GetMetadataAllocated RPC returns metadata on the allocated blocks"]
+    #[doc = " of a snapshot: i.e. this identifies the data ranges that have valid"]
+    #[doc = " data as they were the target of some previous write operation on"]
+    #[doc = " the volume."]
+    async fn get_metadata_allocated(
+      &self,
+      request: tonic::Request<super::GetMetadataAllocatedRequest>,
+    ) -> Result<tonic::Response<Self::GetMetadataAllocatedStream>, tonic::Status>;
+    #[doc = "Server streaming response type for the GetMetadataDelta method."]
+    type GetMetadataDeltaStream: Stream<Item = Result<super::GetMetadataDeltaResponse, tonic::Status>>
+      + Send
+      + Sync
+      + 'static;
+    #[doc = " GetMetadataDelta RPC returns the metadata on the blocks that have"]
+    #[doc = " changed between two snapshots."]
+    async fn get_metadata_delta(
+      &self,
+      request: tonic::Request<super::GetMetadataDeltaRequest>,
+    ) -> Result<tonic::Response<Self::GetMetadataDeltaStream>, tonic::Status>;
+  }
+  #[derive(Debug)]
+  pub struct SnapshotMetadataServer<T: SnapshotMetadata> {
+    inner: _Inner<T>,
+  }
+  struct _Inner<T>(Arc<T>, Option<tonic::Interceptor>);
+  impl<T: SnapshotMetadata> SnapshotMetadataServer<T> {
+    pub fn new(inner: T) -> Self {
+      let inner = Arc::new(inner);
+      let inner = _Inner(inner, None);
+      Self { inner }
+    }
+    pub fn with_interceptor(inner: T, interceptor: impl Into<tonic::Interceptor>) -> Self {
+      let inner = Arc::new(inner);
+      let inner = _Inner(inner, Some(interceptor.into()));
+      Self { inner }
+    }
+  }
+  impl<T, B> Service<http::Request<B>> for SnapshotMetadataServer<T>
+  where
+    T: SnapshotMetadata,
+    B: HttpBody + Send + Sync + 'static,
+    B::Error: Into<StdError> + Send + 'static,
+  {
+    type Response = http::Response<tonic::body::BoxBody>;
+    type Error = Never;
+    type Future = BoxFuture<Self::Response, Self::Error>;
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+      Poll::Ready(Ok(()))
+    }
+    fn call(&mut self, req: http::Request<B>) -> Self::Future {
+      let inner = self.inner.clone();
+      match req.uri().path() {
+        "/csi.v1.SnapshotMetadata/GetMetadataAllocated" => {
+          #[allow(non_camel_case_types)]
+          struct GetMetadataAllocatedSvc<T: SnapshotMetadata>(pub Arc<T>);
+          impl<T: SnapshotMetadata>
+            tonic::server::ServerStreamingService<super::GetMetadataAllocatedRequest>
+            for GetMetadataAllocatedSvc<T>
+          {
+            type Response = super::GetMetadataAllocatedResponse;
+            type ResponseStream = T::GetMetadataAllocatedStream;
+            type Future = BoxFuture<tonic::Response<Self::ResponseStream>, tonic::Status>;
+            fn call(
+              &mut self,
+              request: tonic::Request<super::GetMetadataAllocatedRequest>,
+            ) -> Self::Future {
+              let inner = self.0.clone();
+              let fut = async move { (*inner).get_metadata_allocated(request).await };
+              Box::pin(fut)
+            }
+          }
+          let inner = self.inner.clone();
+          let fut = async move {
+            let interceptor = inner.1;
+            let inner = inner.0;
+            let method = GetMetadataAllocatedSvc(inner);
+            let codec = tonic::codec::ProstCodec::default();
+            let mut grpc = if let Some(interceptor) = interceptor {
+              tonic::server::Grpc::with_interceptor(codec, interceptor)
+            } else {
+              tonic::server::Grpc::new(codec)
+            };
+            let res = grpc.server_streaming(method, req).await;
+            Ok(res)
+          };
+          Box::pin(fut)
+        }
+        "/csi.v1.SnapshotMetadata/GetMetadataDelta" => {
+          #[allow(non_camel_case_types)]
+          struct GetMetadataDeltaSvc<T: SnapshotMetadata>(pub Arc<T>);
+          impl<T: SnapshotMetadata>
+            tonic::server::ServerStreamingService<super::GetMetadataDeltaRequest>
+            for GetMetadataDeltaSvc<T>
+          {
+            type Response = super::GetMetadataDeltaResponse;
+            type ResponseStream = T::GetMetadataDeltaStream;
+            type Future = BoxFuture<tonic::Response<Self::ResponseStream>, tonic::Status>;
+            fn call(
+              &mut self,
+              request: tonic::Request<super::GetMetadataDeltaRequest>,
+            ) -> Self::Future {
+              let inner = self.0.clone();
+              let fut = async move { (*inner).get_metadata_delta(request).await };
+              Box::pin(fut)
+            }
+          }
+          let inner = self.inner.clone();
+          let fut = async move {
+            let interceptor = inner.1;
+            let inner = inner.0;
+            let method = GetMetadataDeltaSvc(inner);
+            let codec = tonic::codec::ProstCodec::default();
+            let mut grpc = if let Some(interceptor) = interceptor {
+              tonic::server::Grpc::with_interceptor(codec, interceptor)
+            } else {
+              tonic::server::Grpc::new(codec)
+            };
+            let res = grpc.server_streaming(method, req).await;
+            Ok(res)
+          };
+          Box::pin(fut)
+        }
+        _ => Box::pin(async move {
+          Ok(
+            http::Response::builder()
+              .status(200)
+              .header("grpc-status", "12")
+              .header("content-type", "application/grpc")
+              .body(tonic::body::BoxBody::empty())
+              .unwrap(),
+          )
+        }),
+      }
+    }
+  }
+  impl<T: SnapshotMetadata> Clone for SnapshotMetadataServer<T> {
+    fn clone(&self) -> Self {
+      let inner = self.inner.clone();
+      Self { inner }
+    }
+  }
+  impl<T: SnapshotMetadata> Clone for _Inner<T> {
+    fn clone(&self) -> Self {
+      Self(self.0.clone(), self.1.clone())
+    }
+  }
+  impl<T: std::fmt::Debug> std::fmt::Debug for _Inner<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+      write!(f, "{:?}", self.0)
+    }
+  }
+  impl<T: SnapshotMetadata> tonic::transport::NamedService for SnapshotMetadataServer<T> {
+    const NAME: &'static str = "csi.v1.SnapshotMetadata";
+  }
+}
+
+impl ::prost::Message for ControllerExpandVolumeRequest {
+  fn encode_raw<B>(&self, buf: &mut B)
+  where
+    B: ::prost::bytes::BufMut,
+  {
+    if !self.volume_id.is_empty() {
+      ::prost::encoding::string::encode(1, &self.volume_id, buf);
+    }
+    if let Some(ref msg) = self.capacity_range {
+      ::prost::encoding::message::encode(2, msg, buf);
+    }
+    ::prost::encoding::hash_map::encode(
+      ::prost::encoding::string::encode,
+      ::prost::encoding::string::encoded_len,
+      ::prost::encoding::string::encode,
+      ::prost::encoding::string::encoded_len,
+      3,
+      &self.secrets,
+      buf,
+    );
+    if let Some(ref msg) = self.volume_capability {
+      ::prost::encoding::message::encode(4, msg, buf);
+    }
+  }
+
+  fn merge_field<B>(
+    &mut self,
+    tag: u32,
+    wire_type: ::prost::encoding::WireType,
+    buf: &mut B,
+    ctx: ::prost::encoding::DecodeContext,
+  ) -> ::core::result::Result<(), ::prost::DecodeError>
+  where
+    B: ::prost::bytes::Buf,
+  {
+    const STRUCT_NAME: &str = "ControllerExpandVolumeRequest";
+    match tag {
+      1 => ::prost::encoding::string::merge(wire_type, &mut self.volume_id, buf, ctx).map_err(
+        |mut error| {
+          error.push(STRUCT_NAME, "volume_id");
+          error
+        },
+      ),
+      2 => ::prost::encoding::message::merge(
+        wire_type,
+        self.capacity_range.get_or_insert_with(Default::default),
+        buf,
+        ctx,
+      )
+      .map_err(|mut error| {
+        error.push(STRUCT_NAME, "capacity_range");
+        error
+      }),
+      3 => ::prost::encoding::hash_map::merge(
+        ::prost::encoding::string::merge,
+        ::prost::encoding::string::merge,
+        &mut self.secrets,
+        buf,
+        ctx,
+      )
+      .map_err(|mut error| {
+        error.push(STRUCT_NAME, "secrets");
+        error
+      }),
+      4 => ::prost::encoding::message::merge(
+        wire_type,
+        self.volume_capability.get_or_insert_with(Default::default),
+        buf,
+        ctx,
+      )
+      .map_err(|mut error| {
+        error.push(STRUCT_NAME, "volume_capability");
+        error
+      }),
+
+      _ => ::prost::encoding::skip_field(wire_type, tag, buf, ctx),
+    }
+  }
+
+  fn encoded_len(&self) -> usize {
+    let mut len = 0;
+    len += if !self.volume_id.is_empty() {
+      ::prost::encoding::string::encoded_len(1, &self.volume_id)
+    } else {
+      0
+    };
+    len += self
+      .capacity_range
+      .as_ref()
+      .map_or(0, |msg| ::prost::encoding::message::encoded_len(2, msg));
+    len += ::prost::encoding::hash_map::encoded_len(
+      ::prost::encoding::string::encoded_len,
+      ::prost::encoding::string::encoded_len,
+      3,
+      &self.secrets,
+    );
+    len += self
+      .volume_capability
+      .as_ref()
+      .map_or(0, |msg| ::prost::encoding::message::encoded_len(4, msg));
+
+    len
+  }
+
+  fn clear(&mut self) {
+    self.volume_id = ::core::default::Default::default();
+    self.capacity_range = ::core::option::Option::None;
+    self.secrets.clear();
+    self.volume_capability = ::core::option::Option::None;
+  }
+}
+
+/// The fields marked with the `csi_secret` option are redacted.
+impl ::core::fmt::Debug for ControllerExpandVolumeRequest {
+  fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+    let mut builder = f.debug_struct("ControllerExpandVolumeRequest");
+    builder.field("volume_id", &self.volume_id);
+    builder.field("capacity_range", &self.capacity_range);
+    builder.field(
+      "secrets",
+      &self
+        .secrets
+        .keys()
+        .map(|k| (k, "SECRET"))
+        .collect::<::std::collections::BTreeMap<_, _>>(),
+    );
+    builder.field("volume_capability", &self.volume_capability);
+
+    builder.finish()
+  }
+}
+
+impl ::prost::Message for ControllerPublishVolumeRequest {
+  fn encode_raw<B>(&self, buf: &mut B)
+  where
+    B: ::prost::bytes::BufMut,
+  {
+    if !self.volume_id.is_empty() {
+      ::prost::encoding::string::encode(1, &self.volume_id, buf);
+    }
+    if !self.node_id.is_empty() {
+      ::prost::encoding::string::encode(2, &self.node_id, buf);
+    }
+    if let Some(ref msg) = self.volume_capability {
+      ::prost::encoding::message::encode(3, msg, buf);
+    }
+    if self.readonly {
+      ::prost::encoding::bool::encode(4, &self.readonly, buf);
+    }
+    ::prost::encoding::hash_map::encode(
+      ::prost::encoding::string::encode,
+      ::prost::encoding::string::encoded_len,
+      ::prost::encoding::string::encode,
+      ::prost::encoding::string::encoded_len,
+      5,
+      &self.secrets,
+      buf,
+    );
+    ::prost::encoding::hash_map::encode(
+      ::prost::encoding::string::encode,
+      ::prost::encoding::string::encoded_len,
+      ::prost::encoding::string::encode,
+      ::prost::encoding::string::encoded_len,
+      6,
+      &self.volume_context,
+      buf,
+    );
+  }
+
+  fn merge_field<B>(
+    &mut self,
+    tag: u32,
+    wire_type: ::prost::encoding::WireType,
+    buf: &mut B,
+    ctx: ::prost::encoding::DecodeContext,
+  ) -> ::core::result::Result<(), ::prost::DecodeError>
+  where
+    B: ::prost::bytes::Buf,
+  {
+    const STRUCT_NAME: &str = "ControllerPublishVolumeRequest";
+    match tag {
+      1 => ::prost::encoding::string::merge(wire_type, &mut self.volume_id, buf, ctx).map_err(
+        |mut error| {
+          error.push(STRUCT_NAME, "volume_id");
+          error
+        },
+      ),
+      2 => ::prost::encoding::string::merge(wire_type, &mut self.node_id, buf, ctx).map_err(
+        |mut error| {
+          error.push(STRUCT_NAME, "node_id");
+          error
+        },
+      ),
+      3 => ::prost::encoding::message::merge(
+        wire_type,
+        self.volume_capability.get_or_insert_with(Default::default),
+        buf,
+        ctx,
+      )
+      .map_err(|mut error| {
+        error.push(STRUCT_NAME, "volume_capability");
+        error
+      }),
+      4 => ::prost::encoding::bool::merge(wire_type, &mut self.readonly, buf, ctx).map_err(
+        |mut error| {
+          error.push(STRUCT_NAME, "readonly");
+          error
+        },
+      ),
+      5 => ::prost::encoding::hash_map::merge(
+        ::prost::encoding::string::merge,
+        ::prost::encoding::string::merge,
+        &mut self.secrets,
+        buf,
+        ctx,
+      )
+      .map_err(|mut error| {
+        error.push(STRUCT_NAME, "secrets");
+        error
+      }),
+      6 => ::prost::encoding::hash_map::merge(
+        ::prost::encoding::string::merge,
+        ::prost::encoding::string::merge,
+        &mut self.volume_context,
+        buf,
+        ctx,
+      )
+      .map_err(|mut error| {
+        error.push(STRUCT_NAME, "volume_context");
+        error
+      }),
+
+      _ => ::prost::encoding::skip_field(wire_type, tag, buf, ctx),
+    }
+  }
+
+  fn encoded_len(&self) -> usize {
+    let mut len = 0;
+    len += if !self.volume_id.is_empty() {
+      ::prost::encoding::string::encoded_len(1, &self.volume_id)
+    } else {
+      0
+    };
+    len += if !self.node_id.is_empty() {
+      ::prost::encoding::string::encoded_len(2, &self.node_id)
+    } else {
+      0
+    };
+    len += self
+      .volume_capability
+      .as_ref()
+      .map_or(0, |msg| ::prost::encoding::message::encoded_len(3, msg));
+    len += if self.readonly {
+      ::prost::encoding::bool::encoded_len(4, &self.readonly)
+    } else {
+      0
+    };
+    len += ::prost::encoding::hash_map::encoded_len(
+      ::prost::encoding::string::encoded_len,
+      ::prost::encoding::string::encoded_len,
+      5,
+      &self.secrets,
+    );
+    len += ::prost::encoding::hash_map::encoded_len(
+      ::prost::encoding::string::encoded_len,
+      ::prost::encoding::string::encoded_len,
+      6,
+      &self.volume_context,
+    );
+
+    len
+  }
+
+  fn clear(&mut self) {
+    self.volume_id = ::core::default::Default::default();
+    self.node_id = ::core::default::Default::default();
+    self.volume_capability = ::core::option::Option::None;
+    self.readonly = ::core::default::Default::default();
+    self.secrets.clear();
+    self.volume_context.clear();
+  }
+}
+
+/// The fields marked with the `csi_secret` option are redacted.
+impl ::core::fmt::Debug for ControllerPublishVolumeRequest {
+  fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+    let mut builder = f.debug_struct("ControllerPublishVolumeRequest");
+    builder.field("volume_id", &self.volume_id);
+    builder.field("node_id", &self.node_id);
+    builder.field("volume_capability", &self.volume_capability);
+    builder.field("readonly", &self.readonly);
+    builder.field(
+      "secrets",
+      &self
+        .secrets
+        .keys()
+        .map(|k| (k, "SECRET"))
+        .collect::<::std::collections::BTreeMap<_, _>>(),
+    );
+    builder.field("volume_context", &self.volume_context);
+
+    builder.finish()
+  }
+}
+
+impl ::prost::Message for ControllerUnpublishVolumeRequest {
+  fn encode_raw<B>(&self, buf: &mut B)
+  where
+    B: ::prost::bytes::BufMut,
+  {
+    if !self.volume_id.is_empty() {
+      ::prost::encoding::string::encode(1, &self.volume_id, buf);
+    }
+    if !self.node_id.is_empty() {
+      ::prost::encoding::string::encode(2, &self.node_id, buf);
+    }
+    ::prost::encoding::hash_map::encode(
+      ::prost::encoding::string::encode,
+      ::prost::encoding::string::encoded_len,
+      ::prost::encoding::string::encode,
+      ::prost::encoding::string::encoded_len,
+      3,
+      &self.secrets,
+      buf,
+    );
+  }
+
+  fn merge_field<B>(
+    &mut self,
+    tag: u32,
+    wire_type: ::prost::encoding::WireType,
+    buf: &mut B,
+    ctx: ::prost::encoding::DecodeContext,
+  ) -> ::core::result::Result<(), ::prost::DecodeError>
+  where
+    B: ::prost::bytes::Buf,
+  {
+    const STRUCT_NAME: &str = "ControllerUnpublishVolumeRequest";
+    match tag {
+      1 => ::prost::encoding::string::merge(wire_type, &mut self.volume_id, buf, ctx).map_err(
+        |mut error| {
+          error.push(STRUCT_NAME, "volume_id");
+          error
+        },
+      ),
+      2 => ::prost::encoding::string::merge(wire_type, &mut self.node_id, buf, ctx).map_err(
+        |mut error| {
+          error.push(STRUCT_NAME, "node_id");
+          error
+        },
+      ),
+      3 => ::prost::encoding::hash_map::merge(
+        ::prost::encoding::string::merge,
+        ::prost::encoding::string::merge,
+        &mut self.secrets,
+        buf,
+        ctx,
+      )
+      .map_err(|mut error| {
+        error.push(STRUCT_NAME, "secrets");
+        error
+      }),
+
+      _ => ::prost::encoding::skip_field(wire_type, tag, buf, ctx),
+    }
+  }
+
+  fn encoded_len(&self) -> usize {
+    let mut len = 0;
+    len += if !self.volume_id.is_empty() {
+      ::prost::encoding::string::encoded_len(1, &self.volume_id)
+    } else {
+      0
+    };
+    len += if !self.node_id.is_empty() {
+      ::prost::encoding::string::encoded_len(2, &self.node_id)
+    } else {
+      0
+    };
+    len += ::prost::encoding::hash_map::encoded_len(
+      ::prost::encoding::string::encoded_len,
+      ::prost::encoding::string::encoded_len,
+      3,
+      &self.secrets,
+    );
+
+    len
+  }
+
+  fn clear(&mut self) {
+    self.volume_id = ::core::default::Default::default();
+    self.node_id = ::core::default::Default::default();
+    self.secrets.clear();
+  }
+}
+
+/// The fields marked with the `csi_secret` option are redacted.
+impl ::core::fmt::Debug for ControllerUnpublishVolumeRequest {
+  fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+    let mut builder = f.debug_struct("ControllerUnpublishVolumeRequest");
+    builder.field("volume_id", &self.volume_id);
+    builder.field("node_id", &self.node_id);
+    builder.field(
+      "secrets",
+      &self
+        .secrets
+        .keys()
+        .map(|k| (k, "SECRET"))
+        .collect::<::std::collections::BTreeMap<_, _>>(),
+    );
+
+    builder.finish()
+  }
+}
+
+impl ::prost::Message for CreateSnapshotRequest {
+  fn encode_raw<B>(&self, buf: &mut B)
+  where
+    B: ::prost::bytes::BufMut,
+  {
+    if !self.source_volume_id.is_empty() {
+      ::prost::encoding::string::encode(1, &self.source_volume_id, buf);
+    }
+    if !self.name.is_empty() {
+      ::prost::encoding::string::encode(2, &self.name, buf);
+    }
+    ::prost::encoding::hash_map::encode(
+      ::prost::encoding::string::encode,
+      ::prost::encoding::string::encoded_len,
+      ::prost::encoding::string::encode,
+      ::prost::encoding::string::encoded_len,
+      3,
+      &self.secrets,
+      buf,
+    );
+    ::prost::encoding::hash_map::encode(
+      ::prost::encoding::string::encode,
+      ::prost::encoding::string::encoded_len,
+      ::prost::encoding::string::encode,
+      ::prost::encoding::string::encoded_len,
+      4,
+      &self.parameters,
+      buf,
+    );
+  }
+
+  fn merge_field<B>(
+    &mut self,
+    tag: u32,
+    wire_type: ::prost::encoding::WireType,
+    buf: &mut B,
+    ctx: ::prost::encoding::DecodeContext,
+  ) -> ::core::result::Result<(), ::prost::DecodeError>
+  where
+    B: ::prost::bytes::Buf,
+  {
+    const STRUCT_NAME: &str = "CreateSnapshotRequest";
+    match tag {
+      1 => ::prost::encoding::string::merge(wire_type, &mut self.source_volume_id, buf, ctx)
+        .map_err(|mut error| {
+          error.push(STRUCT_NAME, "source_volume_id");
+          error
+        }),
+      2 => ::prost::encoding::string::merge(wire_type, &mut self.name, buf, ctx).map_err(
+        |mut error| {
+          error.push(STRUCT_NAME, "name");
+          error
+        },
+      ),
+      3 => ::prost::encoding::hash_map::merge(
+        ::prost::encoding::string::merge,
+        ::prost::encoding::string::merge,
+        &mut self.secrets,
+        buf,
+        ctx,
+      )
+      .map_err(|mut error| {
+        error.push(STRUCT_NAME, "secrets");
+        error
+      }),
+      4 => ::prost::encoding::hash_map::merge(
+        ::prost::encoding::string::merge,
+        ::prost::encoding::string::merge,
+        &mut self.parameters,
+        buf,
+        ctx,
+      )
+      .map_err(|mut error| {
+        error.push(STRUCT_NAME, "parameters");
+        error
+      }),
+
+      _ => ::prost::encoding::skip_field(wire_type, tag, buf, ctx),
+    }
+  }
+
+  fn encoded_len(&self) -> usize {
+    let mut len = 0;
+    len += if !self.source_volume_id.is_empty() {
+      ::prost::encoding::string::encoded_len(1, &self.source_volume_id)
+    } else {
+      0
+    };
+    len += if !self.name.is_empty() {
+      ::prost::encoding::string::encoded_len(2, &self.name)
+    } else {
+      0
+    };
+    len += ::prost::encoding::hash_map::encoded_len(
+      ::prost::encoding::string::encoded_len,
+      ::prost::encoding::string::encoded_len,
+      3,
+      &self.secrets,
+    );
+    len += ::prost::encoding::hash_map::encoded_len(
+      ::prost::encoding::string::encoded_len,
+      ::prost::encoding::string::encoded_len,
+      4,
+      &self.parameters,
+    );
+
+    len
+  }
+
+  fn clear(&mut self) {
+    self.source_volume_id = ::core::default::Default::default();
+    self.name = ::core::default::Default::default();
+    self.secrets.clear();
+    self.parameters.clear();
+  }
+}
+
+/// The fields marked with the `csi_secret` option are redacted.
+impl ::core::fmt::Debug for CreateSnapshotRequest {
+  fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+    let mut builder = f.debug_struct("CreateSnapshotRequest");
+    builder.field("source_volume_id", &self.source_volume_id);
+    builder.field("name", &self.name);
+    builder.field(
+      "secrets",
+      &self
+        .secrets
+        .keys()
+        .map(|k| (k, "SECRET"))
+        .collect::<::std::collections::BTreeMap<_, _>>(),
+    );
+    builder.field("parameters", &self.parameters);
+
+    builder.finish()
+  }
+}
+
+#[cfg(feature = "alpha")]
+impl ::prost::Message for CreateVolumeGroupSnapshotRequest {
+  fn encode_raw<B>(&self, buf: &mut B)
+  where
+    B: ::prost::bytes::BufMut,
+  {
+    if !self.name.is_empty() {
+      ::prost::encoding::string::encode(1, &self.name, buf);
+    }
+    ::prost::encoding::string::encode_repeated(2, &self.source_volume_ids, buf);
+    ::prost::encoding::hash_map::encode(
+      ::prost::encoding::string::encode,
+      ::prost::encoding::string::encoded_len,
+      ::prost::encoding::string::encode,
+      ::prost::encoding::string::encoded_len,
+      3,
+      &self.secrets,
+      buf,
+    );
+    ::prost::encoding::hash_map::encode(
+      ::prost::encoding::string::encode,
+      ::prost::encoding::string::encoded_len,
+      ::prost::encoding::string::encode,
+      ::prost::encoding::string::encoded_len,
+      4,
+      &self.parameters,
+      buf,
+    );
+  }
+
+  fn merge_field<B>(
+    &mut self,
+    tag: u32,
+    wire_type: ::prost::encoding::WireType,
+    buf: &mut B,
+    ctx: ::prost::encoding::DecodeContext,
+  ) -> ::core::result::Result<(), ::prost::DecodeError>
+  where
+    B: ::prost::bytes::Buf,
+  {
+    const STRUCT_NAME: &str = "CreateVolumeGroupSnapshotRequest";
+    match tag {
+      1 => ::prost::encoding::string::merge(wire_type, &mut self.name, buf, ctx).map_err(
+        |mut error| {
+          error.push(STRUCT_NAME, "name");
+          error
+        },
+      ),
+      2 => {
+        ::prost::encoding::string::merge_repeated(wire_type, &mut self.source_volume_ids, buf, ctx)
+          .map_err(|mut error| {
+            error.push(STRUCT_NAME, "source_volume_ids");
+            error
+          })
+      }
+      3 => ::prost::encoding::hash_map::merge(
+        ::prost::encoding::string::merge,
+        ::prost::encoding::string::merge,
+        &mut self.secrets,
+        buf,
+        ctx,
+      )
+      .map_err(|mut error| {
+        error.push(STRUCT_NAME, "secrets");
+        error
+      }),
+      4 => ::prost::encoding::hash_map::merge(
+        ::prost::encoding::string::merge,
+        ::prost::encoding::string::merge,
+        &mut self.parameters,
+        buf,
+        ctx,
+      )
+      .map_err(|mut error| {
+        error.push(STRUCT_NAME, "parameters");
+        error
+      }),
+
+      _ => ::prost::encoding::skip_field(wire_type, tag, buf, ctx),
+    }
+  }
+
+  fn encoded_len(&self) -> usize {
+    let mut len = 0;
+    len += if !self.name.is_empty() {
+      ::prost::encoding::string::encoded_len(1, &self.name)
+    } else {
+      0
+    };
+    len += ::prost::encoding::string::encoded_len_repeated(2, &self.source_volume_ids);
+    len += ::prost::encoding::hash_map::encoded_len(
+      ::prost::encoding::string::encoded_len,
+      ::prost::encoding::string::encoded_len,
+      3,
+      &self.secrets,
+    );
+    len += ::prost::encoding::hash_map::encoded_len(
+      ::prost::encoding::string::encoded_len,
+      ::prost::encoding::string::encoded_len,
+      4,
+      &self.parameters,
+    );
+
+    len
+  }
+
+  fn clear(&mut self) {
+    self.name = ::core::default::Default::default();
+    self.source_volume_ids.clear();
+    self.secrets.clear();
+    self.parameters.clear();
+  }
+}
+
+/// The fields marked with the `csi_secret` option are redacted.
+#[cfg(feature = "alpha")]
+impl ::core::fmt::Debug for CreateVolumeGroupSnapshotRequest {
+  fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+    let mut builder = f.debug_struct("CreateVolumeGroupSnapshotRequest");
+    builder.field("name", &self.name);
+    builder.field("source_volume_ids", &self.source_volume_ids);
+    builder.field(
+      "secrets",
+      &self
+        .secrets
+        .keys()
+        .map(|k| (k, "SECRET"))
+        .collect::<::std::collections::BTreeMap<_, _>>(),
+    );
+    builder.field("parameters", &self.parameters);
+
+    builder.finish()
+  }
+}
+
+impl ::prost::Message for CreateVolumeRequest {
+  fn encode_raw<B>(&self, buf: &mut B)
+  where
+    B: ::prost::bytes::BufMut,
+  {
+    if !self.name.is_empty() {
+      ::prost::encoding::string::encode(1, &self.name, buf);
+    }
+    if let Some(ref msg) = self.capacity_range {
+      ::prost::encoding::message::encode(2, msg, buf);
+    }
+    for msg in &self.volume_capabilities {
+      ::prost::encoding::message::encode(3, msg, buf);
+    }
+    ::prost::encoding::hash_map::encode(
+      ::prost::encoding::string::encode,
+      ::prost::encoding::string::encoded_len,
+      ::prost::encoding::string::encode,
+      ::prost::encoding::string::encoded_len,
+      4,
+      &self.parameters,
+      buf,
+    );
+    ::prost::encoding::hash_map::encode(
+      ::prost::encoding::string::encode,
+      ::prost::encoding::string::encoded_len,
+      ::prost::encoding::string::encode,
+      ::prost::encoding::string::encoded_len,
+      5,
+      &self.secrets,
+      buf,
+    );
+    if let Some(ref msg) = self.volume_content_source {
+      ::prost::encoding::message::encode(6, msg, buf);
+    }
+    if let Some(ref msg) = self.accessibility_requirements {
+      ::prost::encoding::message::encode(7, msg, buf);
+    }
+  }
+
+  fn merge_field<B>(
+    &mut self,
+    tag: u32,
+    wire_type: ::prost::encoding::WireType,
+    buf: &mut B,
+    ctx: ::prost::encoding::DecodeContext,
+  ) -> ::core::result::Result<(), ::prost::DecodeError>
+  where
+    B: ::prost::bytes::Buf,
+  {
+    const STRUCT_NAME: &str = "CreateVolumeRequest";
+    match tag {
+      1 => ::prost::encoding::string::merge(wire_type, &mut self.name, buf, ctx).map_err(
+        |mut error| {
+          error.push(STRUCT_NAME, "name");
+          error
+        },
+      ),
+      2 => ::prost::encoding::message::merge(
+        wire_type,
+        self.capacity_range.get_or_insert_with(Default::default),
+        buf,
+        ctx,
+      )
+      .map_err(|mut error| {
+        error.push(STRUCT_NAME, "capacity_range");
+        error
+      }),
+      3 => ::prost::encoding::message::merge_repeated(
+        wire_type,
+        &mut self.volume_capabilities,
+        buf,
+        ctx,
+      )
+      .map_err(|mut error| {
+        error.push(STRUCT_NAME, "volume_capabilities");
+        error
+      }),
+      4 => ::prost::encoding::hash_map::merge(
+        ::prost::encoding::string::merge,
+        ::prost::encoding::string::merge,
+        &mut self.parameters,
+        buf,
+        ctx,
+      )
+      .map_err(|mut error| {
+        error.push(STRUCT_NAME, "parameters");
+        error
+      }),
+      5 => ::prost::encoding::hash_map::merge(
+        ::prost::encoding::string::merge,
+        ::prost::encoding::string::merge,
+        &mut self.secrets,
+        buf,
+        ctx,
+      )
+      .map_err(|mut error| {
+        error.push(STRUCT_NAME, "secrets");
+        error
+      }),
+      6 => ::prost::encoding::message::merge(
+        wire_type,
+        self
+          .volume_content_source
+          .get_or_insert_with(Default::default),
+        buf,
+        ctx,
+      )
+      .map_err(|mut error| {
+        error.push(STRUCT_NAME, "volume_content_source");
+        error
+      }),
+      7 => ::prost::encoding::message::merge(
+        wire_type,
+        self
+          .accessibility_requirements
+          .get_or_insert_with(Default::default),
+        buf,
+        ctx,
+      )
+      .map_err(|mut error| {
+        error.push(STRUCT_NAME, "accessibility_requirements");
+        error
+      }),
+
+      _ => ::prost::encoding::skip_field(wire_type, tag, buf, ctx),
+    }
+  }
+
+  fn encoded_len(&self) -> usize {
+    let mut len = 0;
+    len += if !self.name.is_empty() {
+      ::prost::encoding::string::encoded_len(1, &self.name)
+    } else {
+      0
+    };
+    len += self
+      .capacity_range
+      .as_ref()
+      .map_or(0, |msg| ::prost::encoding::message::encoded_len(2, msg));
+    len += ::prost::encoding::message::encoded_len_repeated(3, &self.volume_capabilities);
+    len += ::prost::encoding::hash_map::encoded_len(
+      ::prost::encoding::string::encoded_len,
+      ::prost::encoding::string::encoded_len,
+      4,
+      &self.parameters,
+    );
+    len += ::prost::encoding::hash_map::encoded_len(
+      ::prost::encoding::string::encoded_len,
+      ::prost::encoding::string::encoded_len,
+      5,
+      &self.secrets,
+    );
+    len += self
+      .volume_content_source
+      .as_ref()
+      .map_or(0, |msg| ::prost::encoding::message::encoded_len(6, msg));
+    len += self
+      .accessibility_requirements
+      .as_ref()
+      .map_or(0, |msg| ::prost::encoding::message::encoded_len(7, msg));
+
+    len
+  }
+
+  fn clear(&mut self) {
+    self.name = ::core::default::Default::default();
+    self.capacity_range = ::core::option::Option::None;
+    self.volume_capabilities.clear();
+    self.parameters.clear();
+    self.secrets.clear();
+    self.volume_content_source = ::core::option::Option::None;
+    self.accessibility_requirements = ::core::option::Option::None;
+  }
+}
+
+/// The fields marked with the `csi_secret` option are redacted.
+impl ::core::fmt::Debug for CreateVolumeRequest {
+  fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+    let mut builder = f.debug_struct("CreateVolumeRequest");
+    builder.field("name", &self.name);
+    builder.field("capacity_range", &self.capacity_range);
+    builder.field("volume_capabilities", &self.volume_capabilities);
+    builder.field("parameters", &self.parameters);
+    builder.field(
+      "secrets",
+      &self
+        .secrets
+        .keys()
+        .map(|k| (k, "SECRET"))
+        .collect::<::std::collections::BTreeMap<_, _>>(),
+    );
+    builder.field("volume_content_source", &self.volume_content_source);
+    builder.field(
+      "accessibility_requirements",
+      &self.accessibility_requirements,
+    );
+
+    builder.finish()
+  }
+}
+
+impl ::prost::Message for DeleteSnapshotRequest {
+  fn encode_raw<B>(&self, buf: &mut B)
+  where
+    B: ::prost::bytes::BufMut,
+  {
+    if !self.snapshot_id.is_empty() {
+      ::prost::encoding::string::encode(1, &self.snapshot_id, buf);
+    }
+    ::prost::encoding::hash_map::encode(
+      ::prost::encoding::string::encode,
+      ::prost::encoding::string::encoded_len,
+      ::prost::encoding::string::encode,
+      ::prost::encoding::string::encoded_len,
+      2,
+      &self.secrets,
+      buf,
+    );
+  }
+
+  fn merge_field<B>(
+    &mut self,
+    tag: u32,
+    wire_type: ::prost::encoding::WireType,
+    buf: &mut B,
+    ctx: ::prost::encoding::DecodeContext,
+  ) -> ::core::result::Result<(), ::prost::DecodeError>
+  where
+    B: ::prost::bytes::Buf,
+  {
+    const STRUCT_NAME: &str = "DeleteSnapshotRequest";
+    match tag {
+      1 => ::prost::encoding::string::merge(wire_type, &mut self.snapshot_id, buf, ctx).map_err(
+        |mut error| {
+          error.push(STRUCT_NAME, "snapshot_id");
+          error
+        },
+      ),
+      2 => ::prost::encoding::hash_map::merge(
+        ::prost::encoding::string::merge,
+        ::prost::encoding::string::merge,
+        &mut self.secrets,
+        buf,
+        ctx,
+      )
+      .map_err(|mut error| {
+        error.push(STRUCT_NAME, "secrets");
+        error
+      }),
+
+      _ => ::prost::encoding::skip_field(wire_type, tag, buf, ctx),
+    }
+  }
+
+  fn encoded_len(&self) -> usize {
+    let mut len = 0;
+    len += if !self.snapshot_id.is_empty() {
+      ::prost::encoding::string::encoded_len(1, &self.snapshot_id)
+    } else {
+      0
+    };
+    len += ::prost::encoding::hash_map::encoded_len(
+      ::prost::encoding::string::encoded_len,
+      ::prost::encoding::string::encoded_len,
+      2,
+      &self.secrets,
+    );
+
+    len
+  }
+
+  fn clear(&mut self) {
+    self.snapshot_id = ::core::default::Default::default();
+    self.secrets.clear();
+  }
+}
+
+/// The fields marked with the `csi_secret` option are redacted.
+impl ::core::fmt::Debug for DeleteSnapshotRequest {
+  fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+    let mut builder = f.debug_struct("DeleteSnapshotRequest");
+    builder.field("snapshot_id", &self.snapshot_id);
+    builder.field(
+      "secrets",
+      &self
+        .secrets
+        .keys()
+        .map(|k| (k, "SECRET"))
+        .collect::<::std::collections::BTreeMap<_, _>>(),
+    );
+
+    builder.finish()
+  }
+}
+
+#[cfg(feature = "alpha")]
+impl ::prost::Message for DeleteVolumeGroupSnapshotRequest {
+  fn encode_raw<B>(&self, buf: &mut B)
+  where
+    B: ::prost::bytes::BufMut,
+  {
+    if !self.group_snapshot_id.is_empty() {
+      ::prost::encoding::string::encode(1, &self.group_snapshot_id, buf);
+    }
+    ::prost::encoding::string::encode_repeated(2, &self.snapshot_ids, buf);
+    ::prost::encoding::hash_map::encode(
+      ::prost::encoding::string::encode,
+      ::prost::encoding::string::encoded_len,
+      ::prost::encoding::string::encode,
+      ::prost::encoding::string::encoded_len,
+      3,
+      &self.secrets,
+      buf,
+    );
+  }
+
+  fn merge_field<B>(
+    &mut self,
+    tag: u32,
+    wire_type: ::prost::encoding::WireType,
+    buf: &mut B,
+    ctx: ::prost::encoding::DecodeContext,
+  ) -> ::core::result::Result<(), ::prost::DecodeError>
+  where
+    B: ::prost::bytes::Buf,
+  {
+    const STRUCT_NAME: &str = "DeleteVolumeGroupSnapshotRequest";
+    match tag {
+      1 => ::prost::encoding::string::merge(wire_type, &mut self.group_snapshot_id, buf, ctx)
+        .map_err(|mut error| {
+          error.push(STRUCT_NAME, "group_snapshot_id");
+          error
+        }),
+      2 => ::prost::encoding::string::merge_repeated(wire_type, &mut self.snapshot_ids, buf, ctx)
+        .map_err(|mut error| {
+          error.push(STRUCT_NAME, "snapshot_ids");
+          error
+        }),
+      3 => ::prost::encoding::hash_map::merge(
+        ::prost::encoding::string::merge,
+        ::prost::encoding::string::merge,
+        &mut self.secrets,
+        buf,
+        ctx,
+      )
+      .map_err(|mut error| {
+        error.push(STRUCT_NAME, "secrets");
+        error
+      }),
+
+      _ => ::prost::encoding::skip_field(wire_type, tag, buf, ctx),
+    }
+  }
+
+  fn encoded_len(&self) -> usize {
+    let mut len = 0;
+    len += if !self.group_snapshot_id.is_empty() {
+      ::prost::encoding::string::encoded_len(1, &self.group_snapshot_id)
+    } else {
+      0
+    };
+    len += ::prost::encoding::string::encoded_len_repeated(2, &self.snapshot_ids);
+    len += ::prost::encoding::hash_map::encoded_len(
+      ::prost::encoding::string::encoded_len,
+      ::prost::encoding::string::encoded_len,
+      3,
+      &self.secrets,
+    );
+
+    len
+  }
+
+  fn clear(&mut self) {
+    self.group_snapshot_id = ::core::default::Default::default();
+    self.snapshot_ids.clear();
+    self.secrets.clear();
+  }
+}
+
+/// The fields marked with the `csi_secret` option are redacted.
+#[cfg(feature = "alpha")]
+impl ::core::fmt::Debug for DeleteVolumeGroupSnapshotRequest {
+  fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+    let mut builder = f.debug_struct("DeleteVolumeGroupSnapshotRequest");
+    builder.field("group_snapshot_id", &self.group_snapshot_id);
+    builder.field("snapshot_ids", &self.snapshot_ids);
+    builder.field(
+      "secrets",
+      &self
+        .secrets
+        .keys()
+        .map(|k| (k, "SECRET"))
+        .collect::<::std::collections::BTreeMap<_, _>>(),
+    );
+
+    builder.finish()
+  }
+}
+
+impl ::prost::Message for DeleteVolumeRequest {
+  fn encode_raw<B>(&self, buf: &mut B)
+  where
+    B: ::prost::bytes::BufMut,
+  {
+    if !self.volume_id.is_empty() {
+      ::prost::encoding::string::encode(1, &self.volume_id, buf);
+    }
+    ::prost::encoding::hash_map::encode(
+      ::prost::encoding::string::encode,
+      ::prost::encoding::string::encoded_len,
+      ::prost::encoding::string::encode,
+      ::prost::encoding::string::encoded_len,
+      2,
+      &self.secrets,
+      buf,
+    );
+  }
+
+  fn merge_field<B>(
+    &mut self,
+    tag: u32,
+    wire_type: ::prost::encoding::WireType,
+    buf: &mut B,
+    ctx: ::prost::encoding::DecodeContext,
+  ) -> ::core::result::Result<(), ::prost::DecodeError>
+  where
+    B: ::prost::bytes::Buf,
+  {
+    const STRUCT_NAME: &str = "DeleteVolumeRequest";
+    match tag {
+      1 => ::prost::encoding::string::merge(wire_type, &mut self.volume_id, buf, ctx).map_err(
+        |mut error| {
+          error.push(STRUCT_NAME, "volume_id");
+          error
+        },
+      ),
+      2 => ::prost::encoding::hash_map::merge(
+        ::prost::encoding::string::merge,
+        ::prost::encoding::string::merge,
+        &mut self.secrets,
+        buf,
+        ctx,
+      )
+      .map_err(|mut error| {
+        error.push(STRUCT_NAME, "secrets");
+        error
+      }),
+
+      _ => ::prost::encoding::skip_field(wire_type, tag, buf, ctx),
+    }
+  }
+
+  fn encoded_len(&self) -> usize {
+    let mut len = 0;
+    len += if !self.volume_id.is_empty() {
+      ::prost::encoding::string::encoded_len(1, &self.volume_id)
+    } else {
+      0
+    };
+    len += ::prost::encoding::hash_map::encoded_len(
+      ::prost::encoding::string::encoded_len,
+      ::prost::encoding::string::encoded_len,
+      2,
+      &self.secrets,
+    );
+
+    len
+  }
+
+  fn clear(&mut self) {
+    self.volume_id = ::core::default::Default::default();
+    self.secrets.clear();
+  }
+}
+
+/// The fields marked with the `csi_secret` option are redacted.
+impl ::core::fmt::Debug for DeleteVolumeRequest {
+  fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+    let mut builder = f.debug_struct("DeleteVolumeRequest");
+    builder.field("volume_id", &self.volume_id);
+    builder.field(
+      "secrets",
+      &self
+        .secrets
+        .keys()
+        .map(|k| (k, "SECRET"))
+        .collect::<::std::collections::BTreeMap<_, _>>(),
+    );
+
+    builder.finish()
+  }
+}
+
+#[cfg(feature = "alpha")]
+impl ::prost::Message for GetMetadataAllocatedRequest {
+  fn encode_raw<B>(&self, buf: &mut B)
+  where
+    B: ::prost::bytes::BufMut,
+  {
+    if !self.snapshot_id.is_empty() {
+      ::prost::encoding::string::encode(1, &self.snapshot_id, buf);
+    }
+    if self.starting_offset != 0 {
+      ::prost::encoding::int64::encode(2, &self.starting_offset, buf);
+    }
+    if self.max_results != 0 {
+      ::prost::encoding::int32::encode(3, &self.max_results, buf);
+    }
+    ::prost::encoding::hash_map::encode(
+      ::prost::encoding::string::encode,
+      ::prost::encoding::string::encoded_len,
+      ::prost::encoding::string::encode,
+      ::prost::encoding::string::encoded_len,
+      4,
+      &self.secrets,
+      buf,
+    );
+  }
+
+  fn merge_field<B>(
+    &mut self,
+    tag: u32,
+    wire_type: ::prost::encoding::WireType,
+    buf: &mut B,
+    ctx: ::prost::encoding::DecodeContext,
+  ) -> ::core::result::Result<(), ::prost::DecodeError>
+  where
+    B: ::prost::bytes::Buf,
+  {
+    const STRUCT_NAME: &str = "GetMetadataAllocatedRequest";
+    match tag {
+      1 => ::prost::encoding::string::merge(wire_type, &mut self.snapshot_id, buf, ctx).map_err(
+        |mut error| {
+          error.push(STRUCT_NAME, "snapshot_id");
+          error
+        },
+      ),
+      2 => ::prost::encoding::int64::merge(wire_type, &mut self.starting_offset, buf, ctx).map_err(
+        |mut error| {
+          error.push(STRUCT_NAME, "starting_offset");
+          error
+        },
+      ),
+      3 => ::prost::encoding::int32::merge(wire_type, &mut self.max_results, buf, ctx).map_err(
+        |mut error| {
+          error.push(STRUCT_NAME, "max_results");
+          error
+        },
+      ),
+      4 => ::prost::encoding::hash_map::merge(
+        ::prost::encoding::string::merge,
+        ::prost::encoding::string::merge,
+        &mut self.secrets,
+        buf,
+        ctx,
+      )
+      .map_err(|mut error| {
+        error.push(STRUCT_NAME, "secrets");
+        error
+      }),
+
+      _ => ::prost::encoding::skip_field(wire_type, tag, buf, ctx),
+    }
+  }
+
+  fn encoded_len(&self) -> usize {
+    let mut len = 0;
+    len += if !self.snapshot_id.is_empty() {
+      ::prost::encoding::string::encoded_len(1, &self.snapshot_id)
+    } else {
+      0
+    };
+    len += if self.starting_offset != 0 {
+      ::prost::encoding::int64::encoded_len(2, &self.starting_offset)
+    } else {
+      0
+    };
+    len += if self.max_results != 0 {
+      ::prost::encoding::int32::encoded_len(3, &self.max_results)
+    } else {
+      0
+    };
+    len += ::prost::encoding::hash_map::encoded_len(
+      ::prost::encoding::string::encoded_len,
+      ::prost::encoding::string::encoded_len,
+      4,
+      &self.secrets,
+    );
+
+    len
+  }
+
+  fn clear(&mut self) {
+    self.snapshot_id = ::core::default::Default::default();
+    self.starting_offset = ::core::default::Default::default();
+    self.max_results = ::core::default::Default::default();
+    self.secrets.clear();
+  }
+}
+
+/// The fields marked with the `csi_secret` option are redacted.
+#[cfg(feature = "alpha")]
+impl ::core::fmt::Debug for GetMetadataAllocatedRequest {
+  fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+    let mut builder = f.debug_struct("GetMetadataAllocatedRequest");
+    builder.field("snapshot_id", &self.snapshot_id);
+    builder.field("starting_offset", &self.starting_offset);
+    builder.field("max_results", &self.max_results);
+    builder.field(
+      "secrets",
+      &self
+        .secrets
+        .keys()
+        .map(|k| (k, "SECRET"))
+        .collect::<::std::collections::BTreeMap<_, _>>(),
+    );
+
+    builder.finish()
+  }
+}
+
+#[cfg(feature = "alpha")]
+impl ::prost::Message for GetMetadataDeltaRequest {
+  fn encode_raw<B>(&self, buf: &mut B)
+  where
+    B: ::prost::bytes::BufMut,
+  {
+    if !self.base_snapshot_id.is_empty() {
+      ::prost::encoding::string::encode(1, &self.base_snapshot_id, buf);
+    }
+    if !self.target_snapshot_id.is_empty() {
+      ::prost::encoding::string::encode(2, &self.target_snapshot_id, buf);
+    }
+    if self.starting_offset != 0 {
+      ::prost::encoding::int64::encode(3, &self.starting_offset, buf);
+    }
+    if self.max_results != 0 {
+      ::prost::encoding::int32::encode(4, &self.max_results, buf);
+    }
+    ::prost::encoding::hash_map::encode(
+      ::prost::encoding::string::encode,
+      ::prost::encoding::string::encoded_len,
+      ::prost::encoding::string::encode,
+      ::prost::encoding::string::encoded_len,
+      5,
+      &self.secrets,
+      buf,
+    );
+  }
+
+  fn merge_field<B>(
+    &mut self,
+    tag: u32,
+    wire_type: ::prost::encoding::WireType,
+    buf: &mut B,
+    ctx: ::prost::encoding::DecodeContext,
+  ) -> ::core::result::Result<(), ::prost::DecodeError>
+  where
+    B: ::prost::bytes::Buf,
+  {
+    const STRUCT_NAME: &str = "GetMetadataDeltaRequest";
+    match tag {
+      1 => ::prost::encoding::string::merge(wire_type, &mut self.base_snapshot_id, buf, ctx)
+        .map_err(|mut error| {
+          error.push(STRUCT_NAME, "base_snapshot_id");
+          error
+        }),
+      2 => ::prost::encoding::string::merge(wire_type, &mut self.target_snapshot_id, buf, ctx)
+        .map_err(|mut error| {
+          error.push(STRUCT_NAME, "target_snapshot_id");
+          error
+        }),
+      3 => ::prost::encoding::int64::merge(wire_type, &mut self.starting_offset, buf, ctx).map_err(
+        |mut error| {
+          error.push(STRUCT_NAME, "starting_offset");
+          error
+        },
+      ),
+      4 => ::prost::encoding::int32::merge(wire_type, &mut self.max_results, buf, ctx).map_err(
+        |mut error| {
+          error.push(STRUCT_NAME, "max_results");
+          error
+        },
+      ),
+      5 => ::prost::encoding::hash_map::merge(
+        ::prost::encoding::string::merge,
+        ::prost::encoding::string::merge,
+        &mut self.secrets,
+        buf,
+        ctx,
+      )
+      .map_err(|mut error| {
+        error.push(STRUCT_NAME, "secrets");
+        error
+      }),
+
+      _ => ::prost::encoding::skip_field(wire_type, tag, buf, ctx),
+    }
+  }
+
+  fn encoded_len(&self) -> usize {
+    let mut len = 0;
+    len += if !self.base_snapshot_id.is_empty() {
+      ::prost::encoding::string::encoded_len(1, &self.base_snapshot_id)
+    } else {
+      0
+    };
+    len += if !self.target_snapshot_id.is_empty() {
+      ::prost::encoding::string::encoded_len(2, &self.target_snapshot_id)
+    } else {
+      0
+    };
+    len += if self.starting_offset != 0 {
+      ::prost::encoding::int64::encoded_len(3, &self.starting_offset)
+    } else {
+      0
+    };
+    len += if self.max_results != 0 {
+      ::prost::encoding::int32::encoded_len(4, &self.max_results)
+    } else {
+      0
+    };
+    len += ::prost::encoding::hash_map::encoded_len(
+      ::prost::encoding::string::encoded_len,
+      ::prost::encoding::string::encoded_len,
+      5,
+      &self.secrets,
+    );
+
+    len
+  }
+
+  fn clear(&mut self) {
+    self.base_snapshot_id = ::core::default::Default::default();
+    self.target_snapshot_id = ::core::default::Default::default();
+    self.starting_offset = ::core::default::Default::default();
+    self.max_results = ::core::default::Default::default();
+    self.secrets.clear();
+  }
+}
+
+/// The fields marked with the `csi_secret` option are redacted.
+#[cfg(feature = "alpha")]
+impl ::core::fmt::Debug for GetMetadataDeltaRequest {
+  fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+    let mut builder = f.debug_struct("GetMetadataDeltaRequest");
+    builder.field("base_snapshot_id", &self.base_snapshot_id);
+    builder.field("target_snapshot_id", &self.target_snapshot_id);
+    builder.field("starting_offset", &self.starting_offset);
+    builder.field("max_results", &self.max_results);
+    builder.field(
+      "secrets",
+      &self
+        .secrets
+        .keys()
+        .map(|k| (k, "SECRET"))
+        .collect::<::std::collections::BTreeMap<_, _>>(),
+    );
+
+    builder.finish()
+  }
+}
+
+#[cfg(feature = "alpha")]
+impl ::prost::Message for GetVolumeGroupSnapshotRequest {
+  fn encode_raw<B>(&self, buf: &mut B)
+  where
+    B: ::prost::bytes::BufMut,
+  {
+    if !self.group_snapshot_id.is_empty() {
+      ::prost::encoding::string::encode(1, &self.group_snapshot_id, buf);
+    }
+    ::prost::encoding::string::encode_repeated(2, &self.snapshot_ids, buf);
+    ::prost::encoding::hash_map::encode(
+      ::prost::encoding::string::encode,
+      ::prost::encoding::string::encoded_len,
+      ::prost::encoding::string::encode,
+      ::prost::encoding::string::encoded_len,
+      3,
+      &self.secrets,
+      buf,
+    );
+  }
+
+  fn merge_field<B>(
+    &mut self,
+    tag: u32,
+    wire_type: ::prost::encoding::WireType,
+    buf: &mut B,
+    ctx: ::prost::encoding::DecodeContext,
+  ) -> ::core::result::Result<(), ::prost::DecodeError>
+  where
+    B: ::prost::bytes::Buf,
+  {
+    const STRUCT_NAME: &str = "GetVolumeGroupSnapshotRequest";
+    match tag {
+      1 => ::prost::encoding::string::merge(wire_type, &mut self.group_snapshot_id, buf, ctx)
+        .map_err(|mut error| {
+          error.push(STRUCT_NAME, "group_snapshot_id");
+          error
+        }),
+      2 => ::prost::encoding::string::merge_repeated(wire_type, &mut self.snapshot_ids, buf, ctx)
+        .map_err(|mut error| {
+          error.push(STRUCT_NAME, "snapshot_ids");
+          error
+        }),
+      3 => ::prost::encoding::hash_map::merge(
+        ::prost::encoding::string::merge,
+        ::prost::encoding::string::merge,
+        &mut self.secrets,
+        buf,
+        ctx,
+      )
+      .map_err(|mut error| {
+        error.push(STRUCT_NAME, "secrets");
+        error
+      }),
+
+      _ => ::prost::encoding::skip_field(wire_type, tag, buf, ctx),
+    }
+  }
+
+  fn encoded_len(&self) -> usize {
+    let mut len = 0;
+    len += if !self.group_snapshot_id.is_empty() {
+      ::prost::encoding::string::encoded_len(1, &self.group_snapshot_id)
+    } else {
+      0
+    };
+    len += ::prost::encoding::string::encoded_len_repeated(2, &self.snapshot_ids);
+    len += ::prost::encoding::hash_map::encoded_len(
+      ::prost::encoding::string::encoded_len,
+      ::prost::encoding::string::encoded_len,
+      3,
+      &self.secrets,
+    );
+
+    len
+  }
+
+  fn clear(&mut self) {
+    self.group_snapshot_id = ::core::default::Default::default();
+    self.snapshot_ids.clear();
+    self.secrets.clear();
+  }
+}
+
+/// The fields marked with the `csi_secret` option are redacted.
+#[cfg(feature = "alpha")]
+impl ::core::fmt::Debug for GetVolumeGroupSnapshotRequest {
+  fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+    let mut builder = f.debug_struct("GetVolumeGroupSnapshotRequest");
+    builder.field("group_snapshot_id", &self.group_snapshot_id);
+    builder.field("snapshot_ids", &self.snapshot_ids);
+    builder.field(
+      "secrets",
+      &self
+        .secrets
+        .keys()
+        .map(|k| (k, "SECRET"))
+        .collect::<::std::collections::BTreeMap<_, _>>(),
+    );
+
+    builder.finish()
+  }
+}
+
+impl ::prost::Message for ListSnapshotsRequest {
+  fn encode_raw<B>(&self, buf: &mut B)
+  where
+    B: ::prost::bytes::BufMut,
+  {
+    if self.max_entries != 0 {
+      ::prost::encoding::int32::encode(1, &self.max_entries, buf);
+    }
+    if !self.starting_token.is_empty() {
+      ::prost::encoding::string::encode(2, &self.starting_token, buf);
+    }
+    if !self.source_volume_id.is_empty() {
+      ::prost::encoding::string::encode(3, &self.source_volume_id, buf);
+    }
+    if !self.snapshot_id.is_empty() {
+      ::prost::encoding::string::encode(4, &self.snapshot_id, buf);
+    }
+    ::prost::encoding::hash_map::encode(
+      ::prost::encoding::string::encode,
+      ::prost::encoding::string::encoded_len,
+      ::prost::encoding::string::encode,
+      ::prost::encoding::string::encoded_len,
+      5,
+      &self.secrets,
+      buf,
+    );
+  }
+
+  fn merge_field<B>(
+    &mut self,
+    tag: u32,
+    wire_type: ::prost::encoding::WireType,
+    buf: &mut B,
+    ctx: ::prost::encoding::DecodeContext,
+  ) -> ::core::result::Result<(), ::prost::DecodeError>
+  where
+    B: ::prost::bytes::Buf,
+  {
+    const STRUCT_NAME: &str = "ListSnapshotsRequest";
+    match tag {
+      1 => ::prost::encoding::int32::merge(wire_type, &mut self.max_entries, buf, ctx).map_err(
+        |mut error| {
+          error.push(STRUCT_NAME, "max_entries");
+          error
+        },
+      ),
+      2 => ::prost::encoding::string::merge(wire_type, &mut self.starting_token, buf, ctx).map_err(
+        |mut error| {
+          error.push(STRUCT_NAME, "starting_token");
+          error
+        },
+      ),
+      3 => ::prost::encoding::string::merge(wire_type, &mut self.source_volume_id, buf, ctx)
+        .map_err(|mut error| {
+          error.push(STRUCT_NAME, "source_volume_id");
+          error
+        }),
+      4 => ::prost::encoding::string::merge(wire_type, &mut self.snapshot_id, buf, ctx).map_err(
+        |mut error| {
+          error.push(STRUCT_NAME, "snapshot_id");
+          error
+        },
+      ),
+      5 => ::prost::encoding::hash_map::merge(
+        ::prost::encoding::string::merge,
+        ::prost::encoding::string::merge,
+        &mut self.secrets,
+        buf,
+        ctx,
+      )
+      .map_err(|mut error| {
+        error.push(STRUCT_NAME, "secrets");
+        error
+      }),
+
+      _ => ::prost::encoding::skip_field(wire_type, tag, buf, ctx),
+    }
+  }
+
+  fn encoded_len(&self) -> usize {
+    let mut len = 0;
+    len += if self.max_entries != 0 {
+      ::prost::encoding::int32::encoded_len(1, &self.max_entries)
+    } else {
+      0
+    };
+    len += if !self.starting_token.is_empty() {
+      ::prost::encoding::string::encoded_len(2, &self.starting_token)
+    } else {
+      0
+    };
+    len += if !self.source_volume_id.is_empty() {
+      ::prost::encoding::string::encoded_len(3, &self.source_volume_id)
+    } else {
+      0
+    };
+    len += if !self.snapshot_id.is_empty() {
+      ::prost::encoding::string::encoded_len(4, &self.snapshot_id)
+    } else {
+      0
+    };
+    len += ::prost::encoding::hash_map::encoded_len(
+      ::prost::encoding::string::encoded_len,
+      ::prost::encoding::string::encoded_len,
+      5,
+      &self.secrets,
+    );
+
+    len
+  }
+
+  fn clear(&mut self) {
+    self.max_entries = ::core::default::Default::default();
+    self.starting_token = ::core::default::Default::default();
+    self.source_volume_id = ::core::default::Default::default();
+    self.snapshot_id = ::core::default::Default::default();
+    self.secrets.clear();
+  }
+}
+
+/// The fields marked with the `csi_secret` option are redacted.
+impl ::core::fmt::Debug for ListSnapshotsRequest {
+  fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+    let mut builder = f.debug_struct("ListSnapshotsRequest");
+    builder.field("max_entries", &self.max_entries);
+    builder.field("starting_token", &self.starting_token);
+    builder.field("source_volume_id", &self.source_volume_id);
+    builder.field("snapshot_id", &self.snapshot_id);
+    builder.field(
+      "secrets",
+      &self
+        .secrets
+        .keys()
+        .map(|k| (k, "SECRET"))
+        .collect::<::std::collections::BTreeMap<_, _>>(),
+    );
+
+    builder.finish()
+  }
+}
+
+impl ::prost::Message for NodeExpandVolumeRequest {
+  fn encode_raw<B>(&self, buf: &mut B)
+  where
+    B: ::prost::bytes::BufMut,
+  {
+    if !self.volume_id.is_empty() {
+      ::prost::encoding::string::encode(1, &self.volume_id, buf);
+    }
+    if !self.volume_path.is_empty() {
+      ::prost::encoding::string::encode(2, &self.volume_path, buf);
+    }
+    if let Some(ref msg) = self.capacity_range {
+      ::prost::encoding::message::encode(3, msg, buf);
+    }
+    if !self.staging_target_path.is_empty() {
+      ::prost::encoding::string::encode(4, &self.staging_target_path, buf);
+    }
+    if let Some(ref msg) = self.volume_capability {
+      ::prost::encoding::message::encode(5, msg, buf);
+    }
+    ::prost::encoding::hash_map::encode(
+      ::prost::encoding::string::encode,
+      ::prost::encoding::string::encoded_len,
+      ::prost::encoding::string::encode,
+      ::prost::encoding::string::encoded_len,
+      6,
+      &self.secrets,
+      buf,
+    );
+  }
+
+  fn merge_field<B>(
+    &mut self,
+    tag: u32,
+    wire_type: ::prost::encoding::WireType,
+    buf: &mut B,
+    ctx: ::prost::encoding::DecodeContext,
+  ) -> ::core::result::Result<(), ::prost::DecodeError>
+  where
+    B: ::prost::bytes::Buf,
+  {
+    const STRUCT_NAME: &str = "NodeExpandVolumeRequest";
+    match tag {
+      1 => ::prost::encoding::string::merge(wire_type, &mut self.volume_id, buf, ctx).map_err(
+        |mut error| {
+          error.push(STRUCT_NAME, "volume_id");
+          error
+        },
+      ),
+      2 => ::prost::encoding::string::merge(wire_type, &mut self.volume_path, buf, ctx).map_err(
+        |mut error| {
+          error.push(STRUCT_NAME, "volume_path");
+          error
+        },
+      ),
+      3 => ::prost::encoding::message::merge(
+        wire_type,
+        self.capacity_range.get_or_insert_with(Default::default),
+        buf,
+        ctx,
+      )
+      .map_err(|mut error| {
+        error.push(STRUCT_NAME, "capacity_range");
+        error
+      }),
+      4 => ::prost::encoding::string::merge(wire_type, &mut self.staging_target_path, buf, ctx)
+        .map_err(|mut error| {
+          error.push(STRUCT_NAME, "staging_target_path");
+          error
+        }),
+      5 => ::prost::encoding::message::merge(
+        wire_type,
+        self.volume_capability.get_or_insert_with(Default::default),
+        buf,
+        ctx,
+      )
+      .map_err(|mut error| {
+        error.push(STRUCT_NAME, "volume_capability");
+        error
+      }),
+      6 => ::prost::encoding::hash_map::merge(
+        ::prost::encoding::string::merge,
+        ::prost::encoding::string::merge,
+        &mut self.secrets,
+        buf,
+        ctx,
+      )
+      .map_err(|mut error| {
+        error.push(STRUCT_NAME, "secrets");
+        error
+      }),
+
+      _ => ::prost::encoding::skip_field(wire_type, tag, buf, ctx),
+    }
+  }
+
+  fn encoded_len(&self) -> usize {
+    let mut len = 0;
+    len += if !self.volume_id.is_empty() {
+      ::prost::encoding::string::encoded_len(1, &self.volume_id)
+    } else {
+      0
+    };
+    len += if !self.volume_path.is_empty() {
+      ::prost::encoding::string::encoded_len(2, &self.volume_path)
+    } else {
+      0
+    };
+    len += self
+      .capacity_range
+      .as_ref()
+      .map_or(0, |msg| ::prost::encoding::message::encoded_len(3, msg));
+    len += if !self.staging_target_path.is_empty() {
+      ::prost::encoding::string::encoded_len(4, &self.staging_target_path)
+    } else {
+      0
+    };
+    len += self
+      .volume_capability
+      .as_ref()
+      .map_or(0, |msg| ::prost::encoding::message::encoded_len(5, msg));
+    len += ::prost::encoding::hash_map::encoded_len(
+      ::prost::encoding::string::encoded_len,
+      ::prost::encoding::string::encoded_len,
+      6,
+      &self.secrets,
+    );
+
+    len
+  }
+
+  fn clear(&mut self) {
+    self.volume_id = ::core::default::Default::default();
+    self.volume_path = ::core::default::Default::default();
+    self.capacity_range = ::core::option::Option::None;
+    self.staging_target_path = ::core::default::Default::default();
+    self.volume_capability = ::core::option::Option::None;
+    self.secrets.clear();
+  }
+}
+
+/// The fields marked with the `csi_secret` option are redacted.
+impl ::core::fmt::Debug for NodeExpandVolumeRequest {
+  fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+    let mut builder = f.debug_struct("NodeExpandVolumeRequest");
+    builder.field("volume_id", &self.volume_id);
+    builder.field("volume_path", &self.volume_path);
+    builder.field("capacity_range", &self.capacity_range);
+    builder.field("staging_target_path", &self.staging_target_path);
+    builder.field("volume_capability", &self.volume_capability);
+    builder.field(
+      "secrets",
+      &self
+        .secrets
+        .keys()
+        .map(|k| (k, "SECRET"))
+        .collect::<::std::collections::BTreeMap<_, _>>(),
+    );
+
+    builder.finish()
+  }
+}
+
+impl ::prost::Message for NodePublishVolumeRequest {
+  fn encode_raw<B>(&self, buf: &mut B)
+  where
+    B: ::prost::bytes::BufMut,
+  {
+    if !self.volume_id.is_empty() {
+      ::prost::encoding::string::encode(1, &self.volume_id, buf);
+    }
+    ::prost::encoding::hash_map::encode(
+      ::prost::encoding::string::encode,
+      ::prost::encoding::string::encoded_len,
+      ::prost::encoding::string::encode,
+      ::prost::encoding::string::encoded_len,
+      2,
+      &self.publish_context,
+      buf,
+    );
+    if !self.staging_target_path.is_empty() {
+      ::prost::encoding::string::encode(3, &self.staging_target_path, buf);
+    }
+    if !self.target_path.is_empty() {
+      ::prost::encoding::string::encode(4, &self.target_path, buf);
+    }
+    if let Some(ref msg) = self.volume_capability {
+      ::prost::encoding::message::encode(5, msg, buf);
+    }
+    if self.readonly {
+      ::prost::encoding::bool::encode(6, &self.readonly, buf);
+    }
+    ::prost::encoding::hash_map::encode(
+      ::prost::encoding::string::encode,
+      ::prost::encoding::string::encoded_len,
+      ::prost::encoding::string::encode,
+      ::prost::encoding::string::encoded_len,
+      7,
+      &self.secrets,
+      buf,
+    );
+    ::prost::encoding::hash_map::encode(
+      ::prost::encoding::string::encode,
+      ::prost::encoding::string::encoded_len,
+      ::prost::encoding::string::encode,
+      ::prost::encoding::string::encoded_len,
+      8,
+      &self.volume_context,
+      buf,
+    );
+  }
+
+  fn merge_field<B>(
+    &mut self,
+    tag: u32,
+    wire_type: ::prost::encoding::WireType,
+    buf: &mut B,
+    ctx: ::prost::encoding::DecodeContext,
+  ) -> ::core::result::Result<(), ::prost::DecodeError>
+  where
+    B: ::prost::bytes::Buf,
+  {
+    const STRUCT_NAME: &str = "NodePublishVolumeRequest";
+    match tag {
+      1 => ::prost::encoding::string::merge(wire_type, &mut self.volume_id, buf, ctx).map_err(
+        |mut error| {
+          error.push(STRUCT_NAME, "volume_id");
+          error
+        },
+      ),
+      2 => ::prost::encoding::hash_map::merge(
+        ::prost::encoding::string::merge,
+        ::prost::encoding::string::merge,
+        &mut self.publish_context,
+        buf,
+        ctx,
+      )
+      .map_err(|mut error| {
+        error.push(STRUCT_NAME, "publish_context");
+        error
+      }),
+      3 => ::prost::encoding::string::merge(wire_type, &mut self.staging_target_path, buf, ctx)
+        .map_err(|mut error| {
+          error.push(STRUCT_NAME, "staging_target_path");
+          error
+        }),
+      4 => ::prost::encoding::string::merge(wire_type, &mut self.target_path, buf, ctx).map_err(
+        |mut error| {
+          error.push(STRUCT_NAME, "target_path");
+          error
+        },
+      ),
+      5 => ::prost::encoding::message::merge(
+        wire_type,
+        self.volume_capability.get_or_insert_with(Default::default),
+        buf,
+        ctx,
+      )
+      .map_err(|mut error| {
+        error.push(STRUCT_NAME, "volume_capability");
+        error
+      }),
+      6 => ::prost::encoding::bool::merge(wire_type, &mut self.readonly, buf, ctx).map_err(
+        |mut error| {
+          error.push(STRUCT_NAME, "readonly");
+          error
+        },
+      ),
+      7 => ::prost::encoding::hash_map::merge(
+        ::prost::encoding::string::merge,
+        ::prost::encoding::string::merge,
+        &mut self.secrets,
+        buf,
+        ctx,
+      )
+      .map_err(|mut error| {
+        error.push(STRUCT_NAME, "secrets");
+        error
+      }),
+      8 => ::prost::encoding::hash_map::merge(
+        ::prost::encoding::string::merge,
+        ::prost::encoding::string::merge,
+        &mut self.volume_context,
+        buf,
+        ctx,
+      )
+      .map_err(|mut error| {
+        error.push(STRUCT_NAME, "volume_context");
+        error
+      }),
+
+      _ => ::prost::encoding::skip_field(wire_type, tag, buf, ctx),
+    }
+  }
+
+  fn encoded_len(&self) -> usize {
+    let mut len = 0;
+    len += if !self.volume_id.is_empty() {
+      ::prost::encoding::string::encoded_len(1, &self.volume_id)
+    } else {
+      0
+    };
+    len += ::prost::encoding::hash_map::encoded_len(
+      ::prost::encoding::string::encoded_len,
+      ::prost::encoding::string::encoded_len,
+      2,
+      &self.publish_context,
+    );
+    len += if !self.staging_target_path.is_empty() {
+      ::prost::encoding::string::encoded_len(3, &self.staging_target_path)
+    } else {
+      0
+    };
+    len += if !self.target_path.is_empty() {
+      ::prost::encoding::string::encoded_len(4, &self.target_path)
+    } else {
+      0
+    };
+    len += self
+      .volume_capability
+      .as_ref()
+      .map_or(0, |msg| ::prost::encoding::message::encoded_len(5, msg));
+    len += if self.readonly {
+      ::prost::encoding::bool::encoded_len(6, &self.readonly)
+    } else {
+      0
+    };
+    len += ::prost::encoding::hash_map::encoded_len(
+      ::prost::encoding::string::encoded_len,
+      ::prost::encoding::string::encoded_len,
+      7,
+      &self.secrets,
+    );
+    len += ::prost::encoding::hash_map::encoded_len(
+      ::prost::encoding::string::encoded_len,
+      ::prost::encoding::string::encoded_len,
+      8,
+      &self.volume_context,
+    );
+
+    len
+  }
+
+  fn clear(&mut self) {
+    self.volume_id = ::core::default::Default::default();
+    self.publish_context.clear();
+    self.staging_target_path = ::core::default::Default::default();
+    self.target_path = ::core::default::Default::default();
+    self.volume_capability = ::core::option::Option::None;
+    self.readonly = ::core::default::Default::default();
+    self.secrets.clear();
+    self.volume_context.clear();
+  }
+}
+
+/// The fields marked with the `csi_secret` option are redacted.
+impl ::core::fmt::Debug for NodePublishVolumeRequest {
+  fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+    let mut builder = f.debug_struct("NodePublishVolumeRequest");
+    builder.field("volume_id", &self.volume_id);
+    builder.field("publish_context", &self.publish_context);
+    builder.field("staging_target_path", &self.staging_target_path);
+    builder.field("target_path", &self.target_path);
+    builder.field("volume_capability", &self.volume_capability);
+    builder.field("readonly", &self.readonly);
+    builder.field(
+      "secrets",
+      &self
+        .secrets
+        .keys()
+        .map(|k| (k, "SECRET"))
+        .collect::<::std::collections::BTreeMap<_, _>>(),
+    );
+    builder.field("volume_context", &self.volume_context);
+
+    builder.finish()
+  }
+}
+
+impl ::prost::Message for NodeStageVolumeRequest {
+  fn encode_raw<B>(&self, buf: &mut B)
+  where
+    B: ::prost::bytes::BufMut,
+  {
+    if !self.volume_id.is_empty() {
+      ::prost::encoding::string::encode(1, &self.volume_id, buf);
+    }
+    ::prost::encoding::hash_map::encode(
+      ::prost::encoding::string::encode,
+      ::prost::encoding::string::encoded_len,
+      ::prost::encoding::string::encode,
+      ::prost::encoding::string::encoded_len,
+      2,
+      &self.publish_context,
+      buf,
+    );
+    if !self.staging_target_path.is_empty() {
+      ::prost::encoding::string::encode(3, &self.staging_target_path, buf);
+    }
+    if let Some(ref msg) = self.volume_capability {
+      ::prost::encoding::message::encode(4, msg, buf);
+    }
+    ::prost::encoding::hash_map::encode(
+      ::prost::encoding::string::encode,
+      ::prost::encoding::string::encoded_len,
+      ::prost::encoding::string::encode,
+      ::prost::encoding::string::encoded_len,
+      5,
+      &self.secrets,
+      buf,
+    );
+    ::prost::encoding::hash_map::encode(
+      ::prost::encoding::string::encode,
+      ::prost::encoding::string::encoded_len,
+      ::prost::encoding::string::encode,
+      ::prost::encoding::string::encoded_len,
+      6,
+      &self.volume_context,
+      buf,
+    );
+  }
+
+  fn merge_field<B>(
+    &mut self,
+    tag: u32,
+    wire_type: ::prost::encoding::WireType,
+    buf: &mut B,
+    ctx: ::prost::encoding::DecodeContext,
+  ) -> ::core::result::Result<(), ::prost::DecodeError>
+  where
+    B: ::prost::bytes::Buf,
+  {
+    const STRUCT_NAME: &str = "NodeStageVolumeRequest";
+    match tag {
+      1 => ::prost::encoding::string::merge(wire_type, &mut self.volume_id, buf, ctx).map_err(
+        |mut error| {
+          error.push(STRUCT_NAME, "volume_id");
+          error
+        },
+      ),
+      2 => ::prost::encoding::hash_map::merge(
+        ::prost::encoding::string::merge,
+        ::prost::encoding::string::merge,
+        &mut self.publish_context,
+        buf,
+        ctx,
+      )
+      .map_err(|mut error| {
+        error.push(STRUCT_NAME, "publish_context");
+        error
+      }),
+      3 => ::prost::encoding::string::merge(wire_type, &mut self.staging_target_path, buf, ctx)
+        .map_err(|mut error| {
+          error.push(STRUCT_NAME, "staging_target_path");
+          error
+        }),
+      4 => ::prost::encoding::message::merge(
+        wire_type,
+        self.volume_capability.get_or_insert_with(Default::default),
+        buf,
+        ctx,
+      )
+      .map_err(|mut error| {
+        error.push(STRUCT_NAME, "volume_capability");
+        error
+      }),
+      5 => ::prost::encoding::hash_map::merge(
+        ::prost::encoding::string::merge,
+        ::prost::encoding::string::merge,
+        &mut self.secrets,
+        buf,
+        ctx,
+      )
+      .map_err(|mut error| {
+        error.push(STRUCT_NAME, "secrets");
+        error
+      }),
+      6 => ::prost::encoding::hash_map::merge(
+        ::prost::encoding::string::merge,
+        ::prost::encoding::string::merge,
+        &mut self.volume_context,
+        buf,
+        ctx,
+      )
+      .map_err(|mut error| {
+        error.push(STRUCT_NAME, "volume_context");
+        error
+      }),
+
+      _ => ::prost::encoding::skip_field(wire_type, tag, buf, ctx),
+    }
+  }
+
+  fn encoded_len(&self) -> usize {
+    let mut len = 0;
+    len += if !self.volume_id.is_empty() {
+      ::prost::encoding::string::encoded_len(1, &self.volume_id)
+    } else {
+      0
+    };
+    len += ::prost::encoding::hash_map::encoded_len(
+      ::prost::encoding::string::encoded_len,
+      ::prost::encoding::string::encoded_len,
+      2,
+      &self.publish_context,
+    );
+    len += if !self.staging_target_path.is_empty() {
+      ::prost::encoding::string::encoded_len(3, &self.staging_target_path)
+    } else {
+      0
+    };
+    len += self
+      .volume_capability
+      .as_ref()
+      .map_or(0, |msg| ::prost::encoding::message::encoded_len(4, msg));
+    len += ::prost::encoding::hash_map::encoded_len(
+      ::prost::encoding::string::encoded_len,
+      ::prost::encoding::string::encoded_len,
+      5,
+      &self.secrets,
+    );
+    len += ::prost::encoding::hash_map::encoded_len(
+      ::prost::encoding::string::encoded_len,
+      ::prost::encoding::string::encoded_len,
+      6,
+      &self.volume_context,
+    );
+
+    len
+  }
+
+  fn clear(&mut self) {
+    self.volume_id = ::core::default::Default::default();
+    self.publish_context.clear();
+    self.staging_target_path = ::core::default::Default::default();
+    self.volume_capability = ::core::option::Option::None;
+    self.secrets.clear();
+    self.volume_context.clear();
+  }
+}
+
+/// The fields marked with the `csi_secret` option are redacted.
+impl ::core::fmt::Debug for NodeStageVolumeRequest {
+  fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+    let mut builder = f.debug_struct("NodeStageVolumeRequest");
+    builder.field("volume_id", &self.volume_id);
+    builder.field("publish_context", &self.publish_context);
+    builder.field("staging_target_path", &self.staging_target_path);
+    builder.field("volume_capability", &self.volume_capability);
+    builder.field(
+      "secrets",
+      &self
+        .secrets
+        .keys()
+        .map(|k| (k, "SECRET"))
+        .collect::<::std::collections::BTreeMap<_, _>>(),
+    );
+    builder.field("volume_context", &self.volume_context);
+
+    builder.finish()
+  }
+}
+
+impl ::prost::Message for ValidateVolumeCapabilitiesRequest {
+  fn encode_raw<B>(&self, buf: &mut B)
+  where
+    B: ::prost::bytes::BufMut,
+  {
+    if !self.volume_id.is_empty() {
+      ::prost::encoding::string::encode(1, &self.volume_id, buf);
+    }
+    ::prost::encoding::hash_map::encode(
+      ::prost::encoding::string::encode,
+      ::prost::encoding::string::encoded_len,
+      ::prost::encoding::string::encode,
+      ::prost::encoding::string::encoded_len,
+      2,
+      &self.volume_context,
+      buf,
+    );
+    for msg in &self.volume_capabilities {
+      ::prost::encoding::message::encode(3, msg, buf);
+    }
+    ::prost::encoding::hash_map::encode(
+      ::prost::encoding::string::encode,
+      ::prost::encoding::string::encoded_len,
+      ::prost::encoding::string::encode,
+      ::prost::encoding::string::encoded_len,
+      4,
+      &self.parameters,
+      buf,
+    );
+    ::prost::encoding::hash_map::encode(
+      ::prost::encoding::string::encode,
+      ::prost::encoding::string::encoded_len,
+      ::prost::encoding::string::encode,
+      ::prost::encoding::string::encoded_len,
+      5,
+      &self.secrets,
+      buf,
+    );
+  }
+
+  fn merge_field<B>(
+    &mut self,
+    tag: u32,
+    wire_type: ::prost::encoding::WireType,
+    buf: &mut B,
+    ctx: ::prost::encoding::DecodeContext,
+  ) -> ::core::result::Result<(), ::prost::DecodeError>
+  where
+    B: ::prost::bytes::Buf,
+  {
+    const STRUCT_NAME: &str = "ValidateVolumeCapabilitiesRequest";
+    match tag {
+      1 => ::prost::encoding::string::merge(wire_type, &mut self.volume_id, buf, ctx).map_err(
+        |mut error| {
+          error.push(STRUCT_NAME, "volume_id");
+          error
+        },
+      ),
+      2 => ::prost::encoding::hash_map::merge(
+        ::prost::encoding::string::merge,
+        ::prost::encoding::string::merge,
+        &mut self.volume_context,
+        buf,
+        ctx,
+      )
+      .map_err(|mut error| {
+        error.push(STRUCT_NAME, "volume_context");
+        error
+      }),
+      3 => ::prost::encoding::message::merge_repeated(
+        wire_type,
+        &mut self.volume_capabilities,
+        buf,
+        ctx,
+      )
+      .map_err(|mut error| {
+        error.push(STRUCT_NAME, "volume_capabilities");
+        error
+      }),
+      4 => ::prost::encoding::hash_map::merge(
+        ::prost::encoding::string::merge,
+        ::prost::encoding::string::merge,
+        &mut self.parameters,
+        buf,
+        ctx,
+      )
+      .map_err(|mut error| {
+        error.push(STRUCT_NAME, "parameters");
+        error
+      }),
+      5 => ::prost::encoding::hash_map::merge(
+        ::prost::encoding::string::merge,
+        ::prost::encoding::string::merge,
+        &mut self.secrets,
+        buf,
+        ctx,
+      )
+      .map_err(|mut error| {
+        error.push(STRUCT_NAME, "secrets");
+        error
+      }),
+
+      _ => ::prost::encoding::skip_field(wire_type, tag, buf, ctx),
+    }
+  }
+
+  fn encoded_len(&self) -> usize {
+    let mut len = 0;
+    len += if !self.volume_id.is_empty() {
+      ::prost::encoding::string::encoded_len(1, &self.volume_id)
+    } else {
+      0
+    };
+    len += ::prost::encoding::hash_map::encoded_len(
+      ::prost::encoding::string::encoded_len,
+      ::prost::encoding::string::encoded_len,
+      2,
+      &self.volume_context,
+    );
+    len += ::prost::encoding::message::encoded_len_repeated(3, &self.volume_capabilities);
+    len += ::prost::encoding::hash_map::encoded_len(
+      ::prost::encoding::string::encoded_len,
+      ::prost::encoding::string::encoded_len,
+      4,
+      &self.parameters,
+    );
+    len += ::prost::encoding::hash_map::encoded_len(
+      ::prost::encoding::string::encoded_len,
+      ::prost::encoding::string::encoded_len,
+      5,
+      &self.secrets,
+    );
+
+    len
+  }
+
+  fn clear(&mut self) {
+    self.volume_id = ::core::default::Default::default();
+    self.volume_context.clear();
+    self.volume_capabilities.clear();
+    self.parameters.clear();
+    self.secrets.clear();
+  }
+}
+
+/// The fields marked with the `csi_secret` option are redacted.
+impl ::core::fmt::Debug for ValidateVolumeCapabilitiesRequest {
+  fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+    let mut builder = f.debug_struct("ValidateVolumeCapabilitiesRequest");
+    builder.field("volume_id", &self.volume_id);
+    builder.field("volume_context", &self.volume_context);
+    builder.field("volume_capabilities", &self.volume_capabilities);
+    builder.field("parameters", &self.parameters);
+    builder.field(
+      "secrets",
+      &self
+        .secrets
+        .keys()
+        .map(|k| (k, "SECRET"))
+        .collect::<::std::collections::BTreeMap<_, _>>(),
+    );
+
+    builder.finish()
   }
 }
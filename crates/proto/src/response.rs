@@ -0,0 +1,39 @@
+use crate::controller::ControllerCapabilities;
+#[cfg(feature = "alpha")]
+use crate::node::NodeCapabilities;
+use tracing::error;
+
+/// What a plugin advertises, which decides which fields its responses
+/// may, or must, contain.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Advertised {
+  pub(crate) accessibility_constraints: bool,
+  pub(crate) controller: ControllerCapabilities,
+  #[cfg(feature = "alpha")]
+  pub(crate) node: NodeCapabilities,
+}
+
+/// Checks a response of the plugin against the rules of the CSI spec that
+/// depend on what the plugin advertises. The rules that do not are
+/// checked when the response is built.
+pub(crate) trait ValidateResponse: Sized {
+  /// Describe the first rule the response breaks, if any.
+  fn validate(&self, advertised: &Advertised) -> Result<(), String>;
+
+  /// Pass the response through if it is valid. Otherwise fail with
+  /// `INTERNAL`, or panic in debug builds, as the plugin has a bug.
+  fn validated(self, method: &str, advertised: &Advertised) -> Result<Self, tonic::Status> {
+    match self.validate(advertised) {
+      Ok(()) => Ok(self),
+      Err(message) => {
+        let message = format!("Invalid {} response: {}", method, message);
+        error!("{}", message);
+        if cfg!(debug_assertions) {
+          panic!("{}", message);
+        }
+
+        Err(tonic::Status::internal(message))
+      }
+    }
+  }
+}
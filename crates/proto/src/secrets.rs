@@ -1,35 +1,457 @@
-use std::{collections::HashMap, fmt};
+use std::{
+  collections::{hash_map, HashMap},
+  fmt,
+};
+use zeroize::Zeroize;
 
-pub(crate) struct Secrets(HashMap<String, String>);
+/// A secret value, zeroized when dropped. Its `Debug` and `Display`
+/// implementations never reveal the value, use
+/// [`SecretString::expose_secret`] to get at it.
+#[derive(Clone, PartialEq, Eq)]
+pub struct SecretString(String);
 
-impl AsRef<HashMap<String, String>> for Secrets {
+impl SecretString {
+  /// The secret value. Take care not to log it, or keep copies of it
+  /// around for longer than needed.
   #[inline]
-  fn as_ref(&self) -> &HashMap<String, String> {
+  pub fn expose_secret(&self) -> &str {
     &self.0
   }
 }
 
+impl From<String> for SecretString {
+  #[inline]
+  fn from(v: String) -> Self {
+    SecretString(v)
+  }
+}
+
+impl Drop for SecretString {
+  #[inline]
+  fn drop(&mut self) {
+    self.0.zeroize();
+  }
+}
+
+impl fmt::Debug for SecretString {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    fmt::Debug::fmt("SECRET", f)
+  }
+}
+
+impl fmt::Display for SecretString {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.write_str("SECRET")
+  }
+}
+
+/// The secrets passed along with a request. The keys are not considered
+/// secret, the values are [`SecretString`]s.
+#[derive(Clone, Default)]
+pub struct Secrets(HashMap<String, SecretString>);
+
+impl Secrets {
+  /// The secret stored under `key`, if any.
+  #[inline]
+  pub fn get(&self, key: &str) -> Option<&SecretString> {
+    self.0.get(key)
+  }
+
+  /// The secret stored under `key`, failing with `INVALID_ARGUMENT` if it
+  /// is missing.
+  pub fn require(&self, key: &str) -> Result<&SecretString, tonic::Status> {
+    self
+      .get(key)
+      .ok_or_else(|| tonic::Status::invalid_argument(format!("Secret '{}' is missing", key)))
+  }
+
+  /// Extract the secrets into a typed value, see [`FromSecrets`].
+  #[inline]
+  pub fn extract<T: FromSecrets>(&self) -> Result<T, tonic::Status> {
+    T::from_secrets(self)
+  }
+
+  #[inline]
+  pub fn contains_key(&self, key: &str) -> bool {
+    self.0.contains_key(key)
+  }
+
+  #[inline]
+  pub fn keys(&self) -> impl Iterator<Item = &str> {
+    self.0.keys().map(String::as_str)
+  }
+
+  #[inline]
+  pub fn iter(&self) -> hash_map::Iter<'_, String, SecretString> {
+    self.0.iter()
+  }
+
+  #[inline]
+  pub fn len(&self) -> usize {
+    self.0.len()
+  }
+
+  #[inline]
+  pub fn is_empty(&self) -> bool {
+    self.0.is_empty()
+  }
+}
+
 impl From<HashMap<String, String>> for Secrets {
   #[inline]
   fn from(v: HashMap<String, String>) -> Self {
-    Secrets(v)
+    Secrets(v.into_iter().map(|(k, v)| (k, v.into())).collect())
   }
 }
 
-impl From<Secrets> for HashMap<String, String> {
+impl<'a> IntoIterator for &'a Secrets {
+  type Item = (&'a String, &'a SecretString);
+  type IntoIter = hash_map::Iter<'a, String, SecretString>;
+
   #[inline]
-  fn from(v: Secrets) -> Self {
-    v.0
+  fn into_iter(self) -> Self::IntoIter {
+    self.iter()
   }
 }
 
 impl fmt::Debug for Secrets {
   fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-    let mut m = f.debug_map();
-    for k in self.0.keys() {
-      m.key(k).value(&"SECRET");
-    }
+    f.debug_map().entries(self.0.iter()).finish()
+  }
+}
+
+/// A typed view of the secrets of a request, for example
+/// [`UsernamePassword`].
+pub trait FromSecrets: Sized {
+  /// Extract the value, failing with `INVALID_ARGUMENT` if the secrets
+  /// are missing or malformed.
+  fn from_secrets(secrets: &Secrets) -> Result<Self, tonic::Status>;
+}
+
+/// Credentials stored under the `username` and `password` keys.
+#[derive(Debug, Clone)]
+pub struct UsernamePassword {
+  pub username: SecretString,
+  pub password: SecretString,
+}
+
+impl FromSecrets for UsernamePassword {
+  fn from_secrets(secrets: &Secrets) -> Result<Self, tonic::Status> {
+    Ok(UsernamePassword {
+      username: secrets.require("username")?.clone(),
+      password: secrets.require("password")?.clone(),
+    })
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::proto;
+  use prost::Message;
+
+  fn secrets(entries: &[(&str, &str)]) -> Secrets {
+    entries
+      .iter()
+      .map(|(k, v)| (k.to_string(), v.to_string()))
+      .collect::<HashMap<_, _>>()
+      .into()
+  }
+
+  #[test]
+  fn redacts_values() {
+    let secrets = secrets(&[("password", "hunter2")]);
+    let password = secrets.get("password").unwrap();
+
+    assert_eq!(format!("{:?}", secrets), r#"{"password": "SECRET"}"#);
+    assert_eq!(format!("{}", password), "SECRET");
+    assert_eq!(password.expose_secret(), "hunter2");
+  }
+
+  #[test]
+  fn extracts_username_password() {
+    let credentials: UsernamePassword = secrets(&[("username", "admin"), ("password", "hunter2")])
+      .extract()
+      .unwrap();
+    assert_eq!(credentials.username.expose_secret(), "admin");
+    assert_eq!(credentials.password.expose_secret(), "hunter2");
+
+    let err = secrets(&[("username", "admin")])
+      .extract::<UsernamePassword>()
+      .unwrap_err();
+    assert_eq!(err.code(), tonic::Code::InvalidArgument);
+    assert_eq!(err.message(), "Secret 'password' is missing");
+  }
+
+  /// Check that `request` encodes exactly like `D`, the same message with
+  /// the derived `Message` implementation. Maps must have at most one
+  /// entry, as the order their entries are encoded in is random.
+  fn assert_encodes_like_derived<M, D>(request: &M)
+  where
+    M: Message + Default + PartialEq + std::fmt::Debug,
+    D: Message + Default,
+  {
+    let mut buf = Vec::new();
+    request.encode(&mut buf).unwrap();
+    assert_eq!(buf.len(), request.encoded_len());
+    assert_eq!(&M::decode(&*buf).unwrap(), request);
+
+    let derived = D::decode(&*buf).unwrap();
+    let mut derived_buf = Vec::new();
+    derived.encode(&mut derived_buf).unwrap();
+    assert_eq!(derived_buf, buf);
+  }
+
+  fn map(key: &str, value: &str) -> HashMap<String, String> {
+    vec![(key.to_owned(), value.to_owned())]
+      .into_iter()
+      .collect()
+  }
+
+  /// The layout of `NodeStageVolumeRequest`, with the derived `Message`
+  /// implementation.
+  #[derive(Clone, PartialEq, Message)]
+  struct DerivedNodeStageVolumeRequest {
+    #[prost(string, tag = "1")]
+    volume_id: String,
+    #[prost(map = "string, string", tag = "2")]
+    publish_context: HashMap<String, String>,
+    #[prost(string, tag = "3")]
+    staging_target_path: String,
+    #[prost(message, optional, tag = "4")]
+    volume_capability: Option<proto::VolumeCapability>,
+    #[prost(map = "string, string", tag = "5")]
+    secrets: HashMap<String, String>,
+    #[prost(map = "string, string", tag = "6")]
+    volume_context: HashMap<String, String>,
+  }
+
+  #[test]
+  fn redacts_raw_requests() {
+    let request = proto::NodeStageVolumeRequest {
+      volume_id: "vol-1".into(),
+      publish_context: map("device", "/dev/sdb"),
+      staging_target_path: "/staging".into(),
+      volume_capability: Some(proto::VolumeCapability::default()),
+      secrets: map("password", "hunter2"),
+      volume_context: map("pool", "fast"),
+    };
+
+    let debug = format!("{:?}", request);
+    assert!(!debug.contains("hunter2"), "{}", debug);
+    assert!(
+      debug.contains(r#"secrets: {"password": "SECRET"}"#),
+      "{}",
+      debug
+    );
+
+    assert_encodes_like_derived::<_, DerivedNodeStageVolumeRequest>(&request);
+  }
+
+  /// The layout of `CreateVolumeRequest`, with the derived `Message`
+  /// implementation.
+  #[derive(Clone, PartialEq, Message)]
+  struct DerivedCreateVolumeRequest {
+    #[prost(string, tag = "1")]
+    name: String,
+    #[prost(message, optional, tag = "2")]
+    capacity_range: Option<proto::CapacityRange>,
+    #[prost(message, repeated, tag = "3")]
+    volume_capabilities: Vec<proto::VolumeCapability>,
+    #[prost(map = "string, string", tag = "4")]
+    parameters: HashMap<String, String>,
+    #[prost(map = "string, string", tag = "5")]
+    secrets: HashMap<String, String>,
+    #[prost(message, optional, tag = "6")]
+    volume_content_source: Option<proto::VolumeContentSource>,
+    #[prost(message, optional, tag = "7")]
+    accessibility_requirements: Option<proto::TopologyRequirement>,
+  }
+
+  #[test]
+  fn encodes_message_fields_like_derived() {
+    let request = proto::CreateVolumeRequest {
+      name: "vol-1".into(),
+      capacity_range: Some(proto::CapacityRange {
+        required_bytes: 1 << 30,
+        limit_bytes: 0,
+      }),
+      volume_capabilities: vec![
+        proto::VolumeCapability::default(),
+        proto::VolumeCapability {
+          access_mode: Some(proto::volume_capability::AccessMode { mode: 1 }),
+          ..Default::default()
+        },
+      ],
+      parameters: map("pool", "fast"),
+      secrets: map("password", "hunter2"),
+      volume_content_source: Some(proto::VolumeContentSource::default()),
+      accessibility_requirements: Some(proto::TopologyRequirement::default()),
+    };
+
+    assert!(!format!("{:?}", request).contains("hunter2"));
+    assert_encodes_like_derived::<_, DerivedCreateVolumeRequest>(&request);
+    assert_encodes_like_derived::<_, DerivedCreateVolumeRequest>(
+      &proto::CreateVolumeRequest::default(),
+    );
+  }
+
+  /// The layout of `NodePublishVolumeRequest`, with the derived `Message`
+  /// implementation.
+  #[derive(Clone, PartialEq, Message)]
+  struct DerivedNodePublishVolumeRequest {
+    #[prost(string, tag = "1")]
+    volume_id: String,
+    #[prost(map = "string, string", tag = "2")]
+    publish_context: HashMap<String, String>,
+    #[prost(string, tag = "3")]
+    staging_target_path: String,
+    #[prost(string, tag = "4")]
+    target_path: String,
+    #[prost(message, optional, tag = "5")]
+    volume_capability: Option<proto::VolumeCapability>,
+    #[prost(bool, tag = "6")]
+    readonly: bool,
+    #[prost(map = "string, string", tag = "7")]
+    secrets: HashMap<String, String>,
+    #[prost(map = "string, string", tag = "8")]
+    volume_context: HashMap<String, String>,
+  }
+
+  #[test]
+  fn encodes_bool_fields_like_derived() {
+    let request = proto::NodePublishVolumeRequest {
+      volume_id: "vol-1".into(),
+      publish_context: map("device", "/dev/sdb"),
+      staging_target_path: "/staging".into(),
+      target_path: "/target".into(),
+      volume_capability: Some(proto::VolumeCapability::default()),
+      readonly: true,
+      secrets: map("password", "hunter2"),
+      volume_context: map("pool", "fast"),
+    };
+
+    assert_encodes_like_derived::<_, DerivedNodePublishVolumeRequest>(&request);
+  }
+
+  /// The layout of `ListSnapshotsRequest`, with the derived `Message`
+  /// implementation.
+  #[derive(Clone, PartialEq, Message)]
+  struct DerivedListSnapshotsRequest {
+    #[prost(int32, tag = "1")]
+    max_entries: i32,
+    #[prost(string, tag = "2")]
+    starting_token: String,
+    #[prost(string, tag = "3")]
+    source_volume_id: String,
+    #[prost(string, tag = "4")]
+    snapshot_id: String,
+    #[prost(map = "string, string", tag = "5")]
+    secrets: HashMap<String, String>,
+  }
+
+  #[test]
+  fn encodes_integer_fields_like_derived() {
+    let request = proto::ListSnapshotsRequest {
+      max_entries: 100,
+      starting_token: "token".into(),
+      source_volume_id: "vol-1".into(),
+      snapshot_id: "snap-1".into(),
+      secrets: map("password", "hunter2"),
+    };
+
+    assert_encodes_like_derived::<_, DerivedListSnapshotsRequest>(&request);
+  }
+
+  /// The layout of `NodeExpandVolumeRequest`, with the derived `Message`
+  /// implementation.
+  #[derive(Clone, PartialEq, Message)]
+  struct DerivedNodeExpandVolumeRequest {
+    #[prost(string, tag = "1")]
+    volume_id: String,
+    #[prost(string, tag = "2")]
+    volume_path: String,
+    #[prost(message, optional, tag = "3")]
+    capacity_range: Option<proto::CapacityRange>,
+    #[prost(string, tag = "4")]
+    staging_target_path: String,
+    #[prost(message, optional, tag = "5")]
+    volume_capability: Option<proto::VolumeCapability>,
+    #[prost(map = "string, string", tag = "6")]
+    secrets: HashMap<String, String>,
+  }
+
+  #[test]
+  fn redacts_node_expand_secrets() {
+    let request = proto::NodeExpandVolumeRequest {
+      volume_id: "vol-1".into(),
+      volume_path: "/target".into(),
+      capacity_range: Some(proto::CapacityRange::default()),
+      staging_target_path: "/staging".into(),
+      volume_capability: Some(proto::VolumeCapability::default()),
+      secrets: map("password", "hunter2"),
+    };
+
+    assert!(!format!("{:?}", request).contains("hunter2"));
+    assert_encodes_like_derived::<_, DerivedNodeExpandVolumeRequest>(&request);
+  }
+
+  /// The layout of `CreateVolumeGroupSnapshotRequest`, with the derived
+  /// `Message` implementation.
+  #[cfg(feature = "alpha")]
+  #[derive(Clone, PartialEq, Message)]
+  struct DerivedCreateVolumeGroupSnapshotRequest {
+    #[prost(string, tag = "1")]
+    name: String,
+    #[prost(string, repeated, tag = "2")]
+    source_volume_ids: Vec<String>,
+    #[prost(map = "string, string", tag = "3")]
+    secrets: HashMap<String, String>,
+    #[prost(map = "string, string", tag = "4")]
+    parameters: HashMap<String, String>,
+  }
+
+  #[cfg(feature = "alpha")]
+  #[test]
+  fn encodes_repeated_strings_like_derived() {
+    let request = proto::CreateVolumeGroupSnapshotRequest {
+      name: "group-1".into(),
+      source_volume_ids: vec!["vol-1".into(), String::new(), "vol-2".into()],
+      secrets: map("password", "hunter2"),
+      parameters: map("pool", "fast"),
+    };
+
+    assert_encodes_like_derived::<_, DerivedCreateVolumeGroupSnapshotRequest>(&request);
+  }
+
+  /// The layout of `GetMetadataDeltaRequest`, with the derived `Message`
+  /// implementation.
+  #[cfg(feature = "alpha")]
+  #[derive(Clone, PartialEq, Message)]
+  struct DerivedGetMetadataDeltaRequest {
+    #[prost(string, tag = "1")]
+    base_snapshot_id: String,
+    #[prost(string, tag = "2")]
+    target_snapshot_id: String,
+    #[prost(int64, tag = "3")]
+    starting_offset: i64,
+    #[prost(int32, tag = "4")]
+    max_results: i32,
+    #[prost(map = "string, string", tag = "5")]
+    secrets: HashMap<String, String>,
+  }
+
+  #[cfg(feature = "alpha")]
+  #[test]
+  fn encodes_negative_integers_like_derived() {
+    let request = proto::GetMetadataDeltaRequest {
+      base_snapshot_id: "snap-1".into(),
+      target_snapshot_id: "snap-2".into(),
+      starting_offset: -1,
+      max_results: -1,
+      secrets: map("password", "hunter2"),
+    };
 
-    m.finish()
+    assert_encodes_like_derived::<_, DerivedGetMetadataDeltaRequest>(&request);
   }
 }
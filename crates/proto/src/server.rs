@@ -0,0 +1,428 @@
+mod context;
+mod dispatch;
+mod endpoint;
+mod limits;
+#[cfg(feature = "metrics")]
+mod metrics;
+#[cfg(feature = "opentelemetry")]
+mod propagation;
+#[cfg(feature = "alpha")]
+mod route;
+mod trace;
+
+use crate::{
+  controller::{Controller, ControllerCapabilities},
+  node::{Node, NodeCapabilities},
+  plugin::{Identity, Services},
+  proto, ControllerService, IdentityService, NodeService, VolumeExpansionSupport,
+};
+#[cfg(feature = "alpha")]
+use crate::{
+  group_controller::GroupController, snapshot_metadata::SnapshotMetadata, GroupControllerService,
+  SnapshotMetadataService,
+};
+use futures::{
+  future::{self, BoxFuture, Either},
+  TryFutureExt,
+};
+#[cfg(feature = "metrics")]
+use std::net::SocketAddr;
+use std::{future::Future, io, sync::Arc, time::Duration};
+use thiserror::Error;
+use tokio::{sync::oneshot, time::timeout};
+use tonic::{
+  body::BoxBody,
+  codegen::{http, Service, StdError},
+  transport::{server::Router, Body, Server},
+};
+use tracing::{info, warn};
+
+pub use context::{PeerCredentials, RequestContext};
+#[cfg(feature = "alpha")]
+pub(crate) use dispatch::Call;
+pub(crate) use dispatch::{Dispatch, OperationKey};
+pub use endpoint::*;
+pub use limits::SizeLimits;
+#[cfg(feature = "alpha")]
+use route::{GroupControllerName, Route, SnapshotMetadataName};
+#[cfg(feature = "alpha")]
+pub(crate) use trace::Trace;
+pub use trace::TraceFormat;
+
+/// The default time in-flight calls are given to complete once shutdown
+/// has been requested.
+pub const DEFAULT_DRAIN_DEADLINE: Duration = Duration::from_secs(20);
+
+pub type Result<T> = std::result::Result<T, ServerError>;
+
+#[non_exhaustive]
+#[derive(Debug, Error)]
+pub enum ServerError {
+  #[error("Failed to bind to {0}: {1}")]
+  Bind(Endpoint, #[source] io::Error),
+
+  #[error("gRPC transport error: {0}")]
+  Transport(#[from] tonic::transport::Error),
+
+  #[cfg(feature = "metrics")]
+  #[error("Failed to bind the metrics listener to {0}: {1}")]
+  MetricsBind(SocketAddr, #[source] hyper::Error),
+
+  #[cfg(feature = "metrics")]
+  #[error("Metrics listener error: {0}")]
+  Metrics(#[source] hyper::Error),
+
+  /// Shutdown was requested, but some calls did not complete before the
+  /// drain deadline. Contains the names of the methods still running.
+  #[error("Drain deadline exceeded with calls still running: {0:?}")]
+  DrainDeadlineExceeded(Vec<&'static str>),
+}
+
+/// Builder for a gRPC server exposing a CSI plugin.
+///
+/// The server always serves a single `Identity` service, and in addition
+/// the `Controller` and/or `Node` service depending on which `serve_*`
+/// method is used to start it. With the `alpha` feature, the
+/// `GroupController` and `SnapshotMetadata` services are served next to the
+/// `Controller` service when enabled with `PluginServer::group_controller`
+/// and `PluginServer::snapshot_metadata`.
+///
+/// When the shutdown signal set with [`PluginServer::shutdown_signal`]
+/// completes, the server stops accepting connections, refuses new calls
+/// with `UNAVAILABLE`, and waits up to the drain deadline for in-flight
+/// calls to complete. A unix domain socket is removed when the server
+/// exits.
+///
+/// With the `metrics` feature, Prometheus metrics of every call can be
+/// served over HTTP with [`PluginServer::metrics_address`].
+pub struct PluginServer<T: IdentityService> {
+  plugin: Arc<T>,
+  server: Server,
+  dispatch: Arc<Dispatch>,
+  #[cfg(feature = "alpha")]
+  group_controller: Option<Route<GroupControllerName>>,
+  #[cfg(feature = "alpha")]
+  snapshot_metadata: Option<Route<SnapshotMetadataName>>,
+  socket_permissions: Option<u32>,
+  #[cfg(feature = "metrics")]
+  metrics_address: Option<SocketAddr>,
+  shutdown: Option<BoxFuture<'static, ()>>,
+  drain_deadline: Duration,
+}
+
+impl<T: IdentityService> PluginServer<T> {
+  /// Create a new server for the given plugin.
+  #[inline]
+  pub fn new(plugin: Arc<T>) -> Self {
+    PluginServer {
+      plugin,
+      server: Server::builder(),
+      dispatch: Arc::new(Dispatch::default()),
+      #[cfg(feature = "alpha")]
+      group_controller: None,
+      #[cfg(feature = "alpha")]
+      snapshot_metadata: None,
+      socket_permissions: None,
+      #[cfg(feature = "metrics")]
+      metrics_address: None,
+      shutdown: None,
+      drain_deadline: DEFAULT_DRAIN_DEADLINE,
+    }
+  }
+
+  /// Set the file permissions of the unix domain socket the server
+  /// listens on, for example `0o660`. By default the permissions are
+  /// left as created by the process umask.
+  #[inline]
+  pub fn socket_permissions(mut self, mode: u32) -> Self {
+    self.socket_permissions = Some(mode);
+    self
+  }
+
+  /// Shut the server down gracefully when `signal` completes, typically
+  /// on `SIGTERM`. Without a signal the server runs until it fails.
+  #[inline]
+  pub fn shutdown_signal<F>(mut self, signal: F) -> Self
+  where
+    F: Future<Output = ()> + Send + 'static,
+  {
+    self.shutdown = Some(Box::pin(signal));
+    self
+  }
+
+  /// Set how long in-flight calls are given to complete after the
+  /// shutdown signal. Defaults to [`DEFAULT_DRAIN_DEADLINE`].
+  #[inline]
+  pub fn drain_deadline(mut self, deadline: Duration) -> Self {
+    self.drain_deadline = deadline;
+    self
+  }
+
+  /// Reject a call with `ABORTED` while another call is pending for the
+  /// same volume or snapshot, before it reaches the plugin. Calls are
+  /// keyed by:
+  ///
+  /// * `name` for `CreateVolume` and `CreateSnapshot`;
+  /// * `volume_id` for `DeleteVolume`, `ControllerPublishVolume`,
+  ///   `ControllerUnpublishVolume`, `ControllerExpandVolume` and the
+  ///   node stage, unstage and expand calls;
+  /// * `volume_id` and `target_path` for `NodePublishVolume` and
+  ///   `NodeUnpublishVolume`, as a volume may be published at several
+  ///   target paths at the same time;
+  /// * `snapshot_id` for `DeleteSnapshot`.
+  #[inline]
+  pub fn operation_locking(self) -> Self {
+    self.dispatch.enable_locking();
+    self
+  }
+
+  /// Set the size limits on the strings and maps of requests and
+  /// responses. Defaults to [`SizeLimits::SPEC`], lenient COs may need
+  /// larger limits.
+  #[inline]
+  pub fn size_limits(self, limits: SizeLimits) -> Self {
+    self.dispatch.set_limits(limits);
+    self
+  }
+
+  /// Set how requests and responses are recorded on the span of each
+  /// call. Defaults to [`TraceFormat::Debug`].
+  #[inline]
+  pub fn trace_format(self, format: TraceFormat) -> Self {
+    self.dispatch.set_trace_format(format);
+    self
+  }
+
+  /// Serve Prometheus metrics on `/metrics` over HTTP at `addr`, next to
+  /// the gRPC endpoint. The metrics are:
+  ///
+  /// * `csi_plugin_requests_total`, a counter of completed calls labelled
+  ///   by `method` and gRPC status `code`;
+  /// * `csi_plugin_request_duration_seconds`, a histogram of the latency of
+  ///   completed calls with the same labels;
+  /// * `csi_plugin_requests_in_flight`, a gauge of the calls currently
+  ///   in-flight labelled by `method`.
+  #[cfg(feature = "metrics")]
+  #[inline]
+  pub fn metrics_address(mut self, addr: SocketAddr) -> Self {
+    self.metrics_address = Some(addr);
+    self
+  }
+
+  #[inline]
+  fn identity(
+    &self,
+    controller_service: bool,
+  ) -> proto::identity_server::IdentityServer<Identity<T>> {
+    let services = Services {
+      controller: controller_service,
+      #[cfg(feature = "alpha")]
+      group_controller: controller_service && self.group_controller.is_some(),
+      #[cfg(feature = "alpha")]
+      snapshot_metadata: controller_service && self.snapshot_metadata.is_some(),
+    };
+
+    proto::identity_server::IdentityServer::new(Identity::new(
+      self.plugin.clone(),
+      self.dispatch.clone(),
+      services,
+    ))
+  }
+
+  async fn serve_router<A, B>(self, router: Router<A, B>, endpoint: Endpoint) -> Result<()>
+  where
+    A: Service<http::Request<Body>, Response = http::Response<BoxBody>> + Clone + Send + 'static,
+    A::Future: Send + 'static,
+    A::Error: Into<StdError> + Send,
+    B: Service<http::Request<Body>, Response = http::Response<BoxBody>> + Clone + Send + 'static,
+    B::Future: Send + 'static,
+    B::Error: Into<StdError> + Send,
+  {
+    info!(
+      name = self.plugin.name(),
+      version = self.plugin.version(),
+      %endpoint,
+      "Starting CSI plugin server"
+    );
+
+    #[cfg(feature = "metrics")]
+    let metrics = match self.metrics_address {
+      Some(addr) => {
+        info!(%addr, "Serving metrics");
+        Either::Left(
+          metrics::serve(self.dispatch.metrics(), addr)
+            .map_err(|e| ServerError::MetricsBind(addr, e))?
+            .map_err(ServerError::Metrics),
+        )
+      }
+      None => Either::Right(future::pending()),
+    };
+
+    self
+      .dispatch
+      .set_unix_endpoint(endpoint.unix_path().is_some());
+    let incoming = endpoint
+      .bind(self.socket_permissions)
+      .map_err(|e| ServerError::Bind(endpoint.clone(), e))?;
+
+    let (stop_tx, stop_rx) = oneshot::channel::<()>();
+    let serve = router
+      .serve_with_incoming_shutdown(incoming, async {
+        let _ = stop_rx.await;
+      })
+      .map_err(ServerError::from);
+
+    // The metrics listener holds no state worth shutting down gracefully,
+    // it is dropped once the gRPC server stops.
+    #[cfg(feature = "metrics")]
+    let serve = future::try_select(Box::pin(serve), Box::pin(metrics))
+      .map_ok(|_| ())
+      .map_err(|e| e.factor_first().0);
+    let shutdown = self.shutdown.unwrap_or_else(|| Box::pin(future::pending()));
+
+    let result = match future::select(Box::pin(serve), shutdown).await {
+      Either::Left((result, _)) => result,
+      Either::Right(((), serve)) => {
+        info!(deadline = ?self.drain_deadline, "Shutdown requested, draining in-flight calls");
+        self.dispatch.drain();
+        let _ = stop_tx.send(());
+
+        match timeout(self.drain_deadline, serve).await {
+          Ok(result) => result,
+          Err(_) => {
+            let running = self.dispatch.running();
+            warn!(?running, "Drain deadline exceeded");
+            if running.is_empty() {
+              Ok(())
+            } else {
+              Err(ServerError::DrainDeadlineExceeded(running))
+            }
+          }
+        }
+      }
+    };
+
+    if let Err(e) = endpoint.remove_socket() {
+      warn!(%endpoint, error = %e, "Failed to remove socket file");
+    }
+
+    info!(%endpoint, "CSI plugin server stopped");
+    result
+  }
+}
+
+impl<T: ControllerService> PluginServer<T> {
+  #[inline]
+  fn controller(&self) -> proto::controller_server::ControllerServer<Controller<T>> {
+    proto::controller_server::ControllerServer::new(Controller {
+      plugin: self.plugin.clone(),
+      dispatch: self.dispatch.clone(),
+    })
+  }
+
+  /// Serve the `Identity` and `Controller` services on `endpoint`.
+  pub async fn serve_controller(mut self, endpoint: Endpoint) -> Result<()> {
+    ControllerCapabilities::check(&*self.plugin);
+    let router = self
+      .server
+      .add_service(self.identity(true))
+      .add_service(self.controller());
+    #[cfg(feature = "alpha")]
+    let router = router
+      .add_optional_service(self.group_controller.clone())
+      .add_optional_service(self.snapshot_metadata.clone());
+
+    self.serve_router(router, endpoint).await
+  }
+}
+
+#[cfg(feature = "alpha")]
+impl<T: GroupControllerService> PluginServer<T> {
+  /// Also serve the `GroupController` service, and advertise the
+  /// `GROUP_CONTROLLER_SERVICE` plugin capability, whenever the
+  /// `Controller` service is served. The capability is only advertised
+  /// if the plugin enables alpha features.
+  pub fn group_controller(mut self) -> Self {
+    if !self.plugin.enable_alpha_features() {
+      warn!("GroupController service enabled, but it is not advertised as alpha features are not enabled by the plugin");
+    }
+
+    self.group_controller = Some(Route::new(
+      proto::group_controller_server::GroupControllerServer::new(GroupController {
+        plugin: self.plugin.clone(),
+        dispatch: self.dispatch.clone(),
+      }),
+    ));
+    self
+  }
+}
+
+#[cfg(feature = "alpha")]
+impl<T: SnapshotMetadataService> PluginServer<T> {
+  /// Also serve the `SnapshotMetadata` service, and advertise the
+  /// `SNAPSHOT_METADATA_SERVICE` plugin capability, whenever the
+  /// `Controller` service is served. The capability is only advertised
+  /// if the plugin enables alpha features.
+  pub fn snapshot_metadata(mut self) -> Self {
+    if !self.plugin.enable_alpha_features() {
+      warn!("SnapshotMetadata service enabled, but it is not advertised as alpha features are not enabled by the plugin");
+    }
+
+    self.snapshot_metadata = Some(Route::new(
+      proto::snapshot_metadata_server::SnapshotMetadataServer::new(SnapshotMetadata {
+        plugin: self.plugin.clone(),
+        dispatch: self.dispatch.clone(),
+      }),
+    ));
+    self
+  }
+}
+
+impl<T: NodeService> PluginServer<T> {
+  #[inline]
+  fn node(&self) -> proto::node_server::NodeServer<Node<T>> {
+    proto::node_server::NodeServer::new(Node {
+      plugin: self.plugin.clone(),
+      dispatch: self.dispatch.clone(),
+    })
+  }
+
+  /// Serve the `Identity` and `Node` services on `endpoint`.
+  pub async fn serve_node(mut self, endpoint: Endpoint) -> Result<()> {
+    NodeCapabilities::check(&*self.plugin);
+    let router = self
+      .server
+      .add_service(self.identity(false))
+      .add_service(self.node());
+
+    self.serve_router(router, endpoint).await
+  }
+}
+
+impl<T: ControllerService + NodeService> PluginServer<T> {
+  /// Serve the `Identity`, `Controller` and `Node` services on `endpoint`,
+  /// for plugins that run both in the same process.
+  pub async fn serve_controller_and_node(mut self, endpoint: Endpoint) -> Result<()> {
+    ControllerCapabilities::check(&*self.plugin);
+    NodeCapabilities::check(&*self.plugin);
+    if self.plugin.volume_expansion_support() != VolumeExpansionSupport::None
+      && !ControllerCapabilities::advertised(&*self.plugin)
+        .contains(ControllerCapabilities::EXPAND_VOLUME)
+      && !NodeCapabilities::advertised(&*self.plugin).contains(NodeCapabilities::EXPAND_VOLUME)
+    {
+      warn!("The plugin supports volume expansion, but neither the controller nor the node EXPAND_VOLUME capability is advertised");
+    }
+
+    let router = self
+      .server
+      .add_service(self.identity(true))
+      .add_service(self.controller())
+      .add_service(self.node());
+    #[cfg(feature = "alpha")]
+    let router = router
+      .add_optional_service(self.group_controller.clone())
+      .add_optional_service(self.snapshot_metadata.clone());
+
+    self.serve_router(router, endpoint).await
+  }
+}
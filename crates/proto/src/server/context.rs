@@ -0,0 +1,302 @@
+use futures::future::{self, Either};
+use std::{
+  future::Future,
+  net::{Ipv6Addr, SocketAddr, SocketAddrV6},
+  time::Duration,
+};
+use tokio::{net::unix::UCred, time::Instant};
+use tonic::metadata::MetadataMap;
+
+tokio::task_local! {
+  static CONTEXT: RequestContext;
+}
+
+/// The gRPC header carrying the timeout the caller set on a call.
+const GRPC_TIMEOUT: &str = "grpc-timeout";
+
+/// The prefix of the addresses peer credentials are carried in, the
+/// discard-only prefix of RFC 6666, which no peer connects from.
+const PEER_CREDENTIALS_PREFIX: u64 = 0x0100_0000_0000_0000;
+
+/// Context of the call being served: the gRPC metadata, the deadline and
+/// the identity of the caller.
+///
+/// The context is reachable with [`RequestContext::current`] from the
+/// task serving a call to the plugin, so plugins do not have to thread
+/// it through their own code:
+///
+/// ```no_run
+/// # use csi_proto::server::RequestContext;
+/// # async fn backend_call() {}
+/// # async fn create_volume() -> Result<(), tonic::Status> {
+/// if let Some(cx) = RequestContext::current() {
+///   if let Some(peer) = cx.peer_credentials() {
+///     tracing::info!(uid = peer.uid, pid = ?peer.pid, "CreateVolume");
+///   }
+///
+///   // Give up on the backend once the caller stopped waiting.
+///   cx.within_deadline(backend_call()).await?;
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct RequestContext {
+  metadata: MetadataMap,
+  deadline: Option<Instant>,
+  remote_addr: Option<SocketAddr>,
+  peer_credentials: Option<PeerCredentials>,
+}
+
+/// Credentials of the process on the other end of a unix domain socket,
+/// as reported by `SO_PEERCRED`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PeerCredentials {
+  pub uid: u32,
+  pub gid: u32,
+  /// The process id, if the platform reports it.
+  pub pid: Option<i32>,
+}
+
+impl RequestContext {
+  /// Capture the context of `request`, received now on a unix domain
+  /// socket endpoint if `unix` is set, on a TCP endpoint otherwise.
+  pub(crate) fn new<T>(request: &tonic::Request<T>, unix: bool) -> Self {
+    let metadata = request.metadata().clone();
+    let deadline = metadata
+      .get(GRPC_TIMEOUT)
+      .and_then(|v| v.to_str().ok())
+      .and_then(parse_timeout)
+      .and_then(|timeout| Instant::now().checked_add(timeout));
+
+    let (remote_addr, peer_credentials) = split_remote_addr(request.remote_addr(), unix);
+
+    RequestContext {
+      metadata,
+      deadline,
+      remote_addr,
+      peer_credentials,
+    }
+  }
+
+  /// The context of the call served by the current task, if any.
+  pub fn current() -> Option<Self> {
+    CONTEXT.try_with(Clone::clone).ok()
+  }
+
+  /// Run `f` with this context as the current context.
+  pub(crate) async fn scope<F: Future>(self, f: F) -> F::Output {
+    CONTEXT.scope(self, f).await
+  }
+
+  /// The gRPC metadata the caller sent with the call.
+  #[inline]
+  pub fn metadata(&self) -> &MetadataMap {
+    &self.metadata
+  }
+
+  /// The time the caller stops waiting for the call, if it set a timeout.
+  #[inline]
+  pub fn deadline(&self) -> Option<Instant> {
+    self.deadline
+  }
+
+  /// The time left until the deadline, if the caller set a timeout.
+  pub fn remaining(&self) -> Option<Duration> {
+    self
+      .deadline
+      .map(|deadline| deadline.saturating_duration_since(Instant::now()))
+  }
+
+  /// Complete once the deadline has passed. Never completes if the caller
+  /// set no timeout.
+  pub async fn deadline_exceeded(&self) {
+    match self.deadline {
+      Some(deadline) => tokio::time::sleep_until(deadline).await,
+      None => future::pending().await,
+    }
+  }
+
+  /// Run `f` until the deadline, failing with `DEADLINE_EXCEEDED` if it
+  /// has not completed by then. `f` is dropped when the deadline passes.
+  pub async fn within_deadline<F: Future>(&self, f: F) -> Result<F::Output, tonic::Status> {
+    match future::select(Box::pin(f), Box::pin(self.deadline_exceeded())).await {
+      Either::Left((output, _)) => Ok(output),
+      Either::Right(((), _)) => Err(tonic::Status::deadline_exceeded("Deadline exceeded")),
+    }
+  }
+
+  /// The address of the caller, on TCP endpoints.
+  #[inline]
+  pub fn remote_addr(&self) -> Option<SocketAddr> {
+    self.remote_addr
+  }
+
+  /// The credentials of the caller, on unix domain socket endpoints.
+  #[inline]
+  pub fn peer_credentials(&self) -> Option<PeerCredentials> {
+    self.peer_credentials
+  }
+}
+
+impl PeerCredentials {
+  // tonic only hands the remote address of a connection on to the
+  // requests served on it, so the credentials of a unix domain socket
+  // peer are carried in a synthetic address: the uid and gid in the
+  // interface identifier of an address in the discard-only prefix, and
+  // the pid in the scope id.
+
+  /// Encode the credentials as the remote address of a connection.
+  pub(crate) fn to_addr(self) -> SocketAddr {
+    let ip =
+      u128::from(PEER_CREDENTIALS_PREFIX) << 64 | u128::from(self.uid) << 32 | u128::from(self.gid);
+    let pid = self.pid.map_or(0, |pid| pid as u32);
+    SocketAddrV6::new(Ipv6Addr::from(ip), 0, 0, pid).into()
+  }
+
+  /// Decode the credentials from the remote address of a connection, if
+  /// it carries any.
+  pub(crate) fn from_addr(addr: &SocketAddr) -> Option<Self> {
+    let addr = match addr {
+      SocketAddr::V6(addr) => addr,
+      SocketAddr::V4(_) => return None,
+    };
+
+    let ip = u128::from(*addr.ip());
+    if (ip >> 64) as u64 != PEER_CREDENTIALS_PREFIX {
+      return None;
+    }
+
+    Some(PeerCredentials {
+      uid: (ip >> 32) as u32,
+      gid: ip as u32,
+      pid: match addr.scope_id() {
+        0 => None,
+        pid => Some(pid as i32),
+      },
+    })
+  }
+}
+
+impl From<UCred> for PeerCredentials {
+  fn from(cred: UCred) -> Self {
+    PeerCredentials {
+      uid: cred.uid(),
+      gid: cred.gid(),
+      pid: cred.pid(),
+    }
+  }
+}
+
+/// Split the remote address of a connection into the address of a TCP
+/// peer and the credentials of a unix domain socket peer. Credentials are
+/// only decoded on unix domain socket endpoints, as a TCP peer picks the
+/// address it connects from.
+fn split_remote_addr(
+  remote_addr: Option<SocketAddr>,
+  unix: bool,
+) -> (Option<SocketAddr>, Option<PeerCredentials>) {
+  if unix {
+    (
+      None,
+      remote_addr.as_ref().and_then(PeerCredentials::from_addr),
+    )
+  } else {
+    (remote_addr, None)
+  }
+}
+
+/// Parse a `grpc-timeout` header: at most 8 digits followed by a unit.
+fn parse_timeout(value: &str) -> Option<Duration> {
+  if value.len() < 2 || value.len() > 9 {
+    return None;
+  }
+
+  let (digits, unit) = value.split_at(value.len() - 1);
+  if !digits.bytes().all(|b| b.is_ascii_digit()) {
+    return None;
+  }
+
+  let amount = digits.parse::<u64>().ok()?;
+  let timeout = match unit {
+    "H" => Duration::from_secs(amount * 60 * 60),
+    "M" => Duration::from_secs(amount * 60),
+    "S" => Duration::from_secs(amount),
+    "m" => Duration::from_millis(amount),
+    "u" => Duration::from_micros(amount),
+    "n" => Duration::from_nanos(amount),
+    _ => return None,
+  };
+
+  Some(timeout)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use test_case::test_case;
+
+  #[test_case("1H" => Some(Duration::from_secs(3600)) ; "hours")]
+  #[test_case("30S" => Some(Duration::from_secs(30)) ; "seconds")]
+  #[test_case("250m" => Some(Duration::from_millis(250)) ; "milliseconds")]
+  #[test_case("99999999n" => Some(Duration::from_nanos(99_999_999)) ; "eight digits")]
+  #[test_case("100000000n" => None ; "nine digits")]
+  #[test_case("10" => None ; "no unit")]
+  #[test_case("-1S" => None ; "negative")]
+  #[test_case("1d" => None ; "unknown unit")]
+  fn parses_timeout(value: &str) -> Option<Duration> {
+    parse_timeout(value)
+  }
+
+  #[test]
+  fn carries_peer_credentials_in_addr() {
+    for pid in [Some(1234), None] {
+      let peer = PeerCredentials {
+        uid: 1000,
+        gid: u32::MAX,
+        pid,
+      };
+      assert_eq!(PeerCredentials::from_addr(&peer.to_addr()), Some(peer));
+    }
+  }
+
+  #[test]
+  fn tcp_addr_carries_no_peer_credentials() {
+    assert_eq!(
+      PeerCredentials::from_addr(&"[::1]:10000".parse().unwrap()),
+      None
+    );
+  }
+
+  #[test]
+  fn tcp_peer_cannot_claim_credentials() {
+    let peer = PeerCredentials {
+      uid: 0,
+      gid: 0,
+      pid: Some(1),
+    };
+    let addr = peer.to_addr();
+
+    assert_eq!(split_remote_addr(Some(addr), false), (Some(addr), None));
+    assert_eq!(split_remote_addr(Some(addr), true), (None, Some(peer)));
+  }
+
+  #[tokio::test]
+  async fn current_in_scope_only() {
+    let mut request = tonic::Request::new(());
+    request
+      .metadata_mut()
+      .insert(GRPC_TIMEOUT, "10m".parse().unwrap());
+    let cx = RequestContext::new(&request, false);
+    assert!(cx.remaining().unwrap() <= Duration::from_millis(10));
+
+    let result = cx
+      .scope(async {
+        let cx = RequestContext::current().unwrap();
+        cx.within_deadline(future::pending::<()>()).await
+      })
+      .await;
+    assert_eq!(result.unwrap_err().code(), tonic::Code::DeadlineExceeded);
+    assert!(RequestContext::current().is_none());
+  }
+}
@@ -0,0 +1,364 @@
+#[cfg(feature = "metrics")]
+use super::metrics::Metrics;
+use super::{
+  context::RequestContext,
+  limits::{CheckSize, SizeLimits},
+  trace::{Trace, TraceFormat},
+};
+use std::{
+  collections::{HashMap, HashSet},
+  fmt,
+  future::Future,
+  path::PathBuf,
+  sync::{
+    atomic::{AtomicBool, AtomicU64, Ordering},
+    Arc, Mutex, RwLock,
+  },
+  time::Instant,
+};
+use tracing::{error, field, Span};
+
+/// State shared between the server and the service implementations
+/// that dispatch calls into the plugin.
+#[derive(Debug, Default)]
+pub(crate) struct Dispatch {
+  draining: AtomicBool,
+  next_id: AtomicU64,
+  in_flight: Mutex<HashMap<u64, &'static str>>,
+  locking: AtomicBool,
+  unix_endpoint: AtomicBool,
+  pending: Mutex<HashSet<OperationKey>>,
+  limits: RwLock<SizeLimits>,
+  trace_format: RwLock<TraceFormat>,
+  #[cfg(feature = "metrics")]
+  metrics: Arc<Metrics>,
+}
+
+impl Dispatch {
+  /// Register the start of a call to `method`. The call is considered
+  /// in-flight until the returned guard is dropped. The guard owns a
+  /// reference to the dispatch, so it can be moved into a response
+  /// stream to keep a streaming call in-flight until the stream ends.
+  ///
+  /// Fails with `UNAVAILABLE` once the server has started draining.
+  pub(crate) fn begin(self: &Arc<Self>, method: &'static str) -> Result<Call, tonic::Status> {
+    if self.draining.load(Ordering::Acquire) {
+      return Err(tonic::Status::unavailable(format!(
+        "Server is shutting down, refusing {}",
+        method
+      )));
+    }
+
+    let id = self.next_id.fetch_add(1, Ordering::Relaxed);
+    self.in_flight.lock().unwrap().insert(id, method);
+    Ok(Call {
+      dispatch: self.clone(),
+      id,
+    })
+  }
+
+  /// Run a call to `method`, registered with [`Dispatch::begin`] and
+  /// traced with [`Dispatch::trace`]. The guard of the call is passed on
+  /// to `f`.
+  pub(crate) async fn call<Req, Res, F, Fut>(
+    self: &Arc<Self>,
+    method: &'static str,
+    request: tonic::Request<Req>,
+    f: F,
+  ) -> Result<tonic::Response<Res>, tonic::Status>
+  where
+    Req: Trace,
+    Res: Trace,
+    F: FnOnce(Req, Call) -> Fut,
+    Fut: Future<Output = Result<Res, tonic::Status>>,
+  {
+    self
+      .trace(method, request, |request| async move {
+        let call = self.begin(method)?;
+        f(request, call).await
+      })
+      .await
+  }
+
+  /// Run `f` on `request` to `method`, recording the request, the
+  /// response or error, the status code and the latency on the current
+  /// span. With the `opentelemetry` feature, the span is parented under the
+  /// trace context sent by the caller. With the `metrics` feature, the call
+  /// is counted in the metrics. `f` runs with the [`RequestContext`] of the
+  /// call as the current context.
+  #[cfg_attr(not(feature = "metrics"), allow(unused_variables))]
+  pub(crate) async fn trace<Req, Res, F, Fut>(
+    &self,
+    method: &'static str,
+    request: tonic::Request<Req>,
+    f: F,
+  ) -> Result<tonic::Response<Res>, tonic::Status>
+  where
+    Req: Trace,
+    Res: Trace,
+    F: FnOnce(Req) -> Fut,
+    Fut: Future<Output = Result<Res, tonic::Status>>,
+  {
+    #[cfg(feature = "opentelemetry")]
+    super::propagation::set_parent(&Span::current(), request.metadata());
+
+    let context = RequestContext::new(&request, self.unix_endpoint.load(Ordering::Acquire));
+    let format = *self.trace_format.read().unwrap();
+    let request = request.into_inner();
+    request.trace(format, "request");
+
+    #[cfg(feature = "metrics")]
+    let _in_flight = self.metrics.begin(method);
+    let start = Instant::now();
+    let result = context.scope(f(request)).await;
+    let elapsed = start.elapsed();
+
+    let span = Span::current();
+    span.record("latency_ms", &(elapsed.as_secs_f64() * 1000.0));
+    let code = match &result {
+      Ok(response) => {
+        response.trace(format, "response");
+        tonic::Code::Ok
+      }
+      Err(status) => {
+        span.record("error", &status.message());
+        status.code()
+      }
+    };
+    span.record("code", &field::debug(code));
+    #[cfg(feature = "metrics")]
+    self.metrics.record(method, code, elapsed);
+
+    result.map(tonic::Response::new)
+  }
+
+  /// The metrics of the calls.
+  #[cfg(feature = "metrics")]
+  #[inline]
+  pub(crate) fn metrics(&self) -> Arc<Metrics> {
+    self.metrics.clone()
+  }
+
+  /// Set how requests and responses are recorded.
+  #[inline]
+  pub(crate) fn set_trace_format(&self, format: TraceFormat) {
+    *self.trace_format.write().unwrap() = format;
+  }
+
+  /// Stop accepting new calls.
+  #[inline]
+  pub(crate) fn drain(&self) {
+    self.draining.store(true, Ordering::Release);
+  }
+
+  /// Set whether calls are served on a unix domain socket, whose peers
+  /// have their credentials recorded in the [`RequestContext`].
+  #[inline]
+  pub(crate) fn set_unix_endpoint(&self, unix: bool) {
+    self.unix_endpoint.store(unix, Ordering::Release);
+  }
+
+  /// Reject calls that act on the same volume or snapshot as a call that
+  /// is already in-flight, see [`Dispatch::lock`].
+  #[inline]
+  pub(crate) fn enable_locking(&self) {
+    self.locking.store(true, Ordering::Release);
+  }
+
+  /// Register a pending operation on `key`, until the returned guard is
+  /// dropped. Does nothing unless locking is enabled.
+  ///
+  /// Fails with `ABORTED` if an operation on `key` is already pending, as
+  /// the CSI spec recommends.
+  pub(crate) fn lock(self: &Arc<Self>, key: OperationKey) -> Result<OperationLock, tonic::Status> {
+    if !self.locking.load(Ordering::Acquire) {
+      return Ok(OperationLock(None));
+    }
+
+    let mut pending = self.pending.lock().unwrap();
+    if pending.contains(&key) {
+      return Err(tonic::Status::aborted(format!(
+        "An operation is already pending for {}",
+        key
+      )));
+    }
+
+    pending.insert(key.clone());
+    Ok(OperationLock(Some((self.clone(), key))))
+  }
+
+  /// Set the size limits requests and responses are checked against.
+  #[inline]
+  pub(crate) fn set_limits(&self, limits: SizeLimits) {
+    *self.limits.write().unwrap() = limits;
+  }
+
+  /// Fail with `INVALID_ARGUMENT` if `request` exceeds the size limits.
+  pub(crate) fn check_request<M: CheckSize>(&self, request: M) -> Result<M, tonic::Status> {
+    match request.check_size(&self.limits.read().unwrap()) {
+      Ok(()) => Ok(request),
+      Err(message) => Err(tonic::Status::invalid_argument(message)),
+    }
+  }
+
+  /// Fail with `INTERNAL` if `response` of the plugin exceeds the size
+  /// limits.
+  pub(crate) fn check_response<M: CheckSize>(&self, response: M) -> Result<M, tonic::Status> {
+    match response.check_size(&self.limits.read().unwrap()) {
+      Ok(()) => Ok(response),
+      Err(message) => {
+        error!("Invalid response: {}", message);
+        Err(tonic::Status::internal(message))
+      }
+    }
+  }
+
+  /// The methods of all calls that are currently in-flight.
+  pub(crate) fn running(&self) -> Vec<&'static str> {
+    let mut running = self
+      .in_flight
+      .lock()
+      .unwrap()
+      .values()
+      .copied()
+      .collect::<Vec<_>>();
+    running.sort_unstable();
+    running
+  }
+}
+
+/// Guard for an in-flight call, see [`Dispatch::begin`].
+#[must_use]
+pub(crate) struct Call {
+  dispatch: Arc<Dispatch>,
+  id: u64,
+}
+
+impl Drop for Call {
+  fn drop(&mut self) {
+    self.dispatch.in_flight.lock().unwrap().remove(&self.id);
+  }
+}
+
+/// What an operation acts on, for per-volume operation locking.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub(crate) enum OperationKey {
+  /// The name of a volume being created.
+  VolumeName(String),
+  VolumeId(String),
+  /// A volume published at a target path, as a volume may be published at
+  /// several target paths at the same time.
+  VolumeTargetPath(String, PathBuf),
+  /// The name of a snapshot being created.
+  SnapshotName(String),
+  SnapshotId(String),
+}
+
+impl fmt::Display for OperationKey {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      OperationKey::VolumeName(v) => write!(f, "volume name {}", v),
+      OperationKey::VolumeId(v) => write!(f, "volume {}", v),
+      OperationKey::VolumeTargetPath(v, path) => {
+        write!(f, "volume {} at {}", v, path.display())
+      }
+      OperationKey::SnapshotName(v) => write!(f, "snapshot name {}", v),
+      OperationKey::SnapshotId(v) => write!(f, "snapshot {}", v),
+    }
+  }
+}
+
+/// Guard for a pending operation, see [`Dispatch::lock`].
+#[must_use]
+pub(crate) struct OperationLock(Option<(Arc<Dispatch>, OperationKey)>);
+
+impl Drop for OperationLock {
+  fn drop(&mut self) {
+    if let Some((dispatch, key)) = &self.0 {
+      dispatch.pending.lock().unwrap().remove(key);
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::proto;
+
+  #[test]
+  fn refuses_calls_when_draining() {
+    let dispatch = Arc::new(Dispatch::default());
+    let call = dispatch.begin("CreateVolume").unwrap();
+    dispatch.drain();
+
+    let err = dispatch.begin("DeleteVolume").err().unwrap();
+    assert_eq!(err.code(), tonic::Code::Unavailable);
+    assert_eq!(dispatch.running(), vec!["CreateVolume"]);
+
+    drop(call);
+    assert!(dispatch.running().is_empty());
+  }
+
+  #[tokio::test]
+  async fn traces_responses_and_errors() {
+    let dispatch = Dispatch::default();
+    dispatch.set_trace_format(TraceFormat::Fields);
+
+    let response = dispatch
+      .trace(
+        "Probe",
+        tonic::Request::new(proto::ProbeRequest {}),
+        |_| async { Ok(proto::ProbeResponse { ready: Some(true) }) },
+      )
+      .await
+      .unwrap();
+    assert_eq!(response.into_inner().ready, Some(true));
+
+    let err = dispatch
+      .trace(
+        "Probe",
+        tonic::Request::new(proto::ProbeRequest {}),
+        |_| async { Err::<proto::ProbeResponse, _>(tonic::Status::unavailable("not ready")) },
+      )
+      .await
+      .unwrap_err();
+    assert_eq!(err.code(), tonic::Code::Unavailable);
+  }
+
+  #[test]
+  fn rejects_concurrent_operations() {
+    let dispatch = Arc::new(Dispatch::default());
+    dispatch.enable_locking();
+    let lock = dispatch
+      .lock(OperationKey::VolumeId("vol-1".into()))
+      .unwrap();
+
+    let err = dispatch
+      .lock(OperationKey::VolumeId("vol-1".into()))
+      .err()
+      .unwrap();
+    assert_eq!(err.code(), tonic::Code::Aborted);
+    assert!(dispatch
+      .lock(OperationKey::VolumeName("vol-1".into()))
+      .is_ok());
+    assert!(dispatch
+      .lock(OperationKey::VolumeId("vol-2".into()))
+      .is_ok());
+
+    drop(lock);
+    assert!(dispatch
+      .lock(OperationKey::VolumeId("vol-1".into()))
+      .is_ok());
+  }
+
+  #[test]
+  fn locking_is_opt_in() {
+    let dispatch = Arc::new(Dispatch::default());
+    let _lock = dispatch
+      .lock(OperationKey::VolumeId("vol-1".into()))
+      .unwrap();
+    assert!(dispatch
+      .lock(OperationKey::VolumeId("vol-1".into()))
+      .is_ok());
+  }
+}
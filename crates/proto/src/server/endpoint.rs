@@ -0,0 +1,311 @@
+use super::PeerCredentials;
+use futures::Stream;
+use std::{
+  env, fmt, fs, io,
+  net::SocketAddr,
+  os::unix::fs::{FileTypeExt, PermissionsExt},
+  path::{Path, PathBuf},
+  pin::Pin,
+  str::FromStr,
+  task::{Context, Poll},
+};
+use thiserror::Error;
+use tokio::{
+  io::{AsyncRead, AsyncWrite, ReadBuf},
+  net::{TcpListener, TcpStream, UnixListener, UnixStream},
+};
+use tonic::transport::server::Connected;
+use tracing::debug;
+
+/// Name of the environment variable conventionally used to pass the
+/// endpoint to a CSI plugin.
+pub const CSI_ENDPOINT: &str = "CSI_ENDPOINT";
+
+/// An address a CSI plugin can listen on.
+///
+/// Endpoints are written as URLs, either `unix:///path/to/csi.sock`
+/// or `tcp://127.0.0.1:10000`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Endpoint {
+  /// A unix domain socket at the given path.
+  Unix(PathBuf),
+
+  /// A TCP socket at the given address.
+  Tcp(SocketAddr),
+}
+
+#[non_exhaustive]
+#[derive(Debug, Error)]
+pub enum EndpointError {
+  #[error("Environment variable {0} is not set")]
+  MissingEnv(&'static str),
+
+  #[error("Endpoint '{0}' has no scheme, expected unix:// or tcp://")]
+  MissingScheme(String),
+
+  #[error("Endpoint '{0}' has unsupported scheme, expected unix:// or tcp://")]
+  UnsupportedScheme(String),
+
+  #[error("Endpoint '{0}' has an empty path")]
+  EmptyPath(String),
+
+  #[error("Endpoint '{0}' has an invalid address: {1}")]
+  InvalidAddress(String, #[source] std::net::AddrParseError),
+}
+
+impl Endpoint {
+  /// Read the endpoint from the `CSI_ENDPOINT` environment variable.
+  pub fn from_env() -> Result<Self, EndpointError> {
+    match env::var(CSI_ENDPOINT) {
+      Ok(v) => v.parse(),
+      Err(_) => Err(EndpointError::MissingEnv(CSI_ENDPOINT)),
+    }
+  }
+
+  /// The socket path, if this is a unix domain socket endpoint.
+  #[inline]
+  pub fn unix_path(&self) -> Option<&Path> {
+    match self {
+      Endpoint::Unix(path) => Some(path),
+      Endpoint::Tcp(_) => None,
+    }
+  }
+
+  /// Bind a listener to this endpoint.
+  ///
+  /// For unix domain sockets, a stale socket file left behind by a
+  /// previous run is removed before binding, and the permissions of the
+  /// new socket file are set to `permissions` if given. Any other kind
+  /// of file at the socket path is left alone, and binding fails.
+  ///
+  /// This must be called from within a tokio runtime.
+  pub fn bind(&self, permissions: Option<u32>) -> io::Result<Incoming> {
+    match self {
+      Endpoint::Unix(path) => {
+        remove_stale_socket(path)?;
+        let listener = UnixListener::bind(path)?;
+        if let Some(mode) = permissions {
+          fs::set_permissions(path, fs::Permissions::from_mode(mode))?;
+        }
+
+        Ok(Incoming::Unix(listener))
+      }
+
+      Endpoint::Tcp(addr) => {
+        let listener = std::net::TcpListener::bind(addr)?;
+        listener.set_nonblocking(true)?;
+        Ok(Incoming::Tcp(TcpListener::from_std(listener)?))
+      }
+    }
+  }
+
+  /// Remove the socket file of a unix domain socket endpoint, if it
+  /// exists. Does nothing for TCP endpoints.
+  pub fn remove_socket(&self) -> io::Result<()> {
+    match self {
+      Endpoint::Unix(path) => remove_stale_socket(path),
+      Endpoint::Tcp(_) => Ok(()),
+    }
+  }
+}
+
+fn remove_stale_socket(path: &Path) -> io::Result<()> {
+  match fs::symlink_metadata(path) {
+    Ok(meta) if meta.file_type().is_socket() => {
+      debug!(path = %path.display(), "Removing stale socket file");
+      fs::remove_file(path)
+    }
+    Ok(_) => Err(io::Error::new(
+      io::ErrorKind::AlreadyExists,
+      format!("{} exists and is not a socket", path.display()),
+    )),
+    Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(()),
+    Err(e) => Err(e),
+  }
+}
+
+impl FromStr for Endpoint {
+  type Err = EndpointError;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    if let Some(path) = s.strip_prefix("unix://") {
+      if path.is_empty() {
+        return Err(EndpointError::EmptyPath(s.into()));
+      }
+
+      Ok(Endpoint::Unix(path.into()))
+    } else if let Some(addr) = s.strip_prefix("tcp://") {
+      addr
+        .parse()
+        .map(Endpoint::Tcp)
+        .map_err(|e| EndpointError::InvalidAddress(s.into(), e))
+    } else if s.contains("://") {
+      Err(EndpointError::UnsupportedScheme(s.into()))
+    } else {
+      Err(EndpointError::MissingScheme(s.into()))
+    }
+  }
+}
+
+impl fmt::Display for Endpoint {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      Endpoint::Unix(path) => write!(f, "unix://{}", path.display()),
+      Endpoint::Tcp(addr) => write!(f, "tcp://{}", addr),
+    }
+  }
+}
+
+impl From<SocketAddr> for Endpoint {
+  #[inline]
+  fn from(addr: SocketAddr) -> Self {
+    Endpoint::Tcp(addr)
+  }
+}
+
+impl From<PathBuf> for Endpoint {
+  #[inline]
+  fn from(path: PathBuf) -> Self {
+    Endpoint::Unix(path)
+  }
+}
+
+/// A stream of connections accepted on a bound [`Endpoint`].
+#[derive(Debug)]
+pub enum Incoming {
+  Unix(UnixListener),
+  Tcp(TcpListener),
+}
+
+impl Stream for Incoming {
+  type Item = io::Result<Connection>;
+
+  fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+    let result = match &*self {
+      Incoming::Unix(listener) => match listener.poll_accept(cx) {
+        Poll::Pending => return Poll::Pending,
+        Poll::Ready(r) => r.map(|(stream, _)| Connection::Unix(stream)),
+      },
+      Incoming::Tcp(listener) => match listener.poll_accept(cx) {
+        Poll::Pending => return Poll::Pending,
+        Poll::Ready(r) => r.map(|(stream, _)| Connection::Tcp(stream)),
+      },
+    };
+
+    Poll::Ready(Some(result))
+  }
+}
+
+/// A single connection accepted on a bound [`Endpoint`].
+#[derive(Debug)]
+pub enum Connection {
+  Unix(UnixStream),
+  Tcp(TcpStream),
+}
+
+impl Connected for Connection {
+  fn remote_addr(&self) -> Option<SocketAddr> {
+    match self {
+      Connection::Unix(stream) => stream
+        .peer_cred()
+        .ok()
+        .map(|cred| PeerCredentials::from(cred).to_addr()),
+      Connection::Tcp(stream) => stream.peer_addr().ok(),
+    }
+  }
+}
+
+impl AsyncRead for Connection {
+  fn poll_read(
+    self: Pin<&mut Self>,
+    cx: &mut Context<'_>,
+    buf: &mut ReadBuf<'_>,
+  ) -> Poll<io::Result<()>> {
+    match self.get_mut() {
+      Connection::Unix(s) => Pin::new(s).poll_read(cx, buf),
+      Connection::Tcp(s) => Pin::new(s).poll_read(cx, buf),
+    }
+  }
+}
+
+impl AsyncWrite for Connection {
+  fn poll_write(self: Pin<&mut Self>, cx: &mut Context<'_>, buf: &[u8]) -> Poll<io::Result<usize>> {
+    match self.get_mut() {
+      Connection::Unix(s) => Pin::new(s).poll_write(cx, buf),
+      Connection::Tcp(s) => Pin::new(s).poll_write(cx, buf),
+    }
+  }
+
+  fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+    match self.get_mut() {
+      Connection::Unix(s) => Pin::new(s).poll_flush(cx),
+      Connection::Tcp(s) => Pin::new(s).poll_flush(cx),
+    }
+  }
+
+  fn poll_shutdown(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+    match self.get_mut() {
+      Connection::Unix(s) => Pin::new(s).poll_shutdown(cx),
+      Connection::Tcp(s) => Pin::new(s).poll_shutdown(cx),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use futures::StreamExt;
+  use std::os::unix::{fs::MetadataExt, net::UnixListener as StdUnixListener};
+  use test_case::test_case;
+
+  #[test_case("unix:///csi/csi.sock" => Endpoint::Unix("/csi/csi.sock".into()) ; "unix absolute")]
+  #[test_case("unix://csi.sock" => Endpoint::Unix("csi.sock".into()) ; "unix relative")]
+  #[test_case("tcp://127.0.0.1:10000" => Endpoint::Tcp("127.0.0.1:10000".parse().unwrap()) ; "tcp v4")]
+  #[test_case("tcp://[::1]:10000" => Endpoint::Tcp("[::1]:10000".parse().unwrap()) ; "tcp v6")]
+  fn parse(input: &str) -> Endpoint {
+    let endpoint = input.parse::<Endpoint>().unwrap();
+    assert_eq!(endpoint.to_string(), input);
+    endpoint
+  }
+
+  #[test_case("/csi/csi.sock" => matches EndpointError::MissingScheme(_) ; "no scheme")]
+  #[test_case("http://localhost:80" => matches EndpointError::UnsupportedScheme(_) ; "unsupported scheme")]
+  #[test_case("unix://" => matches EndpointError::EmptyPath(_) ; "empty path")]
+  #[test_case("tcp://localhost" => matches EndpointError::InvalidAddress(..) ; "invalid address")]
+  fn parse_invalid(input: &str) -> EndpointError {
+    input.parse::<Endpoint>().unwrap_err()
+  }
+
+  #[tokio::test]
+  async fn bind_unix_replaces_stale_socket() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("csi.sock");
+    drop(StdUnixListener::bind(&path).unwrap());
+    assert!(path.exists());
+
+    let endpoint = Endpoint::Unix(path.clone());
+    let mut incoming = endpoint.bind(Some(0o600)).unwrap();
+    let mode = fs::metadata(&path).unwrap().permissions().mode();
+    assert_eq!(mode & 0o777, 0o600);
+
+    let _client = UnixStream::connect(&path).await.unwrap();
+    let conn = incoming.next().await.unwrap().unwrap();
+    assert!(matches!(conn, Connection::Unix(_)));
+
+    let peer = PeerCredentials::from_addr(&conn.remote_addr().unwrap()).unwrap();
+    assert_eq!(peer.uid, fs::metadata(&path).unwrap().uid());
+    assert_eq!(peer.pid, Some(std::process::id() as i32));
+  }
+
+  #[tokio::test]
+  async fn bind_unix_refuses_to_remove_regular_file() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("csi.sock");
+    fs::write(&path, b"not a socket").unwrap();
+
+    let endpoint = Endpoint::Unix(path.clone());
+    let err = endpoint.bind(None).unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::AlreadyExists);
+    assert!(path.is_file());
+  }
+}
@@ -0,0 +1,396 @@
+use crate::proto;
+use std::collections::HashMap;
+
+/// Size limits on the strings and maps of requests and responses.
+///
+/// The defaults are the limits of the CSI spec: 128 bytes for strings,
+/// 192 bytes for node IDs and 4 KiB for maps, counting both keys and
+/// values. Paths are not limited, as the spec leaves them to the operating
+/// system. Requests exceeding a limit are rejected with `INVALID_ARGUMENT`
+/// before they reach the plugin, and responses exceeding a limit are
+/// replaced by an `INTERNAL` error.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SizeLimits {
+  string: usize,
+  node_id: usize,
+  map: usize,
+}
+
+impl SizeLimits {
+  /// The limits of the CSI spec.
+  pub const SPEC: SizeLimits = SizeLimits {
+    string: 128,
+    node_id: 192,
+    map: 4096,
+  };
+
+  /// No limits at all.
+  pub const UNLIMITED: SizeLimits = SizeLimits {
+    string: usize::MAX,
+    node_id: usize::MAX,
+    map: usize::MAX,
+  };
+
+  /// Set the maximum size of strings in bytes.
+  #[inline]
+  pub fn with_string(mut self, bytes: usize) -> Self {
+    self.string = bytes;
+    self
+  }
+
+  /// Set the maximum size of node IDs in bytes.
+  #[inline]
+  pub fn with_node_id(mut self, bytes: usize) -> Self {
+    self.node_id = bytes;
+    self
+  }
+
+  /// Set the maximum total size of the keys and values of maps in bytes.
+  #[inline]
+  pub fn with_map(mut self, bytes: usize) -> Self {
+    self.map = bytes;
+    self
+  }
+
+  #[inline]
+  pub fn string(&self) -> usize {
+    self.string
+  }
+
+  #[inline]
+  pub fn node_id(&self) -> usize {
+    self.node_id
+  }
+
+  #[inline]
+  pub fn map(&self) -> usize {
+    self.map
+  }
+
+  fn check(size: usize, limit: usize, field: impl FnOnce() -> String) -> Result<(), String> {
+    if size > limit {
+      return Err(format!(
+        "{} is {} bytes, exceeding the limit of {} bytes",
+        field(),
+        size,
+        limit
+      ));
+    }
+
+    Ok(())
+  }
+}
+
+impl Default for SizeLimits {
+  #[inline]
+  fn default() -> Self {
+    Self::SPEC
+  }
+}
+
+/// A message whose strings and maps are subject to [`SizeLimits`].
+pub(crate) trait CheckSize {
+  /// Describe the first field that exceeds `limits`, if any.
+  fn check_size(&self, limits: &SizeLimits) -> Result<(), String>;
+}
+
+impl<T: CheckSize> CheckSize for Option<T> {
+  #[inline]
+  fn check_size(&self, limits: &SizeLimits) -> Result<(), String> {
+    self.iter().try_for_each(|v| v.check_size(limits))
+  }
+}
+
+impl<T: CheckSize> CheckSize for Vec<T> {
+  #[inline]
+  fn check_size(&self, limits: &SizeLimits) -> Result<(), String> {
+    self.iter().try_for_each(|v| v.check_size(limits))
+  }
+}
+
+fn map_size(map: &HashMap<String, String>) -> usize {
+  map.iter().map(|(k, v)| k.len() + v.len()).sum()
+}
+
+/// Implement [`CheckSize`] for a message by listing its fields by kind:
+/// `string`, `node_id`, `strings` and `node_ids` for (repeated) strings,
+/// `map` for maps, and `message` for (optional or repeated) messages.
+/// Nested messages are written as `Name in module`.
+macro_rules! check_size {
+  ($name:ident $(in $module:ident)? { $($(#[$meta:meta])* $kind:ident $field:ident),* $(,)? }) => {
+    impl CheckSize for proto::$($module::)?$name {
+      #[allow(unused_variables)]
+      fn check_size(&self, limits: &SizeLimits) -> Result<(), String> {
+        $(
+          $(#[$meta])*
+          check_size!(@$kind self, limits, $name, $field);
+        )*
+        Ok(())
+      }
+    }
+  };
+  (@string $self:ident, $limits:ident, $name:ident, $field:ident) => {
+    SizeLimits::check($self.$field.len(), $limits.string, || {
+      concat!(stringify!($name), ".", stringify!($field)).to_owned()
+    })?
+  };
+  (@node_id $self:ident, $limits:ident, $name:ident, $field:ident) => {
+    SizeLimits::check($self.$field.len(), $limits.node_id, || {
+      concat!(stringify!($name), ".", stringify!($field)).to_owned()
+    })?
+  };
+  (@strings $self:ident, $limits:ident, $name:ident, $field:ident) => {
+    for (i, v) in $self.$field.iter().enumerate() {
+      SizeLimits::check(v.len(), $limits.string, || {
+        format!(concat!(stringify!($name), ".", stringify!($field), "[{}]"), i)
+      })?
+    }
+  };
+  (@node_ids $self:ident, $limits:ident, $name:ident, $field:ident) => {
+    for (i, v) in $self.$field.iter().enumerate() {
+      SizeLimits::check(v.len(), $limits.node_id, || {
+        format!(concat!(stringify!($name), ".", stringify!($field), "[{}]"), i)
+      })?
+    }
+  };
+  (@map $self:ident, $limits:ident, $name:ident, $field:ident) => {
+    SizeLimits::check(map_size(&$self.$field), $limits.map, || {
+      concat!(stringify!($name), ".", stringify!($field)).to_owned()
+    })?
+  };
+  (@message $self:ident, $limits:ident, $name:ident, $field:ident) => {
+    $self.$field.check_size($limits)?
+  };
+}
+
+check_size!(Topology { map segments });
+check_size!(TopologyRequirement {
+  message requisite,
+  message preferred,
+});
+check_size!(CapacityRange {});
+check_size!(MountVolume in volume_capability {
+  string fs_type,
+  strings mount_flags,
+  string volume_mount_group,
+});
+check_size!(SnapshotSource in volume_content_source { string snapshot_id });
+check_size!(VolumeSource in volume_content_source { string volume_id });
+
+impl CheckSize for proto::VolumeCapability {
+  fn check_size(&self, limits: &SizeLimits) -> Result<(), String> {
+    use proto::volume_capability::AccessType;
+    match &self.access_type {
+      Some(AccessType::Mount(v)) => v.check_size(limits),
+      Some(AccessType::Block(_)) | None => Ok(()),
+    }
+  }
+}
+
+impl CheckSize for proto::VolumeContentSource {
+  fn check_size(&self, limits: &SizeLimits) -> Result<(), String> {
+    use proto::volume_content_source::Type;
+    match &self.r#type {
+      Some(Type::Snapshot(v)) => v.check_size(limits),
+      Some(Type::Volume(v)) => v.check_size(limits),
+      None => Ok(()),
+    }
+  }
+}
+
+check_size!(Volume {
+  string volume_id,
+  map volume_context,
+  message content_source,
+  message accessible_topology,
+});
+#[cfg(feature = "alpha")]
+check_size!(VolumeCondition { string message });
+check_size!(Snapshot {
+  string snapshot_id,
+  string source_volume_id,
+  #[cfg(feature = "alpha")]
+  string group_snapshot_id,
+});
+
+check_size!(CreateVolumeRequest {
+  string name,
+  message capacity_range,
+  message volume_capabilities,
+  map parameters,
+  map secrets,
+  message volume_content_source,
+  message accessibility_requirements,
+});
+check_size!(CreateVolumeResponse { message volume });
+check_size!(DeleteVolumeRequest {
+  string volume_id,
+  map secrets,
+});
+check_size!(ControllerPublishVolumeRequest {
+  string volume_id,
+  node_id node_id,
+  message volume_capability,
+  map secrets,
+  map volume_context,
+});
+check_size!(ControllerPublishVolumeResponse { map publish_context });
+check_size!(ControllerUnpublishVolumeRequest {
+  string volume_id,
+  node_id node_id,
+  map secrets,
+});
+check_size!(ValidateVolumeCapabilitiesRequest {
+  string volume_id,
+  map volume_context,
+  message volume_capabilities,
+  map parameters,
+  map secrets,
+});
+check_size!(Confirmed in validate_volume_capabilities_response {
+  map volume_context,
+  message volume_capabilities,
+  map parameters,
+});
+check_size!(ValidateVolumeCapabilitiesResponse {
+  message confirmed,
+  string message,
+});
+check_size!(ListVolumesRequest { string starting_token });
+check_size!(VolumeStatus in list_volumes_response {
+  node_ids published_node_ids,
+  #[cfg(feature = "alpha")]
+  message volume_condition,
+});
+check_size!(Entry in list_volumes_response {
+  message volume,
+  message status,
+});
+check_size!(ListVolumesResponse {
+  message entries,
+  string next_token,
+});
+#[cfg(feature = "alpha")]
+check_size!(ControllerGetVolumeRequest { string volume_id });
+#[cfg(feature = "alpha")]
+check_size!(VolumeStatus in controller_get_volume_response {
+  node_ids published_node_ids,
+  message volume_condition,
+});
+#[cfg(feature = "alpha")]
+check_size!(ControllerGetVolumeResponse {
+  message volume,
+  message status,
+});
+check_size!(GetCapacityRequest {
+  message volume_capabilities,
+  map parameters,
+  message accessible_topology,
+});
+check_size!(GetCapacityResponse {});
+check_size!(CreateSnapshotRequest {
+  string source_volume_id,
+  string name,
+  map secrets,
+  map parameters,
+});
+check_size!(CreateSnapshotResponse { message snapshot });
+check_size!(DeleteSnapshotRequest {
+  string snapshot_id,
+  map secrets,
+});
+check_size!(ListSnapshotsRequest {
+  string starting_token,
+  string source_volume_id,
+  string snapshot_id,
+  map secrets,
+});
+check_size!(Entry in list_snapshots_response { message snapshot });
+check_size!(ListSnapshotsResponse {
+  message entries,
+  string next_token,
+});
+check_size!(ControllerExpandVolumeRequest {
+  string volume_id,
+  message capacity_range,
+  map secrets,
+  message volume_capability,
+});
+check_size!(ControllerExpandVolumeResponse {});
+
+check_size!(NodeStageVolumeRequest {
+  string volume_id,
+  map publish_context,
+  message volume_capability,
+  map secrets,
+  map volume_context,
+});
+check_size!(NodeUnstageVolumeRequest { string volume_id });
+check_size!(NodePublishVolumeRequest {
+  string volume_id,
+  map publish_context,
+  message volume_capability,
+  map secrets,
+  map volume_context,
+});
+check_size!(NodeUnpublishVolumeRequest { string volume_id });
+check_size!(NodeGetVolumeStatsRequest { string volume_id });
+check_size!(NodeGetVolumeStatsResponse {
+  #[cfg(feature = "alpha")]
+  message volume_condition,
+});
+check_size!(NodeExpandVolumeRequest {
+  string volume_id,
+  message capacity_range,
+  message volume_capability,
+  map secrets,
+});
+check_size!(NodeExpandVolumeResponse {});
+check_size!(NodeGetInfoResponse {
+  node_id node_id,
+  message accessible_topology,
+});
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use test_case::test_case;
+
+  fn request(name_len: usize, parameter_len: usize) -> proto::CreateVolumeRequest {
+    proto::CreateVolumeRequest {
+      name: "n".repeat(name_len),
+      parameters: vec![("key".to_owned(), "v".repeat(parameter_len))]
+        .into_iter()
+        .collect(),
+      ..Default::default()
+    }
+  }
+
+  #[test_case(SizeLimits::SPEC, 128, 4093 => Ok(()) ; "at limits")]
+  #[test_case(SizeLimits::SPEC, 129, 0 => Err("CreateVolumeRequest.name is 129 bytes, exceeding the limit of 128 bytes".to_owned()) ; "long string")]
+  #[test_case(SizeLimits::SPEC, 0, 4094 => Err("CreateVolumeRequest.parameters is 4097 bytes, exceeding the limit of 4096 bytes".to_owned()) ; "large map")]
+  #[test_case(SizeLimits::SPEC.with_string(256), 256, 0 => Ok(()) ; "lenient")]
+  fn checks_size(limits: SizeLimits, name_len: usize, parameter_len: usize) -> Result<(), String> {
+    request(name_len, parameter_len).check_size(&limits)
+  }
+
+  #[test]
+  fn checks_nested_messages() {
+    let mount = proto::volume_capability::MountVolume {
+      mount_flags: vec!["ro".into(), "f".repeat(129)],
+      ..Default::default()
+    };
+    let request = proto::NodeStageVolumeRequest {
+      volume_id: "vol-1".into(),
+      staging_target_path: "/".repeat(1024),
+      volume_capability: Some(proto::VolumeCapability {
+        access_type: Some(proto::volume_capability::AccessType::Mount(mount)),
+        access_mode: None,
+      }),
+      ..Default::default()
+    };
+
+    let err = request.check_size(&SizeLimits::SPEC).unwrap_err();
+    assert!(err.starts_with("MountVolume.mount_flags[1] "), "{}", err);
+  }
+}
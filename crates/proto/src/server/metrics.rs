@@ -0,0 +1,191 @@
+use hyper::{
+  header::CONTENT_TYPE,
+  service::{make_service_fn, service_fn},
+  Body, Method, Request, Response, StatusCode,
+};
+use prometheus::{
+  Encoder, HistogramOpts, HistogramVec, IntCounterVec, IntGaugeVec, Opts, Registry, TextEncoder,
+};
+use std::{convert::Infallible, fmt, future::Future, net::SocketAddr, sync::Arc, time::Duration};
+
+/// The buckets of the latency histogram, in seconds. The same as those
+/// of the Kubernetes CSI sidecars.
+const BUCKETS: &[f64] = &[
+  0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0, 15.0, 25.0, 50.0, 120.0, 300.0, 600.0,
+];
+
+/// Per-method metrics of the calls to the plugin.
+pub(crate) struct Metrics {
+  registry: Registry,
+  requests: IntCounterVec,
+  duration: HistogramVec,
+  in_flight: IntGaugeVec,
+}
+
+impl Metrics {
+  /// Register the start of a call to `method`. The call is counted as
+  /// in-flight until the returned guard is dropped.
+  pub(crate) fn begin(&self, method: &'static str) -> InFlight {
+    let gauge = self.in_flight.with_label_values(&[method]);
+    gauge.inc();
+    InFlight(gauge)
+  }
+
+  /// Record a completed call to `method`, with the status `code` it
+  /// completed with.
+  pub(crate) fn record(&self, method: &'static str, code: tonic::Code, elapsed: Duration) {
+    let code = format!("{:?}", code);
+    let labels = [method, code.as_str()];
+    self.requests.with_label_values(&labels).inc();
+    self
+      .duration
+      .with_label_values(&labels)
+      .observe(elapsed.as_secs_f64());
+  }
+
+  /// The metrics in the Prometheus text format.
+  pub(crate) fn encode(&self) -> String {
+    let mut buf = Vec::new();
+    if let Err(e) = TextEncoder::new().encode(&self.registry.gather(), &mut buf) {
+      tracing::error!(error = %e, "Failed to encode metrics");
+    }
+
+    String::from_utf8(buf).unwrap_or_default()
+  }
+}
+
+impl Default for Metrics {
+  fn default() -> Self {
+    let requests = IntCounterVec::new(
+      Opts::new(
+        "csi_plugin_requests_total",
+        "Number of completed calls, by method and gRPC status code.",
+      ),
+      &["method", "code"],
+    )
+    .unwrap();
+    let duration = HistogramVec::new(
+      HistogramOpts::new(
+        "csi_plugin_request_duration_seconds",
+        "Latency of completed calls, by method and gRPC status code.",
+      )
+      .buckets(BUCKETS.to_vec()),
+      &["method", "code"],
+    )
+    .unwrap();
+    let in_flight = IntGaugeVec::new(
+      Opts::new(
+        "csi_plugin_requests_in_flight",
+        "Number of calls currently in-flight, by method.",
+      ),
+      &["method"],
+    )
+    .unwrap();
+
+    let registry = Registry::new();
+    registry.register(Box::new(requests.clone())).unwrap();
+    registry.register(Box::new(duration.clone())).unwrap();
+    registry.register(Box::new(in_flight.clone())).unwrap();
+
+    Metrics {
+      registry,
+      requests,
+      duration,
+      in_flight,
+    }
+  }
+}
+
+impl fmt::Debug for Metrics {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.debug_struct("Metrics").finish()
+  }
+}
+
+/// Guard for an in-flight call, see [`Metrics::begin`].
+#[must_use]
+pub(crate) struct InFlight(prometheus::IntGauge);
+
+impl Drop for InFlight {
+  fn drop(&mut self) {
+    self.0.dec();
+  }
+}
+
+/// Serve `metrics` on `/metrics` at `addr`. Fails right away if the
+/// address cannot be bound, otherwise the returned future runs until the
+/// listener fails.
+pub(crate) fn serve(
+  metrics: Arc<Metrics>,
+  addr: SocketAddr,
+) -> hyper::Result<impl Future<Output = hyper::Result<()>>> {
+  let make_service = make_service_fn(move |_| {
+    let metrics = metrics.clone();
+    async move {
+      Ok::<_, Infallible>(service_fn(move |request| {
+        let response = handle(&metrics, &request);
+        async move { Ok::<_, Infallible>(response) }
+      }))
+    }
+  });
+
+  Ok(hyper::Server::try_bind(&addr)?.serve(make_service))
+}
+
+fn handle(metrics: &Metrics, request: &Request<Body>) -> Response<Body> {
+  let mut response = Response::default();
+  match (request.method(), request.uri().path()) {
+    (&Method::GET, "/metrics") => {
+      response.headers_mut().insert(
+        CONTENT_TYPE,
+        TextEncoder::new().format_type().parse().unwrap(),
+      );
+      *response.body_mut() = metrics.encode().into();
+    }
+    (_, "/metrics") => *response.status_mut() = StatusCode::METHOD_NOT_ALLOWED,
+    _ => *response.status_mut() = StatusCode::NOT_FOUND,
+  }
+
+  response
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn counts_calls() {
+    let metrics = Metrics::default();
+    let call = metrics.begin("CreateVolume");
+    assert!(metrics
+      .encode()
+      .contains(r#"csi_plugin_requests_in_flight{method="CreateVolume"} 1"#));
+
+    drop(call);
+    metrics.record("CreateVolume", tonic::Code::Ok, Duration::from_millis(200));
+    metrics.record(
+      "CreateVolume",
+      tonic::Code::Aborted,
+      Duration::from_millis(20),
+    );
+
+    let encoded = metrics.encode();
+    assert!(encoded.contains(r#"csi_plugin_requests_in_flight{method="CreateVolume"} 0"#));
+    assert!(encoded.contains(r#"csi_plugin_requests_total{code="Ok",method="CreateVolume"} 1"#));
+    assert!(
+      encoded.contains(r#"csi_plugin_requests_total{code="Aborted",method="CreateVolume"} 1"#)
+    );
+    assert!(encoded.contains(
+      r#"csi_plugin_request_duration_seconds_bucket{code="Ok",method="CreateVolume",le="0.25"} 1"#
+    ));
+  }
+
+  #[test]
+  fn serves_metrics_path_only() {
+    let metrics = Metrics::default();
+    let get = |uri: &str| handle(&metrics, &Request::get(uri).body(Body::empty()).unwrap());
+
+    assert_eq!(get("/metrics").status(), StatusCode::OK);
+    assert_eq!(get("/").status(), StatusCode::NOT_FOUND);
+  }
+}
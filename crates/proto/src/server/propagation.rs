@@ -0,0 +1,77 @@
+use opentelemetry::{
+  propagation::{Extractor, TextMapPropagator},
+  sdk::propagation::TraceContextPropagator,
+};
+use tonic::metadata::{KeyRef, MetadataMap};
+use tracing::Span;
+use tracing_opentelemetry::OpenTelemetrySpanExt;
+
+/// Reads the trace context from the metadata of a request.
+struct MetadataExtractor<'a>(&'a MetadataMap);
+
+impl Extractor for MetadataExtractor<'_> {
+  fn get(&self, key: &str) -> Option<&str> {
+    self.0.get(key).and_then(|v| v.to_str().ok())
+  }
+
+  fn keys(&self) -> Vec<&str> {
+    self
+      .0
+      .keys()
+      .map(|k| match k {
+        KeyRef::Ascii(k) => k.as_str(),
+        KeyRef::Binary(k) => k.as_str(),
+      })
+      .collect()
+  }
+}
+
+/// Parent `span` under the W3C trace context in `metadata`, if the caller
+/// sent one. Without a `traceparent`, the span keeps its local parent.
+pub(crate) fn set_parent(span: &Span, metadata: &MetadataMap) {
+  if metadata.get("traceparent").is_none() {
+    return;
+  }
+
+  let cx = TraceContextPropagator::new().extract(&MetadataExtractor(metadata));
+  span.set_parent(cx);
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use opentelemetry::trace::{TraceContextExt, TraceId, TracerProvider};
+  use tracing_subscriber::layer::SubscriberExt;
+
+  #[test]
+  fn parents_span_under_remote_context() {
+    let provider = opentelemetry::sdk::trace::TracerProvider::builder().build();
+    let tracer = provider.get_tracer("csi-proto", None);
+    let subscriber =
+      tracing_subscriber::registry().with(tracing_opentelemetry::layer().with_tracer(tracer));
+
+    tracing::subscriber::with_default(subscriber, || {
+      let mut metadata = MetadataMap::new();
+      metadata.insert(
+        "traceparent",
+        "00-0af7651916cd43dd8448eb211c80319c-b7ad6b7169203331-01"
+          .parse()
+          .unwrap(),
+      );
+
+      let span = tracing::info_span!("controller.create_volume");
+      set_parent(&span, &metadata);
+      assert_eq!(
+        span.context().span().span_context().trace_id(),
+        TraceId::from_hex("0af7651916cd43dd8448eb211c80319c")
+      );
+
+      let span = tracing::info_span!("controller.delete_volume");
+      set_parent(&span, &MetadataMap::new());
+      assert_ne!(
+        span.context().span().span_context().trace_id(),
+        TraceId::from_hex("0af7651916cd43dd8448eb211c80319c")
+      );
+    });
+  }
+}
@@ -0,0 +1,87 @@
+use futures::future::BoxFuture;
+use std::{
+  marker::PhantomData,
+  sync::Arc,
+  task::{Context, Poll},
+};
+use tonic::{
+  body::BoxBody,
+  codegen::{http, Never, Service},
+  transport::{Body, NamedService},
+};
+
+type Call = dyn Fn(http::Request<Body>) -> BoxFuture<'static, Result<http::Response<BoxBody>, Never>>
+  + Send
+  + Sync;
+
+/// A gRPC service with its type erased, so that optional services can be
+/// kept on the [`PluginServer`](super::PluginServer) without adding
+/// trait bounds to it. The service name is taken from `N`.
+pub(crate) struct Route<N> {
+  call: Arc<Call>,
+  name: PhantomData<fn() -> N>,
+}
+
+impl<N> Route<N> {
+  pub(crate) fn new<S>(service: S) -> Self
+  where
+    S: Service<http::Request<Body>, Response = http::Response<BoxBody>, Error = Never>
+      + Clone
+      + Send
+      + Sync
+      + 'static,
+    S::Future: Send + 'static,
+  {
+    Route {
+      call: Arc::new(move |request| {
+        let mut service = service.clone();
+        Box::pin(service.call(request))
+      }),
+      name: PhantomData,
+    }
+  }
+}
+
+impl<N> Clone for Route<N> {
+  #[inline]
+  fn clone(&self) -> Self {
+    Route {
+      call: self.call.clone(),
+      name: PhantomData,
+    }
+  }
+}
+
+impl<N: NamedService> NamedService for Route<N> {
+  const NAME: &'static str = N::NAME;
+}
+
+impl<N> Service<http::Request<Body>> for Route<N> {
+  type Response = http::Response<BoxBody>;
+  type Error = Never;
+  type Future = BoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+  #[inline]
+  fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+    Poll::Ready(Ok(()))
+  }
+
+  #[inline]
+  fn call(&mut self, request: http::Request<Body>) -> Self::Future {
+    (self.call)(request)
+  }
+}
+
+/// Name of the `GroupController` service.
+pub(crate) enum GroupControllerName {}
+
+impl NamedService for GroupControllerName {
+  const NAME: &'static str = "csi.v1.GroupController";
+}
+
+/// Name of the `SnapshotMetadata` service.
+pub(crate) enum SnapshotMetadataName {}
+
+impl NamedService for SnapshotMetadataName {
+  const NAME: &'static str = "csi.v1.SnapshotMetadata";
+}
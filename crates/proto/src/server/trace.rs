@@ -0,0 +1,370 @@
+use crate::proto;
+use std::borrow::Cow;
+use tracing::{debug, field, Span};
+
+/// How requests and responses are recorded for each call.
+///
+/// Every call is traced with a span, named after the service and method,
+/// like `controller.create_volume`. Regardless of the format, the span
+/// records the gRPC status `code` of the call, the `error` message of a
+/// failed call, and its `latency_ms`. Fields marked as secret in the CSI
+/// spec are never recorded, nor are the `mount_flags` of volume
+/// capabilities, which the spec forbids leaking.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TraceFormat {
+  /// Do not record requests and responses.
+  Off,
+
+  /// Record requests and responses using their `Debug` output, as the
+  /// `request` and `response` fields of the span.
+  Debug,
+
+  /// Record requests and responses as `DEBUG` events within the span,
+  /// with a field per field of the message.
+  Fields,
+}
+
+impl Default for TraceFormat {
+  #[inline]
+  fn default() -> Self {
+    TraceFormat::Debug
+  }
+}
+
+/// A message that is recorded according to a [`TraceFormat`].
+pub(crate) trait Trace {
+  /// Record the message as `kind`, either `request` or `response`.
+  fn trace(&self, format: TraceFormat, kind: &'static str);
+}
+
+/// Implement [`Trace`] for a message by listing the fields that are
+/// recorded with [`TraceFormat::Fields`]. Secret fields are left out. The
+/// volume capabilities of a message, given by `mount_flags(m => ..)` as an
+/// iterator over those of the message `m`, have their mount flags redacted.
+macro_rules! trace {
+  ($name:ident { $($field:ident),* $(,)? }) => {
+    trace!(@impl $name { $($field),* } |_message| {});
+  };
+  ($name:ident { $($field:ident),* $(,)? } mount_flags($m:ident => $capabilities:expr)) => {
+    trace!(@impl $name { $($field),* } |$m| {
+      let $m = $m.to_mut();
+      redact_mount_flags($capabilities);
+    });
+  };
+  (@impl $name:ident { $($field:ident),* } |$m:ident| $redact:block) => {
+    impl Trace for proto::$name {
+      fn trace(&self, format: TraceFormat, kind: &'static str) {
+        if format == TraceFormat::Off {
+          return;
+        }
+
+        let mut message = Cow::Borrowed(self);
+        {
+          let $m = &mut message;
+          $redact
+        }
+
+        match format {
+          TraceFormat::Off => {}
+          TraceFormat::Debug => {
+            Span::current().record(kind, &field::debug(&message));
+          }
+          TraceFormat::Fields => {
+            debug!($($field = ?message.$field,)* "{}", kind);
+          }
+        }
+      }
+    }
+  };
+}
+
+/// Replace the mount flags of `capabilities` with their count, like the
+/// `Debug` output of [`MountVolume`](crate::volume::MountVolume).
+fn redact_mount_flags<'a>(capabilities: impl Iterator<Item = &'a mut proto::VolumeCapability>) {
+  for capability in capabilities {
+    if let Some(proto::volume_capability::AccessType::Mount(mount)) = &mut capability.access_type {
+      if !mount.mount_flags.is_empty() {
+        mount.mount_flags = vec![format!("REDACTED ({} items)", mount.mount_flags.len())];
+      }
+    }
+  }
+}
+
+trace!(GetPluginInfoRequest {});
+trace!(GetPluginInfoResponse {
+  name,
+  vendor_version,
+  manifest,
+});
+trace!(GetPluginCapabilitiesRequest {});
+trace!(GetPluginCapabilitiesResponse { capabilities });
+trace!(ProbeRequest {});
+trace!(ProbeResponse { ready });
+
+trace!(CreateVolumeRequest {
+  name,
+  capacity_range,
+  volume_capabilities,
+  parameters,
+  volume_content_source,
+  accessibility_requirements,
+} mount_flags(m => m.volume_capabilities.iter_mut()));
+trace!(CreateVolumeResponse { volume });
+trace!(DeleteVolumeRequest { volume_id });
+trace!(DeleteVolumeResponse {});
+trace!(ControllerPublishVolumeRequest {
+  volume_id,
+  node_id,
+  volume_capability,
+  readonly,
+  volume_context,
+} mount_flags(m => m.volume_capability.iter_mut()));
+trace!(ControllerPublishVolumeResponse { publish_context });
+trace!(ControllerUnpublishVolumeRequest { volume_id, node_id });
+trace!(ControllerUnpublishVolumeResponse {});
+trace!(ValidateVolumeCapabilitiesRequest {
+  volume_id,
+  volume_context,
+  volume_capabilities,
+  parameters,
+} mount_flags(m => m.volume_capabilities.iter_mut()));
+trace!(ValidateVolumeCapabilitiesResponse {
+  confirmed,
+  message,
+} mount_flags(m => m
+  .confirmed
+  .iter_mut()
+  .flat_map(|c| c.volume_capabilities.iter_mut())));
+trace!(ListVolumesRequest {
+  max_entries,
+  starting_token,
+});
+trace!(ListVolumesResponse {
+  entries,
+  next_token,
+});
+trace!(GetCapacityRequest {
+  volume_capabilities,
+  parameters,
+  accessible_topology,
+} mount_flags(m => m.volume_capabilities.iter_mut()));
+trace!(GetCapacityResponse { available_capacity });
+trace!(ControllerGetCapabilitiesRequest {});
+trace!(ControllerGetCapabilitiesResponse { capabilities });
+trace!(CreateSnapshotRequest {
+  source_volume_id,
+  name,
+  parameters,
+});
+trace!(CreateSnapshotResponse { snapshot });
+trace!(DeleteSnapshotRequest { snapshot_id });
+trace!(DeleteSnapshotResponse {});
+trace!(ListSnapshotsRequest {
+  max_entries,
+  starting_token,
+  source_volume_id,
+  snapshot_id,
+});
+trace!(ListSnapshotsResponse {
+  entries,
+  next_token,
+});
+trace!(ControllerExpandVolumeRequest {
+  volume_id,
+  capacity_range,
+  volume_capability,
+} mount_flags(m => m.volume_capability.iter_mut()));
+trace!(ControllerExpandVolumeResponse {
+  capacity_bytes,
+  node_expansion_required,
+});
+#[cfg(feature = "alpha")]
+trace!(ControllerGetVolumeRequest { volume_id });
+#[cfg(feature = "alpha")]
+trace!(ControllerGetVolumeResponse { volume, status });
+
+trace!(NodeStageVolumeRequest {
+  volume_id,
+  publish_context,
+  staging_target_path,
+  volume_capability,
+  volume_context,
+} mount_flags(m => m.volume_capability.iter_mut()));
+trace!(NodeStageVolumeResponse {});
+trace!(NodeUnstageVolumeRequest {
+  volume_id,
+  staging_target_path,
+});
+trace!(NodeUnstageVolumeResponse {});
+trace!(NodePublishVolumeRequest {
+  volume_id,
+  publish_context,
+  staging_target_path,
+  target_path,
+  volume_capability,
+  readonly,
+  volume_context,
+} mount_flags(m => m.volume_capability.iter_mut()));
+trace!(NodePublishVolumeResponse {});
+trace!(NodeUnpublishVolumeRequest {
+  volume_id,
+  target_path,
+});
+trace!(NodeUnpublishVolumeResponse {});
+trace!(NodeGetVolumeStatsRequest {
+  volume_id,
+  volume_path,
+  staging_target_path,
+});
+#[cfg(feature = "alpha")]
+trace!(NodeGetVolumeStatsResponse {
+  usage,
+  volume_condition,
+});
+#[cfg(not(feature = "alpha"))]
+trace!(NodeGetVolumeStatsResponse { usage });
+trace!(NodeExpandVolumeRequest {
+  volume_id,
+  volume_path,
+  capacity_range,
+  staging_target_path,
+  volume_capability,
+} mount_flags(m => m.volume_capability.iter_mut()));
+trace!(NodeExpandVolumeResponse { capacity_bytes });
+trace!(NodeGetCapabilitiesRequest {});
+trace!(NodeGetCapabilitiesResponse { capabilities });
+trace!(NodeGetInfoRequest {});
+trace!(NodeGetInfoResponse {
+  node_id,
+  max_volumes_per_node,
+  accessible_topology,
+});
+
+#[cfg(feature = "alpha")]
+trace!(GroupControllerGetCapabilitiesRequest {});
+#[cfg(feature = "alpha")]
+trace!(GroupControllerGetCapabilitiesResponse { capabilities });
+#[cfg(feature = "alpha")]
+trace!(CreateVolumeGroupSnapshotRequest {
+  name,
+  source_volume_ids,
+  parameters,
+});
+#[cfg(feature = "alpha")]
+trace!(CreateVolumeGroupSnapshotResponse { group_snapshot });
+#[cfg(feature = "alpha")]
+trace!(DeleteVolumeGroupSnapshotRequest {
+  group_snapshot_id,
+  snapshot_ids,
+});
+#[cfg(feature = "alpha")]
+trace!(DeleteVolumeGroupSnapshotResponse {});
+#[cfg(feature = "alpha")]
+trace!(GetVolumeGroupSnapshotRequest {
+  group_snapshot_id,
+  snapshot_ids,
+});
+#[cfg(feature = "alpha")]
+trace!(GetVolumeGroupSnapshotResponse { group_snapshot });
+
+#[cfg(feature = "alpha")]
+trace!(GetMetadataAllocatedRequest {
+  snapshot_id,
+  starting_offset,
+  max_results,
+});
+#[cfg(feature = "alpha")]
+trace!(GetMetadataDeltaRequest {
+  base_snapshot_id,
+  target_snapshot_id,
+  starting_offset,
+  max_results,
+});
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use std::{
+    io,
+    sync::{Arc, Mutex},
+  };
+  use tracing_subscriber::fmt::format::FmtSpan;
+
+  #[derive(Clone, Default)]
+  struct Output(Arc<Mutex<Vec<u8>>>);
+
+  impl io::Write for Output {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+      self.0.lock().unwrap().extend_from_slice(buf);
+      Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+      Ok(())
+    }
+  }
+
+  fn capability() -> proto::VolumeCapability {
+    proto::VolumeCapability {
+      access_type: Some(proto::volume_capability::AccessType::Mount(
+        proto::volume_capability::MountVolume {
+          fs_type: "ext4".into(),
+          mount_flags: vec!["password=hunter2".into()],
+          ..Default::default()
+        },
+      )),
+      ..Default::default()
+    }
+  }
+
+  #[test]
+  fn never_records_mount_flags() {
+    for &format in &[TraceFormat::Debug, TraceFormat::Fields] {
+      let output = Output::default();
+      let writer = output.clone();
+      let subscriber = tracing_subscriber::fmt()
+        .with_max_level(tracing::Level::DEBUG)
+        .with_span_events(FmtSpan::CLOSE)
+        .with_ansi(false)
+        .with_writer(move || writer.clone())
+        .finish();
+
+      tracing::subscriber::with_default(subscriber, || {
+        let span = tracing::debug_span!("node.publish_volume", request = field::Empty);
+        span.in_scope(|| {
+          proto::NodePublishVolumeRequest {
+            volume_capability: Some(capability()),
+            ..Default::default()
+          }
+          .trace(format, "request");
+        });
+
+        let span = tracing::debug_span!(
+          "controller.validate_volume_capabilities",
+          response = field::Empty
+        );
+        span.in_scope(|| {
+          proto::ValidateVolumeCapabilitiesResponse {
+            confirmed: Some(proto::validate_volume_capabilities_response::Confirmed {
+              volume_capabilities: vec![capability()],
+              ..Default::default()
+            }),
+            ..Default::default()
+          }
+          .trace(format, "response");
+        });
+      });
+
+      let output = String::from_utf8(output.0.lock().unwrap().clone()).unwrap();
+      assert!(!output.contains("hunter2"), "{:?}: {}", format, output);
+      assert_eq!(
+        output.matches("REDACTED (1 items)").count(),
+        2,
+        "{:?}: {}",
+        format,
+        output
+      );
+    }
+  }
+}
@@ -0,0 +1,126 @@
+mod block_metadata;
+mod get_metadata_allocated;
+mod get_metadata_delta;
+mod stream;
+
+use crate::{proto, secrets::*, server::Dispatch, IdentityService};
+use async_trait::async_trait;
+use futures::stream::BoxStream;
+use std::{convert::TryInto, sync::Arc};
+use stream::MetadataStream;
+use tracing::instrument;
+
+pub use block_metadata::*;
+pub use get_metadata_allocated::*;
+pub use get_metadata_delta::*;
+
+/// A stream of block metadata, as returned by the methods of
+/// [`SnapshotMetadataService`]. An error ends the call with that status.
+pub type BlockMetadataStream = BoxStream<'static, Result<BlockMetadataBatch, tonic::Status>>;
+
+/// **ALPHA FEATURE**
+///
+/// The `SnapshotMetadata` service, for retrieving the allocated or changed
+/// blocks of block volume snapshots. It is served next to the `Controller`
+/// service when enabled with
+/// [`PluginServer::snapshot_metadata`](crate::PluginServer::snapshot_metadata).
+///
+/// Both methods return a stream of [`BlockMetadataBatch`]es. The stream is
+/// polled as the client reads the responses, and dropped if the client
+/// disconnects, so any work it does is cancelled with it. The server
+/// splits batches larger than the `max_results` of the request, and ends
+/// the stream with `INTERNAL` if the batches break the ordering rules of
+/// the CSI spec.
+#[async_trait]
+pub trait SnapshotMetadataService: IdentityService {
+  /// Return metadata on the allocated blocks of a snapshot: i.e. the data
+  /// ranges that have valid data as they were the target of some previous
+  /// write operation on the volume.
+  ///
+  /// If the snapshot does not exist, the Plugin MUST return
+  /// [`GetMetadataAllocatedError::SnapshotNotFound`].
+  async fn get_metadata_allocated(
+    &self,
+    request: GetMetadataAllocatedRequest,
+  ) -> Result<BlockMetadataStream, GetMetadataAllocatedError>;
+
+  /// Return metadata on the blocks that have changed between the base and
+  /// target snapshots of the same volume.
+  ///
+  /// If either snapshot does not exist, the Plugin MUST return
+  /// [`GetMetadataDeltaError::SnapshotNotFound`].
+  async fn get_metadata_delta(
+    &self,
+    request: GetMetadataDeltaRequest,
+  ) -> Result<BlockMetadataStream, GetMetadataDeltaError>;
+}
+
+pub(crate) struct SnapshotMetadata<T: SnapshotMetadataService> {
+  pub(crate) plugin: Arc<T>,
+  pub(crate) dispatch: Arc<Dispatch>,
+}
+
+#[async_trait]
+impl<T: SnapshotMetadataService> proto::snapshot_metadata_server::SnapshotMetadata
+  for SnapshotMetadata<T>
+{
+  type GetMetadataAllocatedStream = MetadataStream<proto::GetMetadataAllocatedResponse>;
+
+  #[instrument(
+    name = "snapshot_metadata.get_metadata_allocated",
+    skip(self, request),
+    fields(request, response, code, error, latency_ms)
+  )]
+  async fn get_metadata_allocated(
+    &self,
+    request: tonic::Request<proto::GetMetadataAllocatedRequest>,
+  ) -> Result<tonic::Response<Self::GetMetadataAllocatedStream>, tonic::Status> {
+    self
+      .dispatch
+      .call(
+        "GetMetadataAllocated",
+        request,
+        |request, call| async move {
+          let request: GetMetadataAllocatedRequest = request.try_into()?;
+          let starting_offset = request.starting_offset();
+          let max_results = request.max_results();
+          let stream = self.plugin.get_metadata_allocated(request).await?;
+          Ok(MetadataStream::new(
+            stream,
+            starting_offset,
+            max_results,
+            call,
+          ))
+        },
+      )
+      .await
+  }
+
+  type GetMetadataDeltaStream = MetadataStream<proto::GetMetadataDeltaResponse>;
+
+  #[instrument(
+    name = "snapshot_metadata.get_metadata_delta",
+    skip(self, request),
+    fields(request, response, code, error, latency_ms)
+  )]
+  async fn get_metadata_delta(
+    &self,
+    request: tonic::Request<proto::GetMetadataDeltaRequest>,
+  ) -> Result<tonic::Response<Self::GetMetadataDeltaStream>, tonic::Status> {
+    self
+      .dispatch
+      .call("GetMetadataDelta", request, |request, call| async move {
+        let request: GetMetadataDeltaRequest = request.try_into()?;
+        let starting_offset = request.starting_offset();
+        let max_results = request.max_results();
+        let stream = self.plugin.get_metadata_delta(request).await?;
+        Ok(MetadataStream::new(
+          stream,
+          starting_offset,
+          max_results,
+          call,
+        ))
+      })
+      .await
+  }
+}
@@ -0,0 +1,172 @@
+use crate::proto;
+use std::{fmt, num::NonZeroU64};
+
+/// Describes how the data ranges in a [`BlockMetadataBatch`] should be
+/// interpreted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BlockMetadataType {
+  /// Data ranges are returned in fixed size blocks. All data ranges in
+  /// the stream MUST have the same `size_bytes`.
+  FixedLength,
+
+  /// Data ranges are returned in potentially variable sized extents.
+  VariableLength,
+}
+
+impl From<BlockMetadataType> for proto::BlockMetadataType {
+  #[inline]
+  fn from(value: BlockMetadataType) -> Self {
+    match value {
+      BlockMetadataType::FixedLength => proto::BlockMetadataType::FixedLength,
+      BlockMetadataType::VariableLength => proto::BlockMetadataType::VariableLength,
+    }
+  }
+}
+
+/// A data range in a volume or snapshot.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct BlockMetadata {
+  byte_offset: u64,
+  size_bytes: NonZeroU64,
+}
+
+impl BlockMetadata {
+  /// Create a data range of `size_bytes` starting at `byte_offset`. Fails
+  /// with `INTERNAL` if the end of the range is out of range.
+  pub fn new(byte_offset: u64, size_bytes: NonZeroU64) -> Result<Self, tonic::Status> {
+    match byte_offset.checked_add(size_bytes.get()) {
+      Some(end) if end <= i64::MAX as u64 => Ok(BlockMetadata {
+        byte_offset,
+        size_bytes,
+      }),
+      _ => Err(tonic::Status::internal("BlockMetadata is out of range")),
+    }
+  }
+
+  /// The zero based byte position in the volume or snapshot, measured
+  /// from the start of the object.
+  #[inline]
+  pub fn byte_offset(&self) -> u64 {
+    self.byte_offset
+  }
+
+  /// The size of the data range.
+  #[inline]
+  pub fn size_bytes(&self) -> NonZeroU64 {
+    self.size_bytes
+  }
+
+  /// The byte position immediately after the last byte of the data range.
+  #[inline]
+  pub fn end(&self) -> u64 {
+    self.byte_offset + self.size_bytes.get()
+  }
+}
+
+impl fmt::Debug for BlockMetadata {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "{}+{}", self.byte_offset, self.size_bytes)
+  }
+}
+
+impl From<BlockMetadata> for proto::BlockMetadata {
+  #[inline]
+  fn from(value: BlockMetadata) -> Self {
+    proto::BlockMetadata {
+      byte_offset: value.byte_offset as i64,
+      size_bytes: value.size_bytes.get() as i64,
+    }
+  }
+}
+
+/// One message in a `GetMetadataAllocated` or `GetMetadataDelta` response
+/// stream.
+///
+/// The `block_metadata_type` and `volume_capacity_bytes` MUST be the same
+/// in all batches of a stream, and data ranges MUST be strictly increasing
+/// and non-overlapping across the whole stream. Batches that break these
+/// rules end the stream with `INTERNAL`. Batches larger than the
+/// `max_results` of the request are split before being sent.
+#[derive(Debug, Clone)]
+pub struct BlockMetadataBatch {
+  block_metadata_type: BlockMetadataType,
+  volume_capacity_bytes: u64,
+  block_metadata: Vec<BlockMetadata>,
+}
+
+impl BlockMetadataBatch {
+  /// Create a batch. Fails with `INTERNAL` if `volume_capacity_bytes` is
+  /// out of range.
+  pub fn new(
+    block_metadata_type: BlockMetadataType,
+    volume_capacity_bytes: u64,
+    block_metadata: Vec<BlockMetadata>,
+  ) -> Result<Self, tonic::Status> {
+    if volume_capacity_bytes > i64::MAX as u64 {
+      return Err(tonic::Status::internal(
+        "BlockMetadataBatch.volume_capacity_bytes is out of range",
+      ));
+    }
+
+    Ok(BlockMetadataBatch {
+      block_metadata_type,
+      volume_capacity_bytes,
+      block_metadata,
+    })
+  }
+
+  #[inline]
+  pub fn block_metadata_type(&self) -> BlockMetadataType {
+    self.block_metadata_type
+  }
+
+  #[inline]
+  pub fn volume_capacity_bytes(&self) -> u64 {
+    self.volume_capacity_bytes
+  }
+
+  #[inline]
+  pub fn block_metadata(&self) -> &[BlockMetadata] {
+    &self.block_metadata
+  }
+
+  /// Split the batch into batches of at most `max_results` data ranges.
+  pub(crate) fn split(self, max_results: usize) -> impl Iterator<Item = BlockMetadataBatch> {
+    let block_metadata_type = self.block_metadata_type;
+    let volume_capacity_bytes = self.volume_capacity_bytes;
+    let chunks = if self.block_metadata.is_empty() {
+      vec![Vec::new()]
+    } else {
+      self
+        .block_metadata
+        .chunks(max_results)
+        .map(<[_]>::to_vec)
+        .collect()
+    };
+
+    chunks
+      .into_iter()
+      .map(move |block_metadata| BlockMetadataBatch {
+        block_metadata_type,
+        volume_capacity_bytes,
+        block_metadata,
+      })
+  }
+}
+
+macro_rules! impl_response {
+  ($name:ident) => {
+    impl From<BlockMetadataBatch> for proto::$name {
+      fn from(value: BlockMetadataBatch) -> Self {
+        proto::$name {
+          block_metadata_type: proto::BlockMetadataType::from(value.block_metadata_type).into(),
+          volume_capacity_bytes: value.volume_capacity_bytes as i64,
+          block_metadata: value.block_metadata.into_iter().map(Into::into).collect(),
+        }
+      }
+    }
+  };
+}
+
+impl_response!(GetMetadataAllocatedResponse);
+impl_response!(GetMetadataDeltaResponse);
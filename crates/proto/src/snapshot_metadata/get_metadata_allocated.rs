@@ -0,0 +1,182 @@
+use super::Secrets;
+use crate::proto;
+#[cfg(feature = "test-util")]
+use std::collections::HashMap;
+use std::{convert::TryFrom, num::NonZeroU32};
+use thiserror::Error;
+
+#[derive(Debug)]
+pub struct GetMetadataAllocatedRequest {
+  snapshot_id: String,
+  starting_offset: u64,
+  max_results: Option<NonZeroU32>,
+  secrets: Secrets,
+}
+
+impl GetMetadataAllocatedRequest {
+  /// The ID of the snapshot.
+  #[inline]
+  pub fn snapshot_id(&self) -> &str {
+    &self.snapshot_id
+  }
+
+  /// The zero based starting byte position in the volume snapshot from
+  /// which the result should be computed. It is used to continue a
+  /// previously interrupted call. The returned stream MUST NOT contain
+  /// data ranges that end before the starting offset.
+  #[inline]
+  pub fn starting_offset(&self) -> u64 {
+    self.starting_offset
+  }
+
+  /// The maximum number of data ranges to return in each message of the
+  /// stream. The plugin determines an appropriate value if `None`. Larger
+  /// batches are split by the server, so the plugin is free to ignore it.
+  #[inline]
+  pub fn max_results(&self) -> Option<NonZeroU32> {
+    self.max_results
+  }
+
+  /// Secrets required by plugin to complete the request. This field is
+  /// OPTIONAL. Refer to the `Secrets Requirements` section on how to use
+  /// this field.
+  #[inline]
+  pub fn secrets(&self) -> &Secrets {
+    &self.secrets
+  }
+}
+
+#[cfg(feature = "test-util")]
+impl GetMetadataAllocatedRequest {
+  #[inline]
+  pub fn new(snapshot_id: impl Into<String>) -> Self {
+    GetMetadataAllocatedRequest {
+      snapshot_id: snapshot_id.into(),
+      starting_offset: 0,
+      max_results: None,
+      secrets: Secrets::default(),
+    }
+  }
+
+  #[inline]
+  pub fn with_starting_offset(mut self, starting_offset: u64) -> Self {
+    self.starting_offset = starting_offset;
+    self
+  }
+
+  #[inline]
+  pub fn with_max_results(mut self, max_results: NonZeroU32) -> Self {
+    self.max_results = Some(max_results);
+    self
+  }
+
+  #[inline]
+  pub fn with_secrets(mut self, secrets: HashMap<String, String>) -> Self {
+    self.secrets = secrets.into();
+    self
+  }
+}
+
+impl TryFrom<proto::GetMetadataAllocatedRequest> for GetMetadataAllocatedRequest {
+  type Error = tonic::Status;
+
+  fn try_from(value: proto::GetMetadataAllocatedRequest) -> Result<Self, Self::Error> {
+    let snapshot_id = match value.snapshot_id {
+      v if v.is_empty() => {
+        return Err(tonic::Status::invalid_argument(
+          "GetMetadataAllocatedRequest.snapshot_id is empty",
+        ))
+      }
+      v => v,
+    };
+
+    let starting_offset = match value.starting_offset {
+      v if v < 0 => {
+        return Err(tonic::Status::invalid_argument(
+          "GetMetadataAllocatedRequest.starting_offset is negative",
+        ))
+      }
+      v => v as u64,
+    };
+
+    let max_results = match value.max_results {
+      v if v < 0 => {
+        return Err(tonic::Status::invalid_argument(
+          "GetMetadataAllocatedRequest.max_results is negative",
+        ))
+      }
+      v => NonZeroU32::new(v as u32),
+    };
+
+    let secrets = value.secrets.into();
+
+    Ok(GetMetadataAllocatedRequest {
+      snapshot_id,
+      starting_offset,
+      max_results,
+      secrets,
+    })
+  }
+}
+
+#[non_exhaustive]
+#[derive(Debug, Error)]
+pub enum GetMetadataAllocatedError {
+  /// Indicates that a snapshot corresponding to the specified `snapshot_id`
+  /// does not exist.
+  #[error("Snapshot does not exist: {0}")]
+  SnapshotNotFound(String),
+
+  /// Indicates that the `starting_offset` is not within the volume, for
+  /// example because it is greater than or equal to the volume capacity.
+  #[error("Starting offset out of range: {0}")]
+  StartingOffsetOutOfRange(u64),
+
+  #[error(transparent)]
+  #[doc(hidden)]
+  Other(#[from] tonic::Status),
+}
+
+use tonic::{Code, Status};
+impl From<GetMetadataAllocatedError> for tonic::Status {
+  fn from(value: GetMetadataAllocatedError) -> Self {
+    match value {
+      GetMetadataAllocatedError::Other(v) => v,
+      value => {
+        let code = match &value {
+          GetMetadataAllocatedError::SnapshotNotFound(_) => Code::NotFound,
+          GetMetadataAllocatedError::StartingOffsetOutOfRange(_) => Code::OutOfRange,
+          GetMetadataAllocatedError::Other(_) => unreachable!(),
+        };
+
+        Status::new(code, value.to_string())
+      }
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use std::collections::HashMap;
+  use test_case::test_case;
+
+  fn request(starting_offset: i64, max_results: i32) -> proto::GetMetadataAllocatedRequest {
+    proto::GetMetadataAllocatedRequest {
+      snapshot_id: "snap-1".into(),
+      starting_offset,
+      max_results,
+      secrets: HashMap::new(),
+    }
+  }
+
+  #[test_case(0, 0 => Ok((0, None)) ; "defaults")]
+  #[test_case(4096, 256 => Ok((4096, Some(256))) ; "continued")]
+  #[test_case(-1, 0 => Err(Code::InvalidArgument) ; "negative starting_offset")]
+  #[test_case(0, -1 => Err(Code::InvalidArgument) ; "negative max_results")]
+  fn validates_request(starting_offset: i64, max_results: i32) -> Result<(u64, Option<u32>), Code> {
+    GetMetadataAllocatedRequest::try_from(request(starting_offset, max_results))
+      .map(|r| (r.starting_offset(), r.max_results().map(NonZeroU32::get)))
+      .map_err(|e| e.code())
+  }
+}
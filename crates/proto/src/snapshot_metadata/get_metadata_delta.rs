@@ -0,0 +1,175 @@
+use super::Secrets;
+use crate::proto;
+#[cfg(feature = "test-util")]
+use std::collections::HashMap;
+use std::{convert::TryFrom, num::NonZeroU32};
+use thiserror::Error;
+
+#[derive(Debug)]
+pub struct GetMetadataDeltaRequest {
+  base_snapshot_id: String,
+  target_snapshot_id: String,
+  starting_offset: u64,
+  max_results: Option<NonZeroU32>,
+  secrets: Secrets,
+}
+
+impl GetMetadataDeltaRequest {
+  /// The ID of the snapshot against which changes are to be computed.
+  #[inline]
+  pub fn base_snapshot_id(&self) -> &str {
+    &self.base_snapshot_id
+  }
+
+  /// The ID of a second snapshot of the same volume, created after the
+  /// base snapshot.
+  #[inline]
+  pub fn target_snapshot_id(&self) -> &str {
+    &self.target_snapshot_id
+  }
+
+  /// The zero based starting byte position in the volume snapshot from
+  /// which the result should be computed. It is used to continue a
+  /// previously interrupted call. The returned stream MUST NOT contain
+  /// data ranges that end before the starting offset.
+  #[inline]
+  pub fn starting_offset(&self) -> u64 {
+    self.starting_offset
+  }
+
+  /// The maximum number of data ranges to return in each message of the
+  /// stream. The plugin determines an appropriate value if `None`. Larger
+  /// batches are split by the server, so the plugin is free to ignore it.
+  #[inline]
+  pub fn max_results(&self) -> Option<NonZeroU32> {
+    self.max_results
+  }
+
+  /// Secrets required by plugin to complete the request. This field is
+  /// OPTIONAL. Refer to the `Secrets Requirements` section on how to use
+  /// this field.
+  #[inline]
+  pub fn secrets(&self) -> &Secrets {
+    &self.secrets
+  }
+}
+
+#[cfg(feature = "test-util")]
+impl GetMetadataDeltaRequest {
+  #[inline]
+  pub fn new(base_snapshot_id: impl Into<String>, target_snapshot_id: impl Into<String>) -> Self {
+    GetMetadataDeltaRequest {
+      base_snapshot_id: base_snapshot_id.into(),
+      target_snapshot_id: target_snapshot_id.into(),
+      starting_offset: 0,
+      max_results: None,
+      secrets: Secrets::default(),
+    }
+  }
+
+  #[inline]
+  pub fn with_starting_offset(mut self, starting_offset: u64) -> Self {
+    self.starting_offset = starting_offset;
+    self
+  }
+
+  #[inline]
+  pub fn with_max_results(mut self, max_results: NonZeroU32) -> Self {
+    self.max_results = Some(max_results);
+    self
+  }
+
+  #[inline]
+  pub fn with_secrets(mut self, secrets: HashMap<String, String>) -> Self {
+    self.secrets = secrets.into();
+    self
+  }
+}
+
+impl TryFrom<proto::GetMetadataDeltaRequest> for GetMetadataDeltaRequest {
+  type Error = tonic::Status;
+
+  fn try_from(value: proto::GetMetadataDeltaRequest) -> Result<Self, Self::Error> {
+    let base_snapshot_id = match value.base_snapshot_id {
+      v if v.is_empty() => {
+        return Err(tonic::Status::invalid_argument(
+          "GetMetadataDeltaRequest.base_snapshot_id is empty",
+        ))
+      }
+      v => v,
+    };
+
+    let target_snapshot_id = match value.target_snapshot_id {
+      v if v.is_empty() => {
+        return Err(tonic::Status::invalid_argument(
+          "GetMetadataDeltaRequest.target_snapshot_id is empty",
+        ))
+      }
+      v => v,
+    };
+
+    let starting_offset = match value.starting_offset {
+      v if v < 0 => {
+        return Err(tonic::Status::invalid_argument(
+          "GetMetadataDeltaRequest.starting_offset is negative",
+        ))
+      }
+      v => v as u64,
+    };
+
+    let max_results = match value.max_results {
+      v if v < 0 => {
+        return Err(tonic::Status::invalid_argument(
+          "GetMetadataDeltaRequest.max_results is negative",
+        ))
+      }
+      v => NonZeroU32::new(v as u32),
+    };
+
+    let secrets = value.secrets.into();
+
+    Ok(GetMetadataDeltaRequest {
+      base_snapshot_id,
+      target_snapshot_id,
+      starting_offset,
+      max_results,
+      secrets,
+    })
+  }
+}
+
+#[non_exhaustive]
+#[derive(Debug, Error)]
+pub enum GetMetadataDeltaError {
+  /// Indicates that a snapshot corresponding to the specified
+  /// `base_snapshot_id` or `target_snapshot_id` does not exist.
+  #[error("Snapshot does not exist: {0}")]
+  SnapshotNotFound(String),
+
+  /// Indicates that the `starting_offset` is not within the volume, for
+  /// example because it is greater than or equal to the volume capacity.
+  #[error("Starting offset out of range: {0}")]
+  StartingOffsetOutOfRange(u64),
+
+  #[error(transparent)]
+  #[doc(hidden)]
+  Other(#[from] tonic::Status),
+}
+
+use tonic::{Code, Status};
+impl From<GetMetadataDeltaError> for tonic::Status {
+  fn from(value: GetMetadataDeltaError) -> Self {
+    match value {
+      GetMetadataDeltaError::Other(v) => v,
+      value => {
+        let code = match &value {
+          GetMetadataDeltaError::SnapshotNotFound(_) => Code::NotFound,
+          GetMetadataDeltaError::StartingOffsetOutOfRange(_) => Code::OutOfRange,
+          GetMetadataDeltaError::Other(_) => unreachable!(),
+        };
+
+        Status::new(code, value.to_string())
+      }
+    }
+  }
+}
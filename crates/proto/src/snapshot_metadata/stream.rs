@@ -0,0 +1,243 @@
+use super::{BlockMetadataBatch, BlockMetadataStream, BlockMetadataType};
+use crate::server::{Call, Trace, TraceFormat};
+use futures::{ready, Stream, StreamExt};
+use std::{
+  collections::VecDeque,
+  marker::PhantomData,
+  num::{NonZeroU32, NonZeroU64},
+  pin::Pin,
+  sync::Mutex,
+  task::{Context, Poll},
+};
+
+/// Adapts the [`BlockMetadataStream`] returned by a plugin to a gRPC
+/// response stream of `R`.
+///
+/// The plugin's stream is only polled when the transport asks for the next
+/// message, so a slow client slows down the plugin instead of making the
+/// server buffer. When the client disconnects, this stream, and with it the
+/// plugin's stream, is dropped. Until then the call is kept in-flight, so
+/// graceful shutdown waits for it.
+pub(crate) struct MetadataStream<R> {
+  // Only accessed through `Mutex::get_mut`, which never locks. It makes
+  // the stream `Sync`, as required by tonic.
+  inner: Mutex<BlockMetadataStream>,
+  validator: Validator,
+  max_results: usize,
+  pending: VecDeque<BlockMetadataBatch>,
+  done: bool,
+  _call: Call,
+  response: PhantomData<fn() -> R>,
+}
+
+impl<R> MetadataStream<R> {
+  pub(crate) fn new(
+    inner: BlockMetadataStream,
+    starting_offset: u64,
+    max_results: Option<NonZeroU32>,
+    call: Call,
+  ) -> Self {
+    MetadataStream {
+      inner: Mutex::new(inner),
+      validator: Validator {
+        starting_offset,
+        header: None,
+        size_bytes: None,
+        end: None,
+      },
+      max_results: max_results.map_or(usize::MAX, |v| v.get() as usize),
+      pending: VecDeque::new(),
+      done: false,
+      _call: call,
+      response: PhantomData,
+    }
+  }
+}
+
+/// The responses are streamed after the call has returned, so they are
+/// not recorded.
+impl<R> Trace for MetadataStream<R> {
+  #[inline]
+  fn trace(&self, _format: TraceFormat, _kind: &'static str) {}
+}
+
+impl<R: From<BlockMetadataBatch>> Stream for MetadataStream<R> {
+  type Item = Result<R, tonic::Status>;
+
+  fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+    let this = self.get_mut();
+    loop {
+      if let Some(batch) = this.pending.pop_front() {
+        return Poll::Ready(Some(Ok(batch.into())));
+      }
+
+      if this.done {
+        return Poll::Ready(None);
+      }
+
+      let inner = this.inner.get_mut().unwrap();
+      match ready!(inner.poll_next_unpin(cx)) {
+        Some(Ok(batch)) => {
+          if let Err(e) = this.validator.check(&batch) {
+            this.done = true;
+            return Poll::Ready(Some(Err(e)));
+          }
+
+          this.pending.extend(batch.split(this.max_results));
+        }
+        Some(Err(e)) => {
+          this.done = true;
+          return Poll::Ready(Some(Err(e)));
+        }
+        None => this.done = true,
+      }
+    }
+  }
+}
+
+/// Checks the rules the CSI spec puts on a block metadata stream as a
+/// whole.
+struct Validator {
+  starting_offset: u64,
+  header: Option<(BlockMetadataType, u64)>,
+  size_bytes: Option<NonZeroU64>,
+  end: Option<u64>,
+}
+
+impl Validator {
+  fn check(&mut self, batch: &BlockMetadataBatch) -> Result<(), tonic::Status> {
+    let header = (batch.block_metadata_type(), batch.volume_capacity_bytes());
+    match self.header {
+      None => self.header = Some(header),
+      Some(v) if v != header => {
+        return Err(tonic::Status::internal(
+          "BlockMetadataBatch.block_metadata_type and volume_capacity_bytes must not change",
+        ))
+      }
+      Some(_) => (),
+    }
+
+    for block in batch.block_metadata() {
+      if batch.block_metadata_type() == BlockMetadataType::FixedLength {
+        match self.size_bytes {
+          None => self.size_bytes = Some(block.size_bytes()),
+          Some(v) if v != block.size_bytes() => {
+            return Err(tonic::Status::internal(format!(
+              "BlockMetadata {:?} does not have the fixed length {}",
+              block, v
+            )))
+          }
+          Some(_) => (),
+        }
+      }
+
+      match self.end {
+        None if block.end() <= self.starting_offset => {
+          return Err(tonic::Status::internal(format!(
+            "BlockMetadata {:?} ends before starting_offset {}",
+            block, self.starting_offset
+          )))
+        }
+        Some(end) if block.byte_offset() < end => {
+          return Err(tonic::Status::internal(format!(
+            "BlockMetadata {:?} overlaps or precedes the previous data range",
+            block
+          )))
+        }
+        _ => (),
+      }
+
+      self.end = Some(block.end());
+    }
+
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::{proto, server::Dispatch, snapshot_metadata::BlockMetadata};
+  use futures::{executor::block_on, stream};
+  use std::sync::Arc;
+  use test_case::test_case;
+
+  fn batch(
+    block_metadata_type: BlockMetadataType,
+    capacity: u64,
+    blocks: &[(u64, u64)],
+  ) -> BlockMetadataBatch {
+    let blocks = blocks
+      .iter()
+      .map(|&(offset, size)| BlockMetadata::new(offset, NonZeroU64::new(size).unwrap()).unwrap())
+      .collect();
+    BlockMetadataBatch::new(block_metadata_type, capacity, blocks).unwrap()
+  }
+
+  fn run(
+    batches: Vec<BlockMetadataBatch>,
+    starting_offset: u64,
+    max_results: u32,
+  ) -> Result<Vec<usize>, tonic::Code> {
+    let dispatch = Arc::new(Dispatch::default());
+    let inner = stream::iter(batches.into_iter().map(Ok)).boxed();
+    let call = dispatch.begin("GetMetadataAllocated").unwrap();
+    let stream = MetadataStream::<proto::GetMetadataAllocatedResponse>::new(
+      inner,
+      starting_offset,
+      NonZeroU32::new(max_results),
+      call,
+    );
+
+    let responses = block_on(stream.collect::<Vec<_>>());
+    assert!(dispatch.running().is_empty());
+    responses
+      .into_iter()
+      .map(|r| r.map(|r| r.block_metadata.len()).map_err(|e| e.code()))
+      .collect()
+  }
+
+  #[test_case(0, 0 => Ok(vec![3, 1]) ; "unlimited")]
+  #[test_case(0, 2 => Ok(vec![2, 1, 1]) ; "split")]
+  #[test_case(1000, 0 => Ok(vec![3, 1]) ; "first range contains starting_offset")]
+  #[test_case(1024, 0 => Err(tonic::Code::Internal) ; "first range ends at starting_offset")]
+  fn variable_length(starting_offset: u64, max_results: u32) -> Result<Vec<usize>, tonic::Code> {
+    let batches = vec![
+      batch(
+        BlockMetadataType::VariableLength,
+        1 << 20,
+        &[(0, 1024), (4096, 512), (8192, 4096)],
+      ),
+      batch(BlockMetadataType::VariableLength, 1 << 20, &[(16384, 1)]),
+    ];
+
+    run(batches, starting_offset, max_results)
+  }
+
+  #[test_case(&[(0, 512), (512, 512)] => Ok(vec![2]) ; "adjacent")]
+  #[test_case(&[(0, 512), (256, 512)] => Err(tonic::Code::Internal) ; "overlapping")]
+  #[test_case(&[(4096, 512), (0, 512)] => Err(tonic::Code::Internal) ; "out of order")]
+  fn ordering(blocks: &[(u64, u64)]) -> Result<Vec<usize>, tonic::Code> {
+    let batches = vec![batch(BlockMetadataType::VariableLength, 1 << 20, blocks)];
+    run(batches, 0, 0)
+  }
+
+  #[test]
+  fn rejects_changed_capacity() {
+    let batches = vec![
+      batch(BlockMetadataType::FixedLength, 1 << 20, &[(0, 512)]),
+      batch(BlockMetadataType::FixedLength, 1 << 21, &[(512, 512)]),
+    ];
+    assert_eq!(run(batches, 0, 0), Err(tonic::Code::Internal));
+  }
+
+  #[test]
+  fn rejects_variable_fixed_length() {
+    let batches = vec![batch(
+      BlockMetadataType::FixedLength,
+      1 << 20,
+      &[(0, 512), (512, 1024)],
+    )];
+    assert_eq!(run(batches, 0, 0), Err(tonic::Code::Internal));
+  }
+}
@@ -5,11 +5,16 @@ use std::{
   num::NonZeroU64,
 };
 
-use crate::proto;
+#[cfg(feature = "alpha")]
+use crate::controller::ControllerCapabilities;
+use crate::{
+  proto,
+  response::{Advertised, ValidateResponse},
+};
 
 pub type Topology = HashMap<String, String>;
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub enum VolumeContentSource {
   Snapshot(String),
   Volume(String),
@@ -71,13 +76,113 @@ pub struct Volume {
   accessible_topology: Vec<Topology>,
 }
 
+impl Volume {
+  /// Create a volume. Fails with `INTERNAL` if `volume_id` is empty, as
+  /// it is REQUIRED.
+  pub fn new(volume_id: impl Into<String>) -> Result<Self, tonic::Status> {
+    let volume_id = volume_id.into();
+    if volume_id.is_empty() {
+      return Err(tonic::Status::internal("Volume.volume_id is empty"));
+    }
+
+    Ok(Volume {
+      capacity_bytes: None,
+      volume_id,
+      volume_context: HashMap::new(),
+      content_source: None,
+      accessible_topology: Vec::new(),
+    })
+  }
+
+  /// Set the capacity of the volume in bytes. A value of 0 means the
+  /// capacity is unknown, which is also the default.
+  #[inline]
+  pub fn with_capacity_bytes(mut self, capacity_bytes: u64) -> Self {
+    self.capacity_bytes = NonZeroU64::new(capacity_bytes);
+    self
+  }
+
+  /// Set opaque static properties of the volume, passed to subsequent
+  /// `ControllerPublishVolume`, `NodeStageVolume` and `NodePublishVolume`
+  /// calls.
+  #[inline]
+  pub fn with_volume_context(mut self, volume_context: HashMap<String, String>) -> Self {
+    self.volume_context = volume_context;
+    self
+  }
+
+  /// Set the snapshot or volume the volume was pre-populated from. This
+  /// MUST be set if the request specified a `volume_content_source`.
+  #[inline]
+  pub fn with_content_source(mut self, content_source: VolumeContentSource) -> Self {
+    self.content_source = Some(content_source);
+    self
+  }
+
+  /// Add a topological segment the volume is accessible from. May be
+  /// called multiple times.
+  #[inline]
+  pub fn with_accessible_topology(mut self, topology: Topology) -> Self {
+    self.accessible_topology.push(topology);
+    self
+  }
+
+  /// The capacity of the volume in bytes, `None` if unknown.
+  #[inline]
+  pub fn capacity_bytes(&self) -> Option<NonZeroU64> {
+    self.capacity_bytes
+  }
+
+  /// The identifier for this volume, generated by the plugin.
+  #[inline]
+  pub fn volume_id(&self) -> &str {
+    &self.volume_id
+  }
+
+  /// Opaque static properties of the volume.
+  #[inline]
+  pub fn volume_context(&self) -> &HashMap<String, String> {
+    &self.volume_context
+  }
+
+  /// The source the volume was pre-populated from, if any.
+  #[inline]
+  pub fn content_source(&self) -> Option<&VolumeContentSource> {
+    self.content_source.as_ref()
+  }
+
+  /// The topological segments the volume is accessible from.
+  #[inline]
+  pub fn accessible_topology(&self) -> &[Topology] {
+    &self.accessible_topology
+  }
+}
+
+impl ValidateResponse for Volume {
+  fn validate(&self, advertised: &Advertised) -> Result<(), String> {
+    if !self.accessible_topology.is_empty() && !advertised.accessibility_constraints {
+      return Err(format!(
+        "volume {} has an accessible_topology, but the VOLUME_ACCESSIBILITY_CONSTRAINTS plugin capability is not advertised",
+        self.volume_id
+      ));
+    }
+
+    Ok(())
+  }
+}
+
 impl TryFrom<Volume> for proto::Volume {
   type Error = tonic::Status;
 
   fn try_from(value: Volume) -> Result<Self, Self::Error> {
+    if value.volume_id.is_empty() {
+      return Err(tonic::Status::internal("Volume.volume_id is empty"));
+    }
+
     let capacity_bytes = match value.capacity_bytes {
       None => 0,
-      Some(v) => v.get() as i64,
+      Some(v) => i64::try_from(v.get())
+        .map_err(|_| tonic::Status::internal("Volume.capacity_bytes is out of range"))?,
     };
     let volume_id = value.volume_id;
     let volume_context = value.volume_context;
@@ -108,13 +213,36 @@ impl TryFrom<Volume> for proto::CreateVolumeResponse {
   }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct VolumeCapability {
   access_mode: AccessMode,
   access_type: AccessType,
 }
 
-#[derive(Debug)]
+impl VolumeCapability {
+  #[inline]
+  pub fn new(access_mode: AccessMode, access_type: AccessType) -> Self {
+    VolumeCapability {
+      access_mode,
+      access_type,
+    }
+  }
+
+  /// How the volume will be accessed by workloads.
+  #[inline]
+  pub fn access_mode(&self) -> &AccessMode {
+    &self.access_mode
+  }
+
+  /// Whether the volume will be accessed as a block device or through
+  /// a mounted filesystem.
+  #[inline]
+  pub fn access_type(&self) -> &AccessType {
+    &self.access_type
+  }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 #[non_exhaustive]
 pub enum AccessMode {
   Unknown,
@@ -132,6 +260,16 @@ pub enum AccessMode {
   /// Can be published as read/write at multiple nodes
   /// simultaneously.
   MultiNodeMultiWriter,
+  /// Can only be published once as read/write at a single workload
+  /// on a single node, at any given time. SHOULD be used instead of
+  /// `SingleNodeWriter` for COs using the `SINGLE_NODE_MULTI_WRITER`
+  /// capability.
+  SingleNodeSingleWriter,
+  /// Can be published as read/write at multiple workloads on a
+  /// single node simultaneously. SHOULD be used instead of
+  /// `SingleNodeWriter` for COs using the `SINGLE_NODE_MULTI_WRITER`
+  /// capability.
+  SingleNodeMultiWriter,
 }
 
 impl TryFrom<proto::volume_capability::AccessMode> for AccessMode {
@@ -155,6 +293,12 @@ impl TryFrom<proto::volume_capability::AccessMode> for AccessMode {
         Some(proto::volume_capability::access_mode::Mode::MultiNodeMultiWriter) => {
           AccessMode::MultiNodeMultiWriter
         }
+        Some(proto::volume_capability::access_mode::Mode::SingleNodeSingleWriter) => {
+          AccessMode::SingleNodeSingleWriter
+        }
+        Some(proto::volume_capability::access_mode::Mode::SingleNodeMultiWriter) => {
+          AccessMode::SingleNodeMultiWriter
+        }
         _ => AccessMode::Unknown,
       },
     )
@@ -180,13 +324,19 @@ impl TryFrom<AccessMode> for proto::volume_capability::AccessMode {
       AccessMode::MultiNodeMultiWriter => {
         proto::volume_capability::access_mode::Mode::MultiNodeMultiWriter
       }
+      AccessMode::SingleNodeSingleWriter => {
+        proto::volume_capability::access_mode::Mode::SingleNodeSingleWriter
+      }
+      AccessMode::SingleNodeMultiWriter => {
+        proto::volume_capability::access_mode::Mode::SingleNodeMultiWriter
+      }
     } as i32;
 
     Ok(proto::volume_capability::AccessMode { mode })
   }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub enum AccessType {
   /// Indicate that the volume will be accessed via the block device API.
   Block,
@@ -219,12 +369,29 @@ impl TryFrom<AccessType> for proto::volume_capability::AccessType {
   }
 }
 
+#[derive(Clone)]
 pub struct MountVolume {
   fs_type: Option<String>,
   mount_flags: Vec<String>,
+  volume_mount_group: Option<String>,
 }
 
 impl MountVolume {
+  #[inline]
+  pub fn new(fs_type: Option<String>, mount_flags: Vec<String>) -> Self {
+    MountVolume {
+      fs_type,
+      mount_flags,
+      volume_mount_group: None,
+    }
+  }
+
+  #[inline]
+  pub fn with_volume_mount_group(mut self, volume_mount_group: impl Into<String>) -> Self {
+    self.volume_mount_group = Some(volume_mount_group.into()).filter(|v| !v.is_empty());
+    self
+  }
+
   /// The filesystem type.
   #[inline]
   pub fn fs_type(&self) -> Option<&str> {
@@ -239,6 +406,15 @@ impl MountVolume {
   pub fn mount_flags(&self) -> impl Iterator<Item = &str> + ExactSizeIterator {
     self.mount_flags.iter().map(|v| &**v)
   }
+
+  /// The group identifier the volume should be mounted with, if the
+  /// plugin has the `VOLUME_MOUNT_GROUP` node capability. New files
+  /// and directories SHOULD be readable and writable by this group.
+  /// This field is OPTIONAL.
+  #[inline]
+  pub fn volume_mount_group(&self) -> Option<&str> {
+    self.volume_mount_group.as_deref()
+  }
 }
 
 impl TryFrom<proto::volume_capability::MountVolume> for MountVolume {
@@ -251,10 +427,15 @@ impl TryFrom<proto::volume_capability::MountVolume> for MountVolume {
     };
 
     let mount_flags = value.mount_flags;
+    let volume_mount_group = match value.volume_mount_group {
+      v if v.is_empty() => None,
+      v => Some(v),
+    };
 
     Ok(MountVolume {
       fs_type,
       mount_flags,
+      volume_mount_group,
     })
   }
 }
@@ -265,10 +446,12 @@ impl TryFrom<MountVolume> for proto::volume_capability::MountVolume {
   fn try_from(value: MountVolume) -> Result<Self, Self::Error> {
     let fs_type = value.fs_type.unwrap_or_default();
     let mount_flags = value.mount_flags;
+    let volume_mount_group = value.volume_mount_group.unwrap_or_default();
 
     Ok(proto::volume_capability::MountVolume {
       fs_type,
       mount_flags,
+      volume_mount_group,
     })
   }
 }
@@ -281,6 +464,7 @@ impl fmt::Debug for MountVolume {
         "mount_flags",
         &format!("REDACTED ({} items)", self.mount_flags.len()),
       )
+      .field("volume_mount_group", &self.volume_mount_group)
       .finish()
   }
 }
@@ -320,6 +504,7 @@ impl TryFrom<VolumeCapability> for proto::VolumeCapability {
   }
 }
 
+#[cfg(feature = "alpha")]
 #[derive(Debug)]
 pub struct VolumeCondition {
   /// Normal volumes are available for use and operating optimally.
@@ -330,6 +515,31 @@ pub struct VolumeCondition {
   message: String,
 }
 
+#[cfg(feature = "alpha")]
+impl VolumeCondition {
+  /// Create a volume condition. Fails with `INTERNAL` if `message` is
+  /// empty, as it is REQUIRED.
+  pub fn new(abnormal: bool, message: impl Into<String>) -> Result<Self, tonic::Status> {
+    let message = message.into();
+    if message.is_empty() {
+      return Err(tonic::Status::internal("VolumeCondition.message is empty"));
+    }
+
+    Ok(VolumeCondition { abnormal, message })
+  }
+
+  #[inline]
+  pub fn abnormal(&self) -> bool {
+    self.abnormal
+  }
+
+  #[inline]
+  pub fn message(&self) -> &str {
+    &self.message
+  }
+}
+
+#[cfg(feature = "alpha")]
 impl TryFrom<VolumeCondition> for proto::VolumeCondition {
   type Error = tonic::Status;
 
@@ -341,7 +551,7 @@ impl TryFrom<VolumeCondition> for proto::VolumeCondition {
   }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Default)]
 pub struct VolumeStatus {
   /// A list of all `node_id` of nodes that the volume in this entry
   /// is controller published on.
@@ -359,23 +569,80 @@ pub struct VolumeStatus {
   /// This field is OPTIONAL.
   /// This field MUST be specified if the
   /// VOLUME_CONDITION controller capability is supported.
+  #[cfg(feature = "alpha")]
   volume_condition: Option<VolumeCondition>,
 }
 
+impl VolumeStatus {
+  /// Create an empty volume status, with neither published nodes nor a
+  /// volume condition.
+  #[inline]
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  #[inline]
+  pub fn with_published_node_ids(mut self, published_node_ids: Vec<String>) -> Self {
+    self.published_node_ids = published_node_ids;
+    self
+  }
+
+  #[cfg(feature = "alpha")]
+  #[inline]
+  pub fn with_volume_condition(mut self, volume_condition: VolumeCondition) -> Self {
+    self.volume_condition = Some(volume_condition);
+    self
+  }
+
+  #[inline]
+  pub fn published_node_ids(&self) -> impl ExactSizeIterator<Item = &str> {
+    self.published_node_ids.iter().map(|v| &**v)
+  }
+
+  #[cfg(feature = "alpha")]
+  #[inline]
+  pub fn volume_condition(&self) -> Option<&VolumeCondition> {
+    self.volume_condition.as_ref()
+  }
+}
+
+#[cfg(feature = "alpha")]
+impl ValidateResponse for VolumeStatus {
+  fn validate(&self, advertised: &Advertised) -> Result<(), String> {
+    let volume_condition = advertised
+      .controller
+      .contains(ControllerCapabilities::VOLUME_CONDITION);
+    match (&self.volume_condition, volume_condition) {
+      (Some(_), false) => Err(
+        "has a volume_condition, but the VOLUME_CONDITION controller capability is not advertised"
+          .into(),
+      ),
+      (None, true) => Err(
+        "has no volume_condition, but the VOLUME_CONDITION controller capability is advertised"
+          .into(),
+      ),
+      _ => Ok(()),
+    }
+  }
+}
+
 impl TryFrom<VolumeStatus> for proto::list_volumes_response::VolumeStatus {
   type Error = tonic::Status;
 
   fn try_from(value: VolumeStatus) -> Result<Self, Self::Error> {
     let published_node_ids = value.published_node_ids;
+    #[cfg(feature = "alpha")]
     let volume_condition = value.volume_condition.map(TryInto::try_into).transpose()?;
 
     Ok(proto::list_volumes_response::VolumeStatus {
       published_node_ids,
+      #[cfg(feature = "alpha")]
       volume_condition,
     })
   }
 }
 
+#[cfg(feature = "alpha")]
 impl TryFrom<VolumeStatus> for proto::controller_get_volume_response::VolumeStatus {
   type Error = tonic::Status;
 
@@ -424,13 +691,64 @@ pub struct VolumeUsage {
   unit: VolumeUsageUnit,
 }
 
+impl VolumeUsage {
+  /// Create a volume usage. `unit` and `total` are REQUIRED.
+  #[inline]
+  pub fn new(unit: VolumeUsageUnit, total: NonZeroU64) -> Self {
+    VolumeUsage {
+      available: None,
+      total,
+      used: None,
+      unit,
+    }
+  }
+
+  /// Set the available capacity, in the unit of the usage.
+  #[inline]
+  pub fn with_available(mut self, available: u64) -> Self {
+    self.available = NonZeroU64::new(available);
+    self
+  }
+
+  /// Set the used capacity, in the unit of the usage.
+  #[inline]
+  pub fn with_used(mut self, used: u64) -> Self {
+    self.used = NonZeroU64::new(used);
+    self
+  }
+
+  #[inline]
+  pub fn available(&self) -> Option<NonZeroU64> {
+    self.available
+  }
+
+  #[inline]
+  pub fn total(&self) -> NonZeroU64 {
+    self.total
+  }
+
+  #[inline]
+  pub fn used(&self) -> Option<NonZeroU64> {
+    self.used
+  }
+
+  #[inline]
+  pub fn unit(&self) -> &VolumeUsageUnit {
+    &self.unit
+  }
+}
+
 impl TryFrom<VolumeUsage> for proto::VolumeUsage {
   type Error = tonic::Status;
 
   fn try_from(value: VolumeUsage) -> Result<Self, Self::Error> {
-    let available = value.available.map(|v| v.get() as i64).unwrap_or_default();
-    let total = value.total.get() as i64;
-    let used = value.used.map(|v| v.get() as i64).unwrap_or_default();
+    let to_i64 = |v: Option<NonZeroU64>| {
+      v.map_or(Ok(0), |v| i64::try_from(v.get()))
+        .map_err(|_| tonic::Status::internal("VolumeUsage is out of range"))
+    };
+    let available = to_i64(value.available)?;
+    let total = to_i64(Some(value.total))?;
+    let used = to_i64(value.used)?;
     let unit = proto::volume_usage::Unit::from(value.unit) as i32;
 
     Ok(proto::VolumeUsage {
@@ -442,6 +760,7 @@ impl TryFrom<VolumeUsage> for proto::VolumeUsage {
   }
 }
 
+/// The capacity a volume must have, in bytes.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum CapacityRange {
   AtLeast(NonZeroU64),
@@ -450,23 +769,203 @@ pub enum CapacityRange {
   Between(NonZeroU64, NonZeroU64),
 }
 
+impl CapacityRange {
+  /// The minimum capacity of the volume, if any.
+  #[inline]
+  pub fn required(&self) -> Option<NonZeroU64> {
+    match *self {
+      CapacityRange::AtLeast(r) | CapacityRange::Between(r, _) => Some(r),
+      CapacityRange::AtMost(_) => None,
+    }
+  }
+
+  /// The maximum capacity of the volume, if any.
+  #[inline]
+  pub fn limit(&self) -> Option<NonZeroU64> {
+    match *self {
+      CapacityRange::AtMost(l) | CapacityRange::Between(_, l) => Some(l),
+      CapacityRange::AtLeast(_) => None,
+    }
+  }
+
+  /// Whether a volume of `size` bytes satisfies the range.
+  #[inline]
+  pub fn contains(&self, size: u64) -> bool {
+    match *self {
+      CapacityRange::AtLeast(r) => size >= r.get(),
+      CapacityRange::AtMost(l) => size <= l.get(),
+      CapacityRange::Between(r, l) => size >= r.get() && size <= l.get(),
+    }
+  }
+
+  /// Restrict the range to multiples of `allocation_unit`, rounding the
+  /// required capacity up and the limit down. Returns `None` if no
+  /// multiple of `allocation_unit` is within the range, which plugins
+  /// should report as `OUT_OF_RANGE`.
+  pub fn round_up_to(&self, allocation_unit: NonZeroU64) -> Option<CapacityRange> {
+    let unit = allocation_unit.get();
+    let required = match self.required() {
+      Some(r) => NonZeroU64::new(r.get().checked_add(unit - 1)? / unit * unit),
+      None => None,
+    };
+    let limit = match self.limit() {
+      Some(l) => Some(NonZeroU64::new(l.get() / unit * unit)?),
+      None => None,
+    };
+
+    match (required, limit) {
+      (Some(r), Some(l)) if r > l => None,
+      (Some(r), Some(l)) => Some(CapacityRange::Between(r, l)),
+      (Some(r), None) => Some(CapacityRange::AtLeast(r)),
+      (None, Some(l)) => Some(CapacityRange::AtMost(l)),
+      (None, None) => None,
+    }
+  }
+
+  /// The size to create or expand a volume to: the required capacity if
+  /// specified, otherwise `default` capped at the limit.
+  #[inline]
+  pub fn pick_size(&self, default: u64) -> u64 {
+    match *self {
+      CapacityRange::AtLeast(r) | CapacityRange::Between(r, _) => r.get(),
+      CapacityRange::AtMost(l) => default.min(l.get()),
+    }
+  }
+}
+
 impl TryFrom<proto::CapacityRange> for CapacityRange {
   type Error = tonic::Status;
 
   fn try_from(value: proto::CapacityRange) -> Result<Self, Self::Error> {
-    match (value.required_bytes, value.limit_bytes) {
-      (r, _) if r < 0 => Err(tonic::Status::invalid_argument(
+    if value.required_bytes < 0 {
+      return Err(tonic::Status::invalid_argument(
         "CapacityRange.required_bytes cannot be negative",
-      )),
-      (_, l) if l < 0 => Err(tonic::Status::invalid_argument(
+      ));
+    }
+
+    if value.limit_bytes < 0 {
+      return Err(tonic::Status::invalid_argument(
         "CapacityRange.limit_bytes cannot be negative",
-      )),
-      (r, 0) => Ok(CapacityRange::AtLeast(NonZeroU64::new(r as u64).unwrap())),
-      (0, l) => Ok(CapacityRange::AtMost(NonZeroU64::new(l as u64).unwrap())),
-      (r, l) => Ok(CapacityRange::Between(
-        NonZeroU64::new(r as u64).unwrap(),
-        NonZeroU64::new(l as u64).unwrap(),
+      ));
+    }
+
+    let required = NonZeroU64::new(value.required_bytes as u64);
+    let limit = NonZeroU64::new(value.limit_bytes as u64);
+    match (required, limit) {
+      (Some(r), None) => Ok(CapacityRange::AtLeast(r)),
+      (None, Some(l)) => Ok(CapacityRange::AtMost(l)),
+      (Some(r), Some(l)) if l < r => Err(tonic::Status::invalid_argument(format!(
+        "CapacityRange.limit_bytes {} is less than required_bytes {}",
+        l, r
+      ))),
+      (Some(r), Some(l)) => Ok(CapacityRange::Between(r, l)),
+      (None, None) => Err(tonic::Status::invalid_argument(
+        "CapacityRange must specify required_bytes or limit_bytes",
       )),
     }
   }
 }
+
+impl TryFrom<CapacityRange> for proto::CapacityRange {
+  type Error = tonic::Status;
+
+  fn try_from(value: CapacityRange) -> Result<Self, Self::Error> {
+    let to_i64 = |v: Option<NonZeroU64>| {
+      v.map_or(Ok(0), |v| i64::try_from(v.get()))
+        .map_err(|_| tonic::Status::internal("CapacityRange is out of range"))
+    };
+
+    if let CapacityRange::Between(r, l) = value {
+      if l < r {
+        return Err(tonic::Status::internal(
+          "CapacityRange limit is less than the required capacity",
+        ));
+      }
+    }
+
+    Ok(proto::CapacityRange {
+      required_bytes: to_i64(value.required())?,
+      limit_bytes: to_i64(value.limit())?,
+    })
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use test_case::test_case;
+
+  #[test_case("vol-1", 0 => Ok(0) ; "unknown capacity")]
+  #[test_case("vol-1", 1024 => Ok(1024) ; "known capacity")]
+  #[test_case("", 1024 => Err(tonic::Code::Internal) ; "empty volume_id")]
+  #[test_case("vol-1", u64::MAX => Err(tonic::Code::Internal) ; "capacity out of range")]
+  fn volume_to_proto(volume_id: &str, capacity_bytes: u64) -> Result<i64, tonic::Code> {
+    Volume::new(volume_id)
+      .and_then(|volume| proto::Volume::try_from(volume.with_capacity_bytes(capacity_bytes)))
+      .map(|v| v.capacity_bytes)
+      .map_err(|e| e.code())
+  }
+
+  #[test_case(AccessMode::SingleNodeWriter => AccessMode::SingleNodeWriter ; "single node writer")]
+  #[test_case(AccessMode::SingleNodeSingleWriter => AccessMode::SingleNodeSingleWriter ; "single node single writer")]
+  #[test_case(AccessMode::SingleNodeMultiWriter => AccessMode::SingleNodeMultiWriter ; "single node multi writer")]
+  fn access_mode_roundtrip(access_mode: AccessMode) -> AccessMode {
+    let proto = proto::volume_capability::AccessMode::try_from(access_mode).unwrap();
+    AccessMode::try_from(proto).unwrap()
+  }
+
+  fn capacity_range(required_bytes: i64, limit_bytes: i64) -> CapacityRange {
+    CapacityRange::try_from(proto::CapacityRange {
+      required_bytes,
+      limit_bytes,
+    })
+    .unwrap()
+  }
+
+  #[test_case(10, 0 => Ok((10, 0)) ; "at least")]
+  #[test_case(0, 20 => Ok((0, 20)) ; "at most")]
+  #[test_case(10, 10 => Ok((10, 10)) ; "exact")]
+  #[test_case(0, 0 => Err(tonic::Code::InvalidArgument) ; "empty")]
+  #[test_case(20, 10 => Err(tonic::Code::InvalidArgument) ; "inverted")]
+  #[test_case(-1, 10 => Err(tonic::Code::InvalidArgument) ; "negative")]
+  fn capacity_range_roundtrip(
+    required_bytes: i64,
+    limit_bytes: i64,
+  ) -> Result<(i64, i64), tonic::Code> {
+    let range = CapacityRange::try_from(proto::CapacityRange {
+      required_bytes,
+      limit_bytes,
+    })
+    .map_err(|e| e.code())?;
+    let proto = proto::CapacityRange::try_from(range).unwrap();
+    Ok((proto.required_bytes, proto.limit_bytes))
+  }
+
+  #[test_case(10, 0, 10 => true ; "at least required")]
+  #[test_case(10, 0, 9 => false ; "below required")]
+  #[test_case(0, 20, 20 => true ; "at limit")]
+  #[test_case(10, 20, 21 => false ; "above limit")]
+  fn capacity_range_contains(required_bytes: i64, limit_bytes: i64, size: u64) -> bool {
+    capacity_range(required_bytes, limit_bytes).contains(size)
+  }
+
+  #[test_case(10, 0, 4 => Some(CapacityRange::AtLeast(NonZeroU64::new(12).unwrap())) ; "rounds required up")]
+  #[test_case(0, 10, 4 => Some(CapacityRange::AtMost(NonZeroU64::new(8).unwrap())) ; "rounds limit down")]
+  #[test_case(9, 11, 4 => None ; "no multiple within range")]
+  #[test_case(0, 3, 4 => None ; "limit below unit")]
+  #[test_case(i64::MAX, 0, u64::MAX => None ; "overflow")]
+  fn capacity_range_round_up_to(
+    required_bytes: i64,
+    limit_bytes: i64,
+    unit: u64,
+  ) -> Option<CapacityRange> {
+    capacity_range(required_bytes, limit_bytes).round_up_to(NonZeroU64::new(unit).unwrap())
+  }
+
+  #[test_case(10, 0 => 10 ; "required")]
+  #[test_case(0, 50 => 50 ; "capped at limit")]
+  #[test_case(0, 200 => 100 ; "default")]
+  fn capacity_range_pick_size(required_bytes: i64, limit_bytes: i64) -> u64 {
+    capacity_range(required_bytes, limit_bytes).pick_size(100)
+  }
+}